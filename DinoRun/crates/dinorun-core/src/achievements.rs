@@ -0,0 +1,234 @@
+//! 成就：定义表、单局进度与解锁记录。
+//!
+//! 进度只从 [`GameEvent`] 累积，每个模拟步结束后统一检查；已解锁的成就以 id 记入存档，
+//! 因此改名或调整描述不会影响玩家已有的记录。演示模式下不会解锁成就。
+
+use crate::constants::SCREEN_WIDTH;
+use crate::events::GameEvent;
+use crate::locale::Text;
+use crate::types::{CollectibleKind, Color, FloatingText, ObstacleKind};
+use crate::world::World;
+use glam::vec2;
+use std::collections::BTreeSet;
+
+/// 一项成就的达成条件，均在单局之内计算。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Goal {
+    /// 越过指定数量的某种障碍。
+    Clear {
+        kind: ObstacleKind,
+        count: u32,
+    },
+    Gems(u32),
+    AirJumps(u32),
+    PowerUps(u32),
+    ShieldBreaks(u32),
+    /// 连击倍率达到指定值。
+    Streak(f32),
+    /// 存活指定秒数；`without_dash` 要求期间从未冲刺。
+    Survive {
+        seconds: u32,
+        without_dash: bool,
+    },
+}
+
+/// 一项成就的定义。
+pub struct Achievement {
+    /// 写入存档的稳定标识，不随显示名称改变。
+    pub id: &'static str,
+    pub name: Text,
+    pub description: Text,
+    pub goal: Goal,
+}
+
+/// 所有成就，顺序即成就页面的展示顺序。
+pub const ACHIEVEMENTS: [Achievement; 9] = [
+    Achievement {
+        id: "pit_hopper",
+        name: Text::AchievementPitHopper,
+        description: Text::GoalPitHopper,
+        goal: Goal::Clear {
+            kind: ObstacleKind::Pit,
+            count: 10,
+        },
+    },
+    Achievement {
+        id: "saw_dodger",
+        name: Text::AchievementSawDodger,
+        description: Text::GoalSawDodger,
+        goal: Goal::Clear {
+            kind: ObstacleKind::Saw,
+            count: 25,
+        },
+    },
+    Achievement {
+        id: "on_a_roll",
+        name: Text::AchievementOnARoll,
+        description: Text::GoalOnARoll,
+        goal: Goal::Streak(3.0),
+    },
+    Achievement {
+        id: "treasure_hunter",
+        name: Text::AchievementTreasureHunter,
+        description: Text::GoalTreasureHunter,
+        goal: Goal::Gems(5),
+    },
+    Achievement {
+        id: "frequent_flyer",
+        name: Text::AchievementFrequentFlyer,
+        description: Text::GoalFrequentFlyer,
+        goal: Goal::AirJumps(50),
+    },
+    Achievement {
+        id: "power_hungry",
+        name: Text::AchievementPowerHungry,
+        description: Text::GoalPowerHungry,
+        goal: Goal::PowerUps(3),
+    },
+    Achievement {
+        id: "lucky_break",
+        name: Text::AchievementLuckyBreak,
+        description: Text::GoalLuckyBreak,
+        goal: Goal::ShieldBreaks(1),
+    },
+    Achievement {
+        id: "purist",
+        name: Text::AchievementPurist,
+        description: Text::GoalPurist,
+        goal: Goal::Survive {
+            seconds: 120,
+            without_dash: true,
+        },
+    },
+    Achievement {
+        id: "marathon",
+        name: Text::AchievementMarathon,
+        description: Text::GoalMarathon,
+        goal: Goal::Survive {
+            seconds: 300,
+            without_dash: false,
+        },
+    },
+];
+
+/// 成就提示在屏幕上停留的时间（秒）与出现的高度（位于强化道具计时条下方）。
+const TOAST_DURATION: f32 = 3.0;
+const TOAST_Y: f32 = 108.0;
+
+/// 本局内与成就相关的进度，由事件累积。
+#[derive(Clone, Debug, Default, PartialEq)]
+struct RunProgress {
+    cleared: [u32; ObstacleKind::ALL.len()],
+    gems: u32,
+    air_jumps: u32,
+    power_ups: u32,
+    shield_breaks: u32,
+    best_streak: f32,
+    seconds: u32,
+    dashed: bool,
+}
+
+impl RunProgress {
+    fn observe(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::RunStarted => *self = Self::default(),
+            GameEvent::Jumped { air, .. } => self.air_jumps += u32::from(air),
+            GameEvent::DashStarted => self.dashed = true,
+            GameEvent::Collected { kind, streak, .. } => {
+                if kind == CollectibleKind::Gem {
+                    self.gems += 1;
+                }
+                self.best_streak = self.best_streak.max(streak);
+            }
+            GameEvent::ObstacleCleared(kind) => self.cleared[kind_index(kind)] += 1,
+            GameEvent::PowerUpStarted { .. } => self.power_ups += 1,
+            GameEvent::ShieldBroke { .. } => self.shield_breaks += 1,
+            GameEvent::Survived { seconds } => self.seconds = seconds,
+            GameEvent::Landed { .. }
+            | GameEvent::SlideStarted { .. }
+            | GameEvent::NearMiss(_)
+            | GameEvent::PowerUpEnded(_)
+            | GameEvent::Died { .. } => {}
+        }
+    }
+}
+
+impl Goal {
+    fn is_met(&self, progress: &RunProgress) -> bool {
+        match *self {
+            Goal::Clear { kind, count } => progress.cleared[kind_index(kind)] >= count,
+            Goal::Gems(count) => progress.gems >= count,
+            Goal::AirJumps(count) => progress.air_jumps >= count,
+            Goal::PowerUps(count) => progress.power_ups >= count,
+            Goal::ShieldBreaks(count) => progress.shield_breaks >= count,
+            // 连击倍率按浮点累加，留一点余量避免 2.9999 差之毫厘。
+            Goal::Streak(target) => progress.best_streak >= target - 1e-3,
+            Goal::Survive {
+                seconds,
+                without_dash,
+            } => progress.seconds >= seconds && !(without_dash && progress.dashed),
+        }
+    }
+}
+
+fn kind_index(kind: ObstacleKind) -> usize {
+    ObstacleKind::ALL
+        .iter()
+        .position(|k| *k == kind)
+        .unwrap_or_default()
+}
+
+/// 已解锁的成就与当前局的进度。
+#[derive(Clone, Debug, Default)]
+pub struct Achievements {
+    /// 已解锁成就的 id，随存档保存。
+    pub unlocked: BTreeSet<String>,
+    progress: RunProgress,
+}
+
+impl Achievements {
+    pub fn is_unlocked(&self, achievement: &Achievement) -> bool {
+        self.unlocked.contains(achievement.id)
+    }
+
+    /// 清空本局进度。开局时由 [`World::reset_run_seeded`] 直接调用：
+    /// 在模拟步之外开局（回放复位、演示模式）时，`RunStarted` 事件不会被分发。
+    pub(crate) fn start_run(&mut self) {
+        self.progress = RunProgress::default();
+    }
+
+    /// 已解锁的数量（只统计仍在定义表中的成就）。
+    pub fn unlocked_count(&self) -> usize {
+        ACHIEVEMENTS.iter().filter(|a| self.is_unlocked(a)).count()
+    }
+}
+
+/// 用本步的事件推进成就进度，解锁新达成的成就并弹出提示。
+pub fn process_events(world: &mut World) {
+    let achievements = &mut world.achievements;
+    for event in &world.events {
+        achievements.progress.observe(event);
+    }
+    if world.autoplay {
+        return;
+    }
+    let language = world.settings.language;
+    let mut toast_y = TOAST_Y;
+    for achievement in &ACHIEVEMENTS {
+        if achievements.is_unlocked(achievement) || !achievement.goal.is_met(&achievements.progress)
+        {
+            continue;
+        }
+        achievements.unlocked.insert(achievement.id.to_string());
+        world.floating_texts.push(FloatingText {
+            text: Text::AchievementUnlocked.format(language, &[achievement.name.get(language)]),
+            pos: vec2(SCREEN_WIDTH - 360.0, toast_y),
+            vel: vec2(0.0, -6.0),
+            timer: TOAST_DURATION,
+            max_timer: TOAST_DURATION,
+            color: Color::GOLD,
+        });
+        // 同一步解锁多项时向下错开，避免重叠。
+        toast_y += 32.0;
+    }
+}
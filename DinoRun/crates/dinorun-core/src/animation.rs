@@ -0,0 +1,221 @@
+//! 精灵动画：从数据文件读取命名片段，并由实体上的 [`Animator`] 决定当前播放的帧。
+//!
+//! 数据文件是 TOML，每个片段列出图集中的帧区域与停留时间：
+//!
+//! ```toml
+//! [clips.run]
+//! loop = true
+//! frames = [{ rect = [0, 0, 32, 32], duration = 0.09 }]
+//! ```
+//!
+//! 动画只影响表现，不参与碰撞与随机数，回放结果与其无关。
+
+use crate::utils::Rect;
+use glam::{Vec2, vec2};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 落地压扁、起跳拉伸后回弹到原形的速度（每秒）。
+const SQUASH_RECOVERY: f32 = 12.0;
+/// 形变量为 ±1 时在水平与竖直方向上的最大缩放幅度。
+const SQUASH_SCALE: f32 = 0.3;
+
+/// 片段中的一帧：图集中的像素区域与停留时间。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimationFrame {
+    pub rect: Rect,
+    pub duration: f32,
+}
+
+/// 一段命名动画。
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationClip {
+    pub frames: Vec<AnimationFrame>,
+    pub looping: bool,
+    total: f32,
+}
+
+impl AnimationClip {
+    /// 播放 `time` 秒后应显示的帧；非循环片段停在最后一帧。
+    pub fn frame_at(&self, time: f32) -> &AnimationFrame {
+        let mut t = if self.looping {
+            time.rem_euclid(self.total)
+        } else {
+            time
+        };
+        for frame in &self.frames {
+            if t < frame.duration {
+                return frame;
+            }
+            t -= frame.duration;
+        }
+        &self.frames[self.frames.len() - 1]
+    }
+
+    /// 片段的总时长（秒）。
+    pub fn duration(&self) -> f32 {
+        self.total
+    }
+}
+
+/// 从数据文件加载的全部片段，按名称索引。
+#[derive(Clone, Debug, Default)]
+pub struct AnimationSet {
+    clips: HashMap<String, AnimationClip>,
+}
+
+impl AnimationSet {
+    /// 解析动画数据文件文本。
+    pub fn parse(text: &str) -> Result<Self, AnimationError> {
+        let raw: RawAnimationFile = toml::from_str(text).map_err(AnimationError::Parse)?;
+        let mut clips = HashMap::with_capacity(raw.clips.len());
+        for (name, clip) in raw.clips {
+            if clip.frames.is_empty() {
+                return Err(AnimationError::EmptyClip(name));
+            }
+            if clip
+                .frames
+                .iter()
+                .any(|f| !f.duration.is_finite() || f.duration <= 0.0)
+            {
+                return Err(AnimationError::BadDuration(name));
+            }
+            let frames: Vec<AnimationFrame> = clip
+                .frames
+                .iter()
+                .map(|f| AnimationFrame {
+                    rect: Rect::new(f.rect[0], f.rect[1], f.rect[2], f.rect[3]),
+                    duration: f.duration,
+                })
+                .collect();
+            let total = frames.iter().map(|f| f.duration).sum();
+            clips.insert(
+                name,
+                AnimationClip {
+                    frames,
+                    looping: clip.looping,
+                    total,
+                },
+            );
+        }
+        Ok(Self { clips })
+    }
+
+    /// 读取并解析动画数据文件。
+    pub fn load(path: &Path) -> Result<Self, AnimationError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn clip(&self, name: &str) -> Option<&AnimationClip> {
+        self.clips.get(name)
+    }
+
+    /// 查询实体当前应显示的帧；片段不存在时返回 `None`，由调用方退回到占位绘制。
+    pub fn frame(&self, animator: &Animator) -> Option<&AnimationFrame> {
+        self.clip(animator.clip)
+            .map(|clip| clip.frame_at(animator.time))
+    }
+}
+
+/// 读取动画数据时可能出现的错误。
+#[derive(Debug)]
+pub enum AnimationError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// 片段没有任何帧。
+    EmptyClip(String),
+    /// 片段中存在非正的帧时长。
+    BadDuration(String),
+}
+
+impl fmt::Display for AnimationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationError::Io(err) => write!(f, "failed to read animation data: {err}"),
+            AnimationError::Parse(err) => write!(f, "invalid animation data: {}", err.message()),
+            AnimationError::EmptyClip(name) => write!(f, "animation clip `{name}` has no frames"),
+            AnimationError::BadDuration(name) => {
+                write!(
+                    f,
+                    "animation clip `{name}` has a non-positive frame duration"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnimationError {}
+
+impl From<io::Error> for AnimationError {
+    fn from(err: io::Error) -> Self {
+        AnimationError::Io(err)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawAnimationFile {
+    clips: HashMap<String, RawClip>,
+}
+
+#[derive(Deserialize)]
+struct RawClip {
+    #[serde(rename = "loop", default)]
+    looping: bool,
+    frames: Vec<RawFrame>,
+}
+
+#[derive(Deserialize)]
+struct RawFrame {
+    rect: [f32; 4],
+    duration: f32,
+}
+
+/// 挂在实体上的动画状态：当前片段、已播放时间与挤压形变。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animator {
+    pub clip: &'static str,
+    pub time: f32,
+    /// 形变量：正值为落地压扁，负值为起跳拉伸，随时间回弹到 0。
+    pub squash: f32,
+}
+
+impl Animator {
+    pub fn new(clip: &'static str) -> Self {
+        Self {
+            clip,
+            time: 0.0,
+            squash: 0.0,
+        }
+    }
+
+    /// 切换到指定片段；与当前片段相同时继续播放而不重置。
+    pub fn play(&mut self, clip: &'static str) {
+        if self.clip != clip {
+            self.clip = clip;
+            self.time = 0.0;
+        }
+    }
+
+    /// 推进播放时间，并让形变回弹。
+    pub fn update(&mut self, dt: f32) {
+        self.time += dt;
+        self.squash -= self.squash * (SQUASH_RECOVERY * dt).min(1.0);
+    }
+
+    /// 施加一次形变，`amount` 取值 -1（拉伸）到 1（压扁）。
+    pub fn squash(&mut self, amount: f32) {
+        self.squash = amount.clamp(-1.0, 1.0);
+    }
+
+    /// 当前形变对应的绘制缩放（宽、高），面积近似保持不变。
+    pub fn scale(&self) -> Vec2 {
+        vec2(
+            1.0 + self.squash * SQUASH_SCALE,
+            1.0 - self.squash * SQUASH_SCALE,
+        )
+    }
+}
@@ -0,0 +1,163 @@
+//! 音频调度：把玩法事件映射为音效，并根据游戏状态在背景音乐之间交叉淡入淡出。
+//!
+//! 这里只决定“播什么、多大声”，真正的播放交给 [`AudioBackend`]。渲染层提供基于
+//! macroquad 的实现；无窗口的模拟与测试使用 [`NullAudio`]。
+
+use crate::events::GameEvent;
+use crate::types::{CollectibleKind, GameState, PowerUpKind};
+use crate::world::World;
+
+/// 音效种类。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sfx {
+    Jump,
+    DoubleJump,
+    Dash,
+    Land,
+    Coin,
+    Gem,
+    PowerUp,
+    ShieldBreak,
+    Death,
+    Slide,
+}
+
+impl Sfx {
+    pub const ALL: [Sfx; 10] = [
+        Sfx::Jump,
+        Sfx::DoubleJump,
+        Sfx::Dash,
+        Sfx::Land,
+        Sfx::Coin,
+        Sfx::Gem,
+        Sfx::PowerUp,
+        Sfx::ShieldBreak,
+        Sfx::Death,
+        Sfx::Slide,
+    ];
+}
+
+/// 循环播放的背景音乐。`RunSlow` 是降调版的对局音乐，在时间减缓期间替换 `Run`。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MusicTrack {
+    Menu,
+    Run,
+    RunSlow,
+}
+
+impl MusicTrack {
+    pub const ALL: [MusicTrack; 3] = [MusicTrack::Menu, MusicTrack::Run, MusicTrack::RunSlow];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// 播放后端。所有音乐在启动时即开始循环，调度器只调整各自的音量。
+pub trait AudioBackend {
+    /// 以给定音量（0.0..=1.0）播放一次音效。
+    fn play(&mut self, sfx: Sfx, volume: f32);
+    /// 设置某条音乐的音量（0.0..=1.0）。
+    fn set_music_volume(&mut self, track: MusicTrack, volume: f32);
+}
+
+/// 不发声的后端，用于无音频环境与测试。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullAudio;
+
+impl AudioBackend for NullAudio {
+    fn play(&mut self, _sfx: Sfx, _volume: f32) {}
+    fn set_music_volume(&mut self, _track: MusicTrack, _volume: f32) {}
+}
+
+/// 交叉淡入淡出一次所需的时间（秒）。
+const CROSSFADE_TIME: f32 = 0.8;
+/// 暂停时对局音乐压低到的比例。
+const PAUSE_DUCK: f32 = 0.4;
+/// 轻触地面时落地音效的最小音量比例。
+const MIN_LAND_VOLUME: f32 = 0.2;
+
+/// 音频调度器：记录各条音乐当前的混音比例；音量取自 `World::settings`，调整后即时生效。
+#[derive(Default)]
+pub struct AudioDirector {
+    /// 各条音乐的淡入淡出进度，按 [`MusicTrack::ALL`] 的顺序排列。
+    mix: [f32; 3],
+    /// 上次发给后端的音量，只在变化时通知后端；`None` 表示尚未设置过。
+    sent: [Option<f32>; 3],
+}
+
+impl AudioDirector {
+    /// 在每个模拟步之后调用，为本步的玩法事件播放音效。
+    pub fn on_step(&mut self, world: &World, backend: &mut dyn AudioBackend) {
+        let gain = world.settings.sfx_gain();
+        if gain <= 0.0 {
+            return;
+        }
+        for event in &world.events {
+            let (sfx, volume) = match *event {
+                GameEvent::Jumped { air: false, .. } => (Sfx::Jump, 1.0),
+                GameEvent::Jumped { air: true, .. } => (Sfx::DoubleJump, 1.0),
+                GameEvent::DashStarted => (Sfx::Dash, 0.8),
+                GameEvent::SlideStarted { .. } => (Sfx::Slide, 0.7),
+                // 落地越重声音越大，轻触地面时仍保留一点声音。
+                GameEvent::Landed { speed } => (
+                    Sfx::Land,
+                    (speed / world.tuning.player.terminal_velocity).clamp(MIN_LAND_VOLUME, 1.0),
+                ),
+                GameEvent::Collected {
+                    kind: CollectibleKind::Coin,
+                    ..
+                } => (Sfx::Coin, 0.7),
+                GameEvent::Collected {
+                    kind: CollectibleKind::Gem,
+                    ..
+                } => (Sfx::Gem, 0.9),
+                GameEvent::PowerUpStarted { .. } => (Sfx::PowerUp, 1.0),
+                GameEvent::ShieldBroke { .. } => (Sfx::ShieldBreak, 1.0),
+                GameEvent::Died { .. } => (Sfx::Death, 1.0),
+                _ => continue,
+            };
+            backend.play(sfx, volume * gain);
+        }
+    }
+
+    /// 每帧调用一次：让混音比例向当前状态对应的音乐靠拢，并把音量同步给后端。
+    pub fn update(&mut self, world: &World, dt: f32, backend: &mut dyn AudioBackend) {
+        let (target, level) = music_target(world);
+        let step = dt / CROSSFADE_TIME;
+        for track in MusicTrack::ALL {
+            let goal = if track == target { level } else { 0.0 };
+            let mix = &mut self.mix[track.index()];
+            *mix = if *mix < goal {
+                (*mix + step).min(goal)
+            } else {
+                (*mix - step).max(goal)
+            };
+        }
+
+        let gain = world.settings.music_gain();
+        for track in MusicTrack::ALL {
+            let volume = self.mix[track.index()] * gain;
+            let sent = &mut self.sent[track.index()];
+            if *sent != Some(volume) {
+                backend.set_music_volume(track, volume);
+                *sent = Some(volume);
+            }
+        }
+    }
+}
+
+/// 当前状态应播放的音乐及其目标比例。
+fn music_target(world: &World) -> (MusicTrack, f32) {
+    let slowed = world.power_up.active && world.power_up.kind == PowerUpKind::TimeSlow;
+    let run_track = if slowed {
+        MusicTrack::RunSlow
+    } else {
+        MusicTrack::Run
+    };
+    match world.state {
+        GameState::Running => (run_track, 1.0),
+        GameState::Paused => (run_track, PAUSE_DUCK),
+        _ => (MusicTrack::Menu, 1.0),
+    }
+}
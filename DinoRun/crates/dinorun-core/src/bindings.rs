@@ -0,0 +1,473 @@
+//! 按键绑定：把玩法与菜单动作映射到按键，随设置文件保存，可在按键设置页改绑。
+//!
+//! 键盘按键以渲染层按键码的名称书写（如 `"Space"`、`"LeftShift"`），手柄按钮与摇杆方向使用
+//! [`crate::gamepad`] 中的名称（如 `"PadSouth"`、`"StickDown"`），两种设备共用同一份绑定，
+//! 核心不依赖具体输入库。
+//! 动作分属玩法、菜单与录像回放三个场景：同一场景内两个动作不能共用按键，跨场景共用
+//! （如 Space 既是跳跃又是菜单确认）不算冲突。
+
+use crate::input::InputFrame;
+use crate::locale::Text;
+use crate::menu::{MenuAction, MenuCursor};
+use serde::{Deserialize, Serialize};
+
+/// 每个动作最多绑定的按键数。
+pub const MAX_KEYS_PER_ACTION: usize = 4;
+
+/// 可改绑的动作。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Jump,
+    Dash,
+    Slide,
+    /// 对局中暂停，菜单中返回上一页。
+    Pause,
+    MenuUp,
+    MenuDown,
+    MenuLeft,
+    MenuRight,
+    Confirm,
+    /// 按键设置页中清除选中的按键。
+    ClearKey,
+    /// 结算界面观看本局录像。
+    WatchReplay,
+    ReplayPause,
+    /// 回放暂停时前进一个模拟步。
+    ReplayStep,
+    ReplaySlower,
+    ReplayFaster,
+    ReplayRestart,
+    ReplayExit,
+}
+
+impl Action {
+    pub const ALL: [Action; 17] = [
+        Action::Jump,
+        Action::Dash,
+        Action::Slide,
+        Action::Pause,
+        Action::MenuUp,
+        Action::MenuDown,
+        Action::MenuLeft,
+        Action::MenuRight,
+        Action::Confirm,
+        Action::ClearKey,
+        Action::WatchReplay,
+        Action::ReplayPause,
+        Action::ReplayStep,
+        Action::ReplaySlower,
+        Action::ReplayFaster,
+        Action::ReplayRestart,
+        Action::ReplayExit,
+    ];
+
+    pub fn label(self) -> Text {
+        match self {
+            Action::Jump => Text::ActionJump,
+            Action::Dash => Text::ActionDash,
+            Action::Slide => Text::ActionSlide,
+            Action::Pause => Text::ActionPause,
+            Action::MenuUp => Text::ActionMenuUp,
+            Action::MenuDown => Text::ActionMenuDown,
+            Action::MenuLeft => Text::ActionMenuLeft,
+            Action::MenuRight => Text::ActionMenuRight,
+            Action::Confirm => Text::ActionConfirm,
+            Action::ClearKey => Text::ActionClearKey,
+            Action::WatchReplay => Text::ActionWatchReplay,
+            Action::ReplayPause => Text::ActionReplayPause,
+            Action::ReplayStep => Text::ActionReplayStep,
+            Action::ReplaySlower => Text::ActionReplaySlower,
+            Action::ReplayFaster => Text::ActionReplayFaster,
+            Action::ReplayRestart => Text::ActionReplayRestart,
+            Action::ReplayExit => Text::ActionReplayExit,
+        }
+    }
+
+    /// 是否在对局中生效。
+    fn in_gameplay(self) -> bool {
+        matches!(
+            self,
+            Action::Jump | Action::Dash | Action::Slide | Action::Pause
+        )
+    }
+
+    /// 是否在菜单、暂停与结算界面中生效。
+    fn in_menus(self) -> bool {
+        matches!(
+            self,
+            Action::Pause
+                | Action::MenuUp
+                | Action::MenuDown
+                | Action::MenuLeft
+                | Action::MenuRight
+                | Action::Confirm
+                | Action::ClearKey
+                | Action::WatchReplay
+        )
+    }
+
+    /// 是否在录像回放界面中生效。
+    fn in_replay(self) -> bool {
+        matches!(
+            self,
+            Action::ReplayPause
+                | Action::ReplayStep
+                | Action::ReplaySlower
+                | Action::ReplayFaster
+                | Action::ReplayRestart
+                | Action::ReplayExit
+        )
+    }
+
+    /// 两个动作是否会在同一场景下同时生效，共用按键时即为冲突。
+    pub fn overlaps(self, other: Action) -> bool {
+        self != other
+            && ((self.in_gameplay() && other.in_gameplay())
+                || (self.in_menus() && other.in_menus())
+                || (self.in_replay() && other.in_replay()))
+    }
+}
+
+/// 各动作绑定的按键名，在设置文件中写作 `[controls]` 表。
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Bindings {
+    pub jump: Vec<String>,
+    pub dash: Vec<String>,
+    pub slide: Vec<String>,
+    pub pause: Vec<String>,
+    pub menu_up: Vec<String>,
+    pub menu_down: Vec<String>,
+    pub menu_left: Vec<String>,
+    pub menu_right: Vec<String>,
+    pub confirm: Vec<String>,
+    pub clear_key: Vec<String>,
+    pub watch_replay: Vec<String>,
+    pub replay_pause: Vec<String>,
+    pub replay_step: Vec<String>,
+    pub replay_slower: Vec<String>,
+    pub replay_faster: Vec<String>,
+    pub replay_restart: Vec<String>,
+    pub replay_exit: Vec<String>,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        let keys = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        Self {
+            jump: keys(&["Space", "Up", "W", "PadSouth"]),
+            dash: keys(&["LeftShift", "RightShift", "PadRT"]),
+            slide: keys(&["Down", "S", "PadDown", "StickDown"]),
+            pause: keys(&["Escape", "PadStart", "PadEast"]),
+            menu_up: keys(&["Up", "PadUp", "StickUp"]),
+            menu_down: keys(&["Down", "PadDown", "StickDown"]),
+            menu_left: keys(&["Left", "PadLeft", "StickLeft"]),
+            menu_right: keys(&["Right", "PadRight", "StickRight"]),
+            confirm: keys(&["Enter", "Space", "PadSouth"]),
+            clear_key: keys(&["Backspace", "Delete", "PadWest"]),
+            watch_replay: keys(&["R", "PadNorth"]),
+            replay_pause: keys(&["Space", "PadSouth"]),
+            replay_step: keys(&["Period", "PadRB"]),
+            replay_slower: keys(&["Left", "Minus", "PadLeft"]),
+            replay_faster: keys(&["Right", "Equal", "PadRight"]),
+            replay_restart: keys(&["R", "PadNorth"]),
+            replay_exit: keys(&["Escape", "PadEast"]),
+        }
+    }
+}
+
+/// 一次改绑的结果。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rebind {
+    Bound,
+    /// 按键原本属于同一场景下的 `from`，已从那里移除。
+    Moved {
+        from: Action,
+    },
+    /// 操作会让 `owner` 没有任何按键，未执行。
+    Refused {
+        owner: Action,
+    },
+}
+
+/// 同一场景下被两个动作共用的按键。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conflict {
+    pub key: String,
+    pub first: Action,
+    pub second: Action,
+}
+
+impl Bindings {
+    pub fn keys(&self, action: Action) -> &[String] {
+        match action {
+            Action::Jump => &self.jump,
+            Action::Dash => &self.dash,
+            Action::Slide => &self.slide,
+            Action::Pause => &self.pause,
+            Action::MenuUp => &self.menu_up,
+            Action::MenuDown => &self.menu_down,
+            Action::MenuLeft => &self.menu_left,
+            Action::MenuRight => &self.menu_right,
+            Action::Confirm => &self.confirm,
+            Action::ClearKey => &self.clear_key,
+            Action::WatchReplay => &self.watch_replay,
+            Action::ReplayPause => &self.replay_pause,
+            Action::ReplayStep => &self.replay_step,
+            Action::ReplaySlower => &self.replay_slower,
+            Action::ReplayFaster => &self.replay_faster,
+            Action::ReplayRestart => &self.replay_restart,
+            Action::ReplayExit => &self.replay_exit,
+        }
+    }
+
+    fn keys_mut(&mut self, action: Action) -> &mut Vec<String> {
+        match action {
+            Action::Jump => &mut self.jump,
+            Action::Dash => &mut self.dash,
+            Action::Slide => &mut self.slide,
+            Action::Pause => &mut self.pause,
+            Action::MenuUp => &mut self.menu_up,
+            Action::MenuDown => &mut self.menu_down,
+            Action::MenuLeft => &mut self.menu_left,
+            Action::MenuRight => &mut self.menu_right,
+            Action::Confirm => &mut self.confirm,
+            Action::ClearKey => &mut self.clear_key,
+            Action::WatchReplay => &mut self.watch_replay,
+            Action::ReplayPause => &mut self.replay_pause,
+            Action::ReplayStep => &mut self.replay_step,
+            Action::ReplaySlower => &mut self.replay_slower,
+            Action::ReplayFaster => &mut self.replay_faster,
+            Action::ReplayRestart => &mut self.replay_restart,
+            Action::ReplayExit => &mut self.replay_exit,
+        }
+    }
+
+    /// `names` 中是否有绑定到 `action` 的按键。
+    /// 不进入输入帧的界面动作（清除按键、观看与控制回放）由渲染层直接以本帧新按下的按键查询。
+    pub fn hit(&self, action: Action, names: &[&str]) -> bool {
+        self.keys(action)
+            .iter()
+            .any(|key| names.contains(&key.as_str()))
+    }
+
+    /// 由本帧新按下与正在按住的按键名生成输入帧；键盘与手柄都经过这里。
+    pub fn frame(&self, pressed: &[&str], held: &[&str]) -> InputFrame {
+        let hit = |action, names: &[&str]| self.hit(action, names);
+        InputFrame {
+            jump_pressed: hit(Action::Jump, pressed),
+            jump_held: hit(Action::Jump, held),
+            dash_held: hit(Action::Dash, held),
+            slide_held: hit(Action::Slide, held),
+            pause_pressed: hit(Action::Pause, pressed),
+            menu_up: hit(Action::MenuUp, pressed),
+            menu_down: hit(Action::MenuDown, pressed),
+            menu_left: hit(Action::MenuLeft, pressed),
+            menu_right: hit(Action::MenuRight, pressed),
+            menu_confirm: hit(Action::Confirm, pressed),
+        }
+    }
+
+    /// 把 `key` 绑定到 `action` 的第 `slot` 个位置；位置为空时追加到末尾。
+    /// 同一场景下已使用该键的动作会失去它，若那是它唯一的按键则拒绝改绑。
+    pub fn bind(&mut self, action: Action, slot: usize, key: &str) -> Rebind {
+        let owner = Action::ALL
+            .into_iter()
+            .find(|other| action.overlaps(*other) && self.keys(*other).iter().any(|k| k == key));
+        if let Some(owner) = owner
+            && self.keys(owner).len() == 1
+        {
+            return Rebind::Refused { owner };
+        }
+        if let Some(owner) = owner {
+            self.keys_mut(owner).retain(|k| k != key);
+        }
+
+        let keys = self.keys_mut(action);
+        if let Some(existing) = keys.iter().position(|k| k == key) {
+            // 已绑定在本动作的其他位置时挪到新位置，不重复保存。
+            keys.remove(existing);
+        }
+        if slot < keys.len() {
+            keys[slot] = key.to_string();
+        } else if keys.len() < MAX_KEYS_PER_ACTION {
+            keys.push(key.to_string());
+        }
+        match owner {
+            Some(from) => Rebind::Moved { from },
+            None => Rebind::Bound,
+        }
+    }
+
+    /// 移除 `action` 第 `slot` 个位置的按键；不能移除唯一的按键。
+    pub fn clear(&mut self, action: Action, slot: usize) -> Rebind {
+        let keys = self.keys_mut(action);
+        if slot >= keys.len() {
+            return Rebind::Bound;
+        }
+        if keys.len() == 1 {
+            return Rebind::Refused { owner: action };
+        }
+        keys.remove(slot);
+        Rebind::Bound
+    }
+
+    /// 列出同一场景下被两个动作共用的按键，通常来自手动编辑的设置文件。
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut conflicts = Vec::new();
+        for (idx, first) in Action::ALL.iter().enumerate() {
+            for second in &Action::ALL[idx + 1..] {
+                if !first.overlaps(*second) {
+                    continue;
+                }
+                for key in self.keys(*first) {
+                    if self.keys(*second).contains(key) {
+                        conflicts.push(Conflict {
+                            key: key.clone(),
+                            first: *first,
+                            second: *second,
+                        });
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// 把默认绑定中的手柄按键补到各动作末尾，用于迁移加入手柄支持之前的设置文件。
+    /// 已满或同一场景下已被占用的按键跳过，不挤掉玩家自己的绑定。
+    pub fn add_default_pad_keys(&mut self) {
+        let defaults = Self::default();
+        for action in Action::ALL {
+            for key in defaults.keys(action) {
+                let is_pad = key.starts_with("Pad") || key.starts_with("Stick");
+                let taken = Action::ALL.into_iter().any(|other| {
+                    (other == action || action.overlaps(other)) && self.keys(other).contains(key)
+                });
+                let keys = self.keys_mut(action);
+                if is_pad && !taken && keys.len() < MAX_KEYS_PER_ACTION {
+                    keys.push(key.clone());
+                }
+            }
+        }
+    }
+
+    /// 去掉重复与超出上限的按键；没有按键的动作恢复默认绑定，避免无法操作菜单。
+    pub fn sanitized(mut self) -> Self {
+        let defaults = Self::default();
+        for action in Action::ALL {
+            let keys = self.keys_mut(action);
+            let mut unique: Vec<String> = Vec::new();
+            for key in keys.drain(..) {
+                if !key.is_empty() && !unique.contains(&key) {
+                    unique.push(key);
+                }
+            }
+            unique.truncate(MAX_KEYS_PER_ACTION);
+            if unique.is_empty() {
+                unique = defaults.keys(action).to_vec();
+            }
+            *keys = unique;
+        }
+        self
+    }
+}
+
+/// 按键设置页的条目，顺序即显示顺序。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ControlsItem {
+    Action(Action),
+    ResetDefaults,
+}
+
+impl ControlsItem {
+    pub const ALL: [ControlsItem; 18] = [
+        ControlsItem::Action(Action::Jump),
+        ControlsItem::Action(Action::Dash),
+        ControlsItem::Action(Action::Slide),
+        ControlsItem::Action(Action::Pause),
+        ControlsItem::Action(Action::MenuUp),
+        ControlsItem::Action(Action::MenuDown),
+        ControlsItem::Action(Action::MenuLeft),
+        ControlsItem::Action(Action::MenuRight),
+        ControlsItem::Action(Action::Confirm),
+        ControlsItem::Action(Action::ClearKey),
+        ControlsItem::Action(Action::WatchReplay),
+        ControlsItem::Action(Action::ReplayPause),
+        ControlsItem::Action(Action::ReplayStep),
+        ControlsItem::Action(Action::ReplaySlower),
+        ControlsItem::Action(Action::ReplayFaster),
+        ControlsItem::Action(Action::ReplayRestart),
+        ControlsItem::Action(Action::ReplayExit),
+        ControlsItem::ResetDefaults,
+    ];
+
+    pub fn label(self) -> Text {
+        match self {
+            ControlsItem::Action(action) => action.label(),
+            ControlsItem::ResetDefaults => Text::ResetDefaults,
+        }
+    }
+}
+
+/// 按键设置页的状态：选中的动作与按键位置，以及是否正在等待玩家按键。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ControlsPage {
+    pub cursor: MenuCursor,
+    /// 选中动作的第几个按键位置。
+    pub slot: usize,
+    /// 等待按键期间，按下的键由渲染层通过 [`ControlsPage::capture`] 送来，不作为菜单输入。
+    pub capturing: bool,
+    /// 最近一次改绑涉及的按键与结果，显示在页面底部。
+    pub last: Option<(String, Rebind)>,
+}
+
+impl ControlsPage {
+    /// 当前选中的条目。
+    pub fn selected(&self) -> ControlsItem {
+        ControlsItem::ALL[self.cursor.selected]
+    }
+
+    /// 处理菜单操作：左右选择按键位置，确认开始等待按键或恢复默认绑定。
+    pub fn apply(&mut self, bindings: &mut Bindings, action: MenuAction) {
+        match (self.selected(), action) {
+            (ControlsItem::Action(_), MenuAction::Adjust(delta)) => {
+                self.slot =
+                    (self.slot as i32 + delta).clamp(0, MAX_KEYS_PER_ACTION as i32 - 1) as usize;
+            }
+            (ControlsItem::Action(_), MenuAction::Confirm) => {
+                self.capturing = true;
+                self.last = None;
+            }
+            (ControlsItem::ResetDefaults, MenuAction::Confirm) => {
+                *bindings = Bindings::default();
+                self.last = None;
+            }
+            (ControlsItem::ResetDefaults, MenuAction::Adjust(_)) => {}
+        }
+    }
+
+    /// 把玩家按下的 `key` 绑定到选中的位置，并结束等待。
+    pub fn capture(&mut self, bindings: &mut Bindings, key: &str) {
+        if let ControlsItem::Action(action) = self.selected() {
+            let result = bindings.bind(action, self.slot, key);
+            self.last = Some((key.to_string(), result));
+        }
+        self.capturing = false;
+    }
+
+    /// 放弃本次改绑。
+    pub fn cancel(&mut self) {
+        self.capturing = false;
+    }
+
+    /// 移除选中位置上的按键。
+    pub fn clear(&mut self, bindings: &mut Bindings) {
+        if let ControlsItem::Action(action) = self.selected()
+            && let Some(key) = bindings.keys(action).get(self.slot).cloned()
+        {
+            let result = bindings.clear(action, self.slot);
+            self.last = Some((key, result));
+        }
+    }
+}
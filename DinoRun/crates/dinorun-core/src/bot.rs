@@ -0,0 +1,335 @@
+//! 内置的自动游玩机器人：观察 `World` 中的障碍与收集物，给出跳跃、二段跳、滑铲与冲刺。
+//!
+//! 机器人只看当前画面，对几种候选动作（不动、现在跳、稍后再跳、冲刺、滑铲）
+//! 用游戏本身的玩家物理向前预测，选一个不会撞上的；它不做深度搜索，像一个反应很快的玩家，
+//! 并不保证不死，因此可以用来衡量一条难度曲线对“熟练玩家”有多难。
+//! 决策只依赖世界状态，同一种子的对局总是得到同样的结果。
+
+use crate::constants::*;
+use crate::input::{InputFrame, InputSource, control_player};
+use crate::player::update_player;
+use crate::stats::RunStats;
+use crate::types::{DifficultyTrack, GameState, ObstacleKind};
+use crate::utils::Rect;
+use crate::world::World;
+use serde::Serialize;
+use std::fmt;
+
+/// 预测轨迹在腾空后落地、继续检查的时长（秒）。
+const LANDING_LOOKAHEAD: f32 = 0.15;
+/// 轨迹预测的最长时长（秒）。
+const LOOKAHEAD: f32 = 2.0;
+/// 判断“晚一点再跳是否仍然安全”时推迟的时长（秒）。
+const JUMP_DELAY: f32 = 0.05;
+/// 距离碰撞不足这个时间时开始滑铲（秒）。
+const SLIDE_LEAD: f32 = 0.15;
+/// 已经无路可走时，距离碰撞不足这个时间就硬跳碰运气（秒）。
+const PANIC_LEAD: f32 = 0.06;
+/// 搜索补救的空中跳跃时刻时的采样间隔（秒）。
+const FOLLOW_UP_STEP: f32 = 0.05;
+/// 障碍四周额外留出的安全距离（像素）。
+const SAFETY_MARGIN: f32 = 3.0;
+
+/// 内置机器人，作为 [`InputSource`] 取代键盘输入。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bot;
+
+impl InputSource for Bot {
+    fn poll(&mut self, world: &World) -> InputFrame {
+        if world.state != GameState::Running {
+            return InputFrame::default();
+        }
+        View::new(world).decide()
+    }
+}
+
+/// 一条候选动作：在哪些时刻（秒后）按下跳跃、是否一直冲刺、是否一直滑铲。
+#[derive(Clone, Copy, Default)]
+struct Plan {
+    jumps: [Option<f32>; 2],
+    dash: bool,
+    slide: bool,
+}
+
+impl Plan {
+    fn jump_at(at: f32) -> Self {
+        Self {
+            jumps: [Some(at), None],
+            ..Self::default()
+        }
+    }
+
+    /// 再追加一次跳跃；已经排满时返回 `None`。
+    fn then_jump(self, at: f32) -> Option<Self> {
+        let slot = self.jumps.iter().position(Option::is_none)?;
+        let mut plan = self;
+        plan.jumps[slot] = Some(at);
+        Some(plan)
+    }
+}
+
+/// 机器人做决策时看到的局面。
+struct View<'a> {
+    world: &'a World,
+    /// 需要避开的区域与是否为深坑。
+    threats: Vec<(Rect, bool)>,
+    /// 想要碰到的收集物。
+    targets: Vec<(Rect, bool)>,
+}
+
+impl<'a> View<'a> {
+    fn new(world: &'a World) -> Self {
+        let ahead = |rect: &Rect| rect.x + rect.w > world.player.pos.x;
+        let threats = world
+            .obstacles
+            .iter()
+            .map(|o| {
+                if o.kind == ObstacleKind::Pit {
+                    (o.rect, true)
+                } else {
+                    (o.hurt_box.inflate(SAFETY_MARGIN, SAFETY_MARGIN), false)
+                }
+            })
+            .filter(|(rect, _)| ahead(rect))
+            .collect();
+        let targets = world
+            .collectibles
+            .iter()
+            .map(|c| (c.rect, false))
+            .filter(|(rect, _)| ahead(rect))
+            .collect();
+        Self {
+            world,
+            threats,
+            targets,
+        }
+    }
+
+    fn decide(&self) -> InputFrame {
+        let player = &self.world.player;
+        let ground = self.world.difficulty.ground_y(&self.world.tuning);
+        let on_ground = player.on_ground(ground);
+        // 机器人始终按住跳跃键，只做满高度的跳跃，与轨迹预测一致。
+        let mut input = InputFrame {
+            jump_held: true,
+            ..InputFrame::default()
+        };
+        let idle = Plan::default();
+        let jump = Plan::jump_at(0.0);
+        let jumps_left = player.air_jumps_left as usize + usize::from(on_ground);
+        let recoverable = |plan: Plan| self.is_recoverable(plan, jumps_left);
+
+        let Some(hit) = self.first_hit(idle) else {
+            // 前方安全时跳起去够收集物，前提是这一跳本身不会撞上障碍。
+            input.jump_pressed = on_ground && self.touches_target(jump) && self.is_clear(jump);
+            return input;
+        };
+        // 空中按住滑铲会在落地瞬间起滑，来得及钻过低处的障碍。
+        if hit <= SLIDE_LEAD {
+            let slide = Plan {
+                slide: true,
+                ..idle
+            };
+            if self.first_hit(slide).is_none_or(|t| t > hit + SLIDE_LEAD) {
+                input.slide_held = true;
+                return input;
+            }
+        }
+        // 稍后再跳仍有活路时先等待：落地更晚，之后的反应时间更充裕，空中跳跃也留到需要时再用。
+        if jumps_left > 0 && recoverable(Plan::jump_at(JUMP_DELAY)) {
+            return input;
+        }
+        if jumps_left > 0 && recoverable(jump) {
+            input.jump_pressed = true;
+            return input;
+        }
+        let can_dash = player.stamina > 0.0;
+        let dash = Plan { dash: true, ..idle };
+        if can_dash && !on_ground && self.is_clear(dash) {
+            input.dash_held = true;
+            return input;
+        }
+        if can_dash && jumps_left > 0 && recoverable(Plan { dash: true, ..jump }) {
+            input.jump_pressed = true;
+            input.dash_held = true;
+            return input;
+        }
+        // 已经无路可走：在撞上之前硬跳碰运气。
+        input.jump_pressed = jumps_left > 0 && hit <= PANIC_LEAD;
+        input
+    }
+
+    /// 计划本身安全，或者在计划的跳跃之后再补一次空中跳跃就能安全。
+    fn is_recoverable(&self, plan: Plan, jumps_left: usize) -> bool {
+        if self.is_clear(plan) {
+            return true;
+        }
+        let used = plan.jumps.iter().flatten().count();
+        if used >= jumps_left {
+            return false;
+        }
+        let last = plan.jumps.iter().flatten().fold(0.0, |a: f32, &b| a.max(b));
+        let mut at = last + FOLLOW_UP_STEP;
+        while at < LOOKAHEAD {
+            if plan.then_jump(at).is_some_and(|p| self.is_clear(p)) {
+                return true;
+            }
+            at += FOLLOW_UP_STEP;
+        }
+        false
+    }
+
+    fn is_clear(&self, plan: Plan) -> bool {
+        self.first_hit(plan).is_none()
+    }
+
+    fn touches_target(&self, plan: Plan) -> bool {
+        self.trace(plan, &self.targets).is_some()
+    }
+
+    /// 按计划行动时第一次撞上障碍的时间。
+    fn first_hit(&self, plan: Plan) -> Option<f32> {
+        self.trace(plan, &self.threats)
+    }
+
+    /// 按 `step_world` 的顺序逐步预测玩家，返回第一次与 `zones` 相交的时间。
+    /// 障碍视为匀速左移；预测在腾空后落地满 [`LANDING_LOOKAHEAD`] 秒或 [`LOOKAHEAD`] 秒后结束。
+    fn trace(&self, plan: Plan, zones: &[(Rect, bool)]) -> Option<f32> {
+        let world = self.world;
+        let tuning = &world.tuning;
+        let scale = world.slow_mo_factor;
+        let mut player = world.player.clone();
+        let mut difficulty = DifficultyTrack {
+            time: world.difficulty.time,
+            ..DifficultyTrack::new()
+        };
+        let mut scrolled = 0.0;
+        let mut airborne = false;
+        let mut landed_at: Option<f32> = None;
+        let steps = (LOOKAHEAD / SIM_DT) as usize;
+        for step in 0..steps {
+            let t = step as f32 * SIM_DT;
+            let jump_now = plan
+                .jumps
+                .iter()
+                .flatten()
+                .any(|&at| at >= t && at < t + SIM_DT);
+            let input = InputFrame {
+                jump_pressed: jump_now,
+                jump_held: true,
+                dash_held: plan.dash,
+                slide_held: plan.slide,
+                ..InputFrame::default()
+            };
+            control_player(
+                &mut player,
+                &input,
+                SIM_DT,
+                difficulty.ground_y(tuning),
+                tuning,
+            );
+            let scaled_dt = SIM_DT * scale;
+            difficulty.time += scaled_dt;
+            let ground = difficulty.ground_y(tuning);
+            update_player(&mut player, scaled_dt, ground, &tuning.player);
+            scrolled += difficulty.scroll_speed(tuning) * scale * scaled_dt;
+
+            let body = player.rect();
+            let hit = zones.iter().any(|(zone, pit)| {
+                let zone = Rect::new(zone.x - scrolled, zone.y, zone.w, zone.h);
+                if *pit {
+                    body.x + body.w > zone.x
+                        && body.x < zone.x + zone.w
+                        && body.y + body.h >= ground - 4.0 - SAFETY_MARGIN
+                } else {
+                    zone.overlaps(&body)
+                }
+            });
+            if hit {
+                return Some(t + SIM_DT);
+            }
+            if !player.on_ground(ground) {
+                airborne = true;
+                landed_at = None;
+            } else if airborne && plan.jumps.iter().flatten().all(|&at| at <= t) {
+                let at = *landed_at.get_or_insert(t);
+                if t - at > LANDING_LOOKAHEAD {
+                    break;
+                }
+            }
+        }
+        None
+    }
+}
+
+/// 一局机器人对局的结果。
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BotRun {
+    pub seed: u64,
+    /// 本局统计；存活满上限时存活时间即为上限，`killed_by` 为 `None`。
+    #[serde(flatten)]
+    pub stats: RunStats,
+}
+
+/// 以 `seed` 开一局，由机器人游玩直到死亡或存活满 `max_time` 秒。
+/// 沿用 `world` 当前的调参、图案与升级；对局以演示模式进行，不会改变存档进度。
+pub fn play_run(world: &mut World, seed: u64, max_time: f32) -> BotRun {
+    let mut bot = Bot;
+    world.start_autoplay_seeded(seed);
+    while world.state == GameState::Running && world.survival_time < max_time {
+        let input = bot.poll(world);
+        crate::update::step_world(world, &input, SIM_DT);
+    }
+    let mut stats = world.run_stats();
+    stats.survival_time = stats.survival_time.min(max_time);
+    let run = BotRun { seed, stats };
+    world.stop_autoplay();
+    run
+}
+
+/// 一组样本的分布统计。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Distribution {
+    pub count: usize,
+    pub mean: f32,
+    pub min: f32,
+    pub p10: f32,
+    pub median: f32,
+    pub p90: f32,
+    pub max: f32,
+}
+
+impl Distribution {
+    /// 统计样本；样本为空时返回 `None`。
+    pub fn from_samples(samples: &[f32]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f32::total_cmp);
+        // 最近秩法取分位数。
+        let percentile = |p: f32| {
+            let rank = (p * sorted.len() as f32).ceil() as usize;
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        };
+        Some(Self {
+            count: sorted.len(),
+            mean: sorted.iter().sum::<f32>() / sorted.len() as f32,
+            min: sorted[0],
+            p10: percentile(0.1),
+            median: percentile(0.5),
+            p90: percentile(0.9),
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mean {:.1}  min {:.1}  p10 {:.1}  median {:.1}  p90 {:.1}  max {:.1}",
+            self.mean, self.min, self.p10, self.median, self.p90, self.max
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use crate::constants::*;
+use crate::rng::Rng;
+use crate::types::CollectibleKind;
+use crate::utils::Rect;
+
+/// 场景中的可收集物体，包含漂浮动画相位。
+pub struct Collectible {
+    pub kind: CollectibleKind,
+    pub rect: Rect,
+    /// 上一个模拟步结束时的外形，供渲染插值。
+    pub prev_rect: Rect,
+    pub value: u32,
+    pub float_phase: f32,
+}
+
+impl Collectible {
+    /// 利用随机数控制生成位置，价值由调参决定。
+    pub fn new(kind: CollectibleKind, value: u32, ground_y: f32, rng: &mut Rng) -> Self {
+        match kind {
+            CollectibleKind::Coin => {
+                let size = 24.0;
+                let x = SCREEN_WIDTH + rng.gen_range(40.0, 200.0);
+                let y = ground_y - PLAYER_SIZE.y - rng.gen_range(40.0, 130.0);
+                let rect = Rect::new(x, y, size, size);
+                Self {
+                    kind,
+                    rect,
+                    prev_rect: rect,
+                    value,
+                    float_phase: rng.gen_range(0.0, 360.0),
+                }
+            }
+            CollectibleKind::Gem => {
+                let size = 30.0;
+                let x = SCREEN_WIDTH + rng.gen_range(240.0, 380.0);
+                let y = ground_y - PLAYER_SIZE.y - rng.gen_range(70.0, 220.0);
+                let rect = Rect::new(x, y, size, size);
+                Self {
+                    kind,
+                    rect,
+                    prev_rect: rect,
+                    value,
+                    float_phase: rng.gen_range(0.0, 360.0),
+                }
+            }
+        }
+    }
+
+    /// 更新位置与漂浮相位，响应整体卷轴速度。
+    pub fn update(&mut self, dt: f32, speed: f32) {
+        self.rect.x -= speed * dt;
+        self.float_phase += dt * 3.0;
+    }
+
+    /// 在上一步与当前步之间插值出渲染外形。
+    pub fn interpolated_rect(&self, alpha: f32) -> Rect {
+        self.prev_rect.lerp(&self.rect, alpha)
+    }
+
+    /// 判断物体是否完全离开屏幕，用于回收。
+    pub fn is_offscreen(&self) -> bool {
+        self.rect.x + self.rect.w < -60.0
+    }
+}
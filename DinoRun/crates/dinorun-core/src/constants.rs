@@ -0,0 +1,34 @@
+use glam::Vec2;
+
+// 画面配置 -----------------------------------------------------------------
+// 游戏基准分辨率（像素），被窗口配置与渲染逻辑引用。
+pub const SCREEN_WIDTH: f32 = 1100.0;
+pub const SCREEN_HEIGHT: f32 = 620.0;
+
+// 地面与玩家 -----------------------------------------------------------------
+// 计算场景地板的基线位置以及玩家初始尺寸与出生坐标。
+pub const BASE_GROUND_Y: f32 = SCREEN_HEIGHT - 112.0;
+pub const PLAYER_SIZE: Vec2 = Vec2 { x: 56.0, y: 72.0 };
+pub const PLAYER_START: Vec2 = Vec2 {
+    x: 160.0,
+    y: BASE_GROUND_Y - PLAYER_SIZE.y,
+};
+
+// 手感与节奏相关的数值（重力、滚屏、体力、得分等）见 `tuning.rs`。
+
+// 游戏时序 -------------------------------------------------------------------
+// 昼夜循环长度以及菜单淡入时长。
+pub const DAY_NIGHT_DURATION: f32 = 45.0;
+pub const MENU_FADE_TIME: f32 = 1.4;
+
+// 粒子系统 -------------------------------------------------------------------
+// 预分配的粒子数量上限，用于粒子池初始化。
+pub const MAX_PARTICLES: usize = 120;
+// 粒子下落的重力加速度，只影响表现。
+pub const PARTICLE_GRAVITY: f32 = 520.0;
+
+// 固定步长 -------------------------------------------------------------------
+// 模拟以固定频率推进，与显示器刷新率无关；单帧时间上限防止卡顿后追帧雪崩。
+pub const SIM_HZ: f32 = 120.0;
+pub const SIM_DT: f32 = 1.0 / SIM_HZ;
+pub const MAX_FRAME_TIME: f32 = 0.25;
@@ -0,0 +1,45 @@
+//! 玩法事件总线：模拟步中发生的事情按顺序写入 `World::events`，玩法代码只负责发出事件。
+//!
+//! 每个模拟步开始时清空队列，步末由 `step_world` 依次交给核心内的订阅者（单局统计、粒子与提示文字、成就）；
+//! 渲染层的音效、工具里的遥测等在 `step_world` 返回后读取同一队列即可，不需要改动模拟代码。
+
+use crate::types::{CollectibleKind, ObstacleKind, PowerUpKind};
+use crate::utils::Rect;
+use glam::Vec2;
+
+/// 一个模拟步内发生的玩法事件。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameEvent {
+    /// 新的一局开始。
+    RunStarted,
+    /// 起跳；`air` 表示空中跳跃，`origin` 为扬尘位置。
+    Jumped { air: bool, origin: Vec2 },
+    /// 从空中落回地面，`speed` 为触地时的下落速度。
+    Landed { speed: f32 },
+    /// 开始消耗体力冲刺。
+    DashStarted,
+    /// 开始滑铲，`origin` 为扬尘位置。
+    SlideStarted { origin: Vec2 },
+    /// 拾取收集物；`streak` 与 `combo` 为拾取后的连击倍率与连击数。
+    Collected {
+        kind: CollectibleKind,
+        value: u32,
+        rect: Rect,
+        streak: f32,
+        combo: u32,
+    },
+    /// 障碍完整地落到玩家身后。
+    ObstacleCleared(ObstacleKind),
+    /// 与障碍擦身而过：越过时与伤害判定框的最近距离小于阈值。
+    NearMiss(ObstacleKind),
+    /// 拾取强化道具并生效，`at` 为道具中心。
+    PowerUpStarted { kind: PowerUpKind, at: Vec2 },
+    /// 强化效果结束：计时耗尽、护盾被击碎或被新的道具替换。
+    PowerUpEnded(PowerUpKind),
+    /// 护盾替玩家挡下一次撞击，`rect` 为被撞碎的障碍。
+    ShieldBroke { rect: Rect },
+    /// 存活时间跨过一个整秒。
+    Survived { seconds: u32 },
+    /// 撞上障碍，本局结束；`at` 为玩家中心。
+    Died { killed_by: ObstacleKind, at: Vec2 },
+}
@@ -0,0 +1,249 @@
+//! 手柄输入：把各手柄的按钮与左摇杆状态翻译成按键名，经与键盘相同的按键绑定生成 [`InputFrame`]。
+//!
+//! 具体设备由渲染层的 [`GamepadBackend`] 提供，核心只处理热插拔、摇杆死区与按下沿检测；
+//! [`VirtualGamepad`] 可在测试中代替真实手柄。
+
+use crate::input::{InputFrame, InputSource};
+use crate::types::GameState;
+use crate::world::World;
+use glam::Vec2;
+use std::collections::BTreeMap;
+
+/// 摇杆在某个方向上的分量超过该值才视为按下，过滤静止时的漂移。
+pub const STICK_DEAD_ZONE: f32 = 0.35;
+
+/// 手柄按钮，按位置而非字母命名，兼容不同厂商的布局。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PadButton {
+    South,
+    East,
+    West,
+    North,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl PadButton {
+    pub const ALL: [PadButton; 14] = [
+        PadButton::South,
+        PadButton::East,
+        PadButton::West,
+        PadButton::North,
+        PadButton::LeftBumper,
+        PadButton::RightBumper,
+        PadButton::LeftTrigger,
+        PadButton::RightTrigger,
+        PadButton::Select,
+        PadButton::Start,
+        PadButton::DPadUp,
+        PadButton::DPadDown,
+        PadButton::DPadLeft,
+        PadButton::DPadRight,
+    ];
+
+    /// 在按键绑定中使用的名称。
+    pub fn name(self) -> &'static str {
+        match self {
+            PadButton::South => "PadSouth",
+            PadButton::East => "PadEast",
+            PadButton::West => "PadWest",
+            PadButton::North => "PadNorth",
+            PadButton::LeftBumper => "PadLB",
+            PadButton::RightBumper => "PadRB",
+            PadButton::LeftTrigger => "PadLT",
+            PadButton::RightTrigger => "PadRT",
+            PadButton::Select => "PadSelect",
+            PadButton::Start => "PadStart",
+            PadButton::DPadUp => "PadUp",
+            PadButton::DPadDown => "PadDown",
+            PadButton::DPadLeft => "PadLeft",
+            PadButton::DPadRight => "PadRight",
+        }
+    }
+}
+
+/// 一个手柄当前的状态。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PadState {
+    /// 正在按住的按钮。
+    pub buttons: Vec<PadButton>,
+    /// 左摇杆，各分量范围 -1.0..=1.0，y 向下为正（与屏幕坐标一致）。
+    pub stick: Vec2,
+}
+
+impl PadState {
+    /// 按住的按钮与越过死区的摇杆方向在按键绑定中的名称。
+    pub fn input_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self.buttons.iter().map(|b| b.name()).collect();
+        let directions = [
+            (-self.stick.y, "StickUp"),
+            (self.stick.y, "StickDown"),
+            (-self.stick.x, "StickLeft"),
+            (self.stick.x, "StickRight"),
+        ];
+        for (amount, name) in directions {
+            if amount > STICK_DEAD_ZONE {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+/// 一个已连接的手柄。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pad {
+    /// 设备编号，在手柄保持连接期间不变。
+    pub id: usize,
+    pub name: String,
+    pub state: PadState,
+}
+
+/// 手柄设备接口：每帧返回当前连接的所有手柄。
+pub trait GamepadBackend {
+    fn pads(&mut self) -> Vec<Pad>;
+}
+
+impl<B: GamepadBackend + ?Sized> GamepadBackend for Box<B> {
+    fn pads(&mut self) -> Vec<Pad> {
+        (**self).pads()
+    }
+}
+
+/// 没有手柄支持时使用的空实现。
+pub struct NullGamepad;
+
+impl GamepadBackend for NullGamepad {
+    fn pads(&mut self) -> Vec<Pad> {
+        Vec::new()
+    }
+}
+
+/// 测试用的虚拟手柄，直接设置连接状态、按钮与摇杆。
+#[derive(Clone, Debug, Default)]
+pub struct VirtualGamepad {
+    pads: Vec<Pad>,
+}
+
+impl VirtualGamepad {
+    pub fn connect(&mut self, id: usize, name: &str) {
+        self.disconnect(id);
+        self.pads.push(Pad {
+            id,
+            name: name.to_string(),
+            state: PadState::default(),
+        });
+    }
+
+    pub fn disconnect(&mut self, id: usize) {
+        self.pads.retain(|pad| pad.id != id);
+    }
+
+    pub fn press(&mut self, id: usize, button: PadButton) {
+        if let Some(state) = self.state_mut(id)
+            && !state.buttons.contains(&button)
+        {
+            state.buttons.push(button);
+        }
+    }
+
+    pub fn release(&mut self, id: usize, button: PadButton) {
+        if let Some(state) = self.state_mut(id) {
+            state.buttons.retain(|held| *held != button);
+        }
+    }
+
+    pub fn set_stick(&mut self, id: usize, stick: Vec2) {
+        if let Some(state) = self.state_mut(id) {
+            state.stick = stick;
+        }
+    }
+
+    fn state_mut(&mut self, id: usize) -> Option<&mut PadState> {
+        self.pads
+            .iter_mut()
+            .find(|pad| pad.id == id)
+            .map(|pad| &mut pad.state)
+    }
+}
+
+impl GamepadBackend for VirtualGamepad {
+    fn pads(&mut self) -> Vec<Pad> {
+        self.pads.clone()
+    }
+}
+
+/// 手柄输入来源：合并所有已连接手柄，按设置中的按键绑定生成输入。
+/// 对局中有手柄断开时自动暂停。
+pub struct GamepadInput<B: GamepadBackend> {
+    backend: B,
+    /// 上一帧各手柄按住的输入名，用于检测按下沿与断开。
+    previous: BTreeMap<usize, Vec<&'static str>>,
+    /// 最近一次 `poll` 中新按下的输入名。
+    pressed: Vec<&'static str>,
+}
+
+impl<B: GamepadBackend> GamepadInput<B> {
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            previous: BTreeMap::new(),
+            pressed: Vec::new(),
+        }
+    }
+
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// 当前连接的手柄数。
+    pub fn connected(&self) -> usize {
+        self.previous.len()
+    }
+
+    /// 最近一次 `poll` 中新按下的某个输入名，供按键设置页改绑。
+    pub fn last_pressed(&self) -> Option<&'static str> {
+        self.pressed.first().copied()
+    }
+
+    /// 最近一次 `poll` 中新按下的全部输入名，供不进入输入帧的界面动作查询。
+    pub fn pressed(&self) -> &[&'static str] {
+        &self.pressed
+    }
+}
+
+impl<B: GamepadBackend> InputSource for GamepadInput<B> {
+    fn poll(&mut self, world: &World) -> InputFrame {
+        let mut pressed = Vec::new();
+        let mut held = Vec::new();
+        let mut current = BTreeMap::new();
+        for pad in self.backend.pads() {
+            let names = pad.state.input_names();
+            // 新连接的手柄没有上一帧，此时按住的按钮也算作按下。
+            let before = self.previous.get(&pad.id);
+            pressed.extend(
+                names
+                    .iter()
+                    .filter(|name| before.is_none_or(|before| !before.contains(name))),
+            );
+            held.extend(names.iter().copied());
+            current.insert(pad.id, names);
+        }
+        let disconnected = self.previous.keys().any(|id| !current.contains_key(id));
+        self.previous = current;
+        let mut frame = world.settings.controls.frame(&pressed, &held);
+        if disconnected && world.state == GameState::Running {
+            frame.pause_pressed = true;
+        }
+        self.pressed = pressed;
+        frame
+    }
+}
@@ -0,0 +1,349 @@
+use crate::achievements::Achievements;
+use crate::bindings::{ControlsItem, ControlsPage};
+use crate::constants::*;
+use crate::events::GameEvent;
+use crate::menu::{MainMenuItem, MenuAction, MenuCursor, PauseMenuItem, SettingsItem};
+use crate::player::Player;
+use crate::shop::{UpgradeKind, Upgrades};
+use crate::tuning::Tuning;
+use crate::types::{GameState, PlayerAction, PowerUpKind};
+use crate::world::World;
+use glam::{Vec2, vec2};
+
+/// 单帧的抽象输入，与具体设备无关；玩法逻辑只读取这个值。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InputFrame {
+    pub jump_pressed: bool,
+    /// 跳跃键是否仍按住；上升途中松开会截断跳跃高度。
+    pub jump_held: bool,
+    pub dash_held: bool,
+    pub slide_held: bool,
+    /// 暂停/恢复；在结算界面表示返回菜单。
+    pub pause_pressed: bool,
+    pub menu_up: bool,
+    pub menu_down: bool,
+    /// 在菜单中调整滑块或切换选项。
+    pub menu_left: bool,
+    pub menu_right: bool,
+    pub menu_confirm: bool,
+}
+
+impl InputFrame {
+    /// 合并新一帧的采样：按下类事件累积直到被模拟步消费，按住类状态取最新值。
+    pub fn accumulate(&mut self, latest: &InputFrame) {
+        self.jump_pressed |= latest.jump_pressed;
+        self.jump_held = latest.jump_held;
+        self.pause_pressed |= latest.pause_pressed;
+        self.menu_up |= latest.menu_up;
+        self.menu_down |= latest.menu_down;
+        self.menu_left |= latest.menu_left;
+        self.menu_right |= latest.menu_right;
+        self.menu_confirm |= latest.menu_confirm;
+        self.dash_held = latest.dash_held;
+        self.slide_held = latest.slide_held;
+    }
+
+    /// 合并两个设备同一帧的输入，任一设备按下或按住即生效。
+    pub fn merge(&self, other: &InputFrame) -> InputFrame {
+        InputFrame {
+            jump_pressed: self.jump_pressed || other.jump_pressed,
+            jump_held: self.jump_held || other.jump_held,
+            dash_held: self.dash_held || other.dash_held,
+            slide_held: self.slide_held || other.slide_held,
+            pause_pressed: self.pause_pressed || other.pause_pressed,
+            menu_up: self.menu_up || other.menu_up,
+            menu_down: self.menu_down || other.menu_down,
+            menu_left: self.menu_left || other.menu_left,
+            menu_right: self.menu_right || other.menu_right,
+            menu_confirm: self.menu_confirm || other.menu_confirm,
+        }
+    }
+
+    /// 清除已被消费的按下类事件，保留按住类状态。
+    pub fn clear_pressed(&mut self) {
+        *self = InputFrame {
+            jump_held: self.jump_held,
+            dash_held: self.dash_held,
+            slide_held: self.slide_held,
+            ..InputFrame::default()
+        };
+    }
+}
+
+/// 输入来源：键盘、手柄、脚本、录像或 AI 每帧产出一个 [`InputFrame`]。
+pub trait InputSource {
+    /// 读取当前帧的输入，`world` 供需要观察场景的来源（如 AI）使用。
+    fn poll(&mut self, world: &World) -> InputFrame;
+}
+
+/// 按顺序回放预先编排的输入帧，结束后持续返回空输入。
+pub struct ScriptedInput {
+    frames: Vec<InputFrame>,
+    cursor: usize,
+}
+
+impl ScriptedInput {
+    pub fn new(frames: Vec<InputFrame>) -> Self {
+        Self { frames, cursor: 0 }
+    }
+
+    /// 已经输出的帧数。
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// 脚本总帧数。
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// 脚本是否已经全部播放完毕。
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.frames.len()
+    }
+}
+
+impl InputSource for ScriptedInput {
+    fn poll(&mut self, _world: &World) -> InputFrame {
+        let frame = self.frames.get(self.cursor).copied().unwrap_or_default();
+        self.cursor += 1;
+        frame
+    }
+}
+
+/// 统一处理玩家输入，根据当前状态机执行操作或切换状态。
+pub fn apply_input(world: &mut World, input: &InputFrame, dt: f32) {
+    if input.pause_pressed {
+        match world.state {
+            GameState::Running => {
+                world.state = GameState::Paused;
+                world.pause_flash = 1.0;
+                world.pause_menu = MenuCursor::default();
+            }
+            GameState::Paused => {
+                world.state = GameState::Running;
+            }
+            GameState::Shop | GameState::Achievements | GameState::Settings => {
+                world.state = GameState::Menu { fade: 1.0 };
+                return;
+            }
+            GameState::Controls => {
+                if !world.controls_page.capturing {
+                    world.state = GameState::Settings;
+                }
+                return;
+            }
+            GameState::Menu { .. } | GameState::Splash { .. } => {}
+            GameState::GameOver { .. } => {}
+        }
+    }
+
+    match world.state {
+        GameState::Splash { .. } => {}
+        GameState::Menu { .. } => {
+            let action = world.main_menu.navigate(input, MainMenuItem::ALL.len());
+            if action == Some(MenuAction::Confirm) {
+                match MainMenuItem::ALL[world.main_menu.selected] {
+                    MainMenuItem::StartRun => {
+                        world.reset_run();
+                        world.state = GameState::Running;
+                    }
+                    MainMenuItem::Shop => {
+                        world.shop_menu.selected = 0;
+                        world.state = GameState::Shop;
+                    }
+                    MainMenuItem::Achievements => world.state = GameState::Achievements,
+                    MainMenuItem::Settings => {
+                        world.settings_menu.selected = 0;
+                        world.state = GameState::Settings;
+                    }
+                    MainMenuItem::AttractMode => world.start_autoplay(),
+                    MainMenuItem::InstantShield => {
+                        world.power_up.activate(PowerUpKind::Shield);
+                        world.power_up.timer = 0.1;
+                    }
+                    MainMenuItem::ResetProgress => {
+                        world.reset_run();
+                        world.best_score = 0.0;
+                        world.best_run = None;
+                        world.achievements = Achievements::default();
+                        world.currency = 0;
+                        world.upgrades = Upgrades::default();
+                    }
+                }
+            }
+        }
+        GameState::Shop => {
+            let action = world.shop_menu.navigate(input, UpgradeKind::ALL.len());
+            if action == Some(MenuAction::Confirm) {
+                let kind = UpgradeKind::ALL[world.shop_menu.selected];
+                world.upgrades.purchase(kind, &mut world.currency);
+            }
+        }
+        GameState::Settings => {
+            let action = world.settings_menu.navigate(input, SettingsItem::ALL.len());
+            match (SettingsItem::ALL[world.settings_menu.selected], action) {
+                (SettingsItem::Controls, Some(MenuAction::Confirm)) => {
+                    world.controls_page = ControlsPage::default();
+                    world.state = GameState::Controls;
+                }
+                (item, Some(action)) => item.apply(&mut world.settings, action),
+                (_, None) => {}
+            }
+        }
+        GameState::Controls => {
+            // 等待按键期间，按键由渲染层交给 `ControlsPage::capture`，不作为菜单输入。
+            let page = &mut world.controls_page;
+            if !page.capturing
+                && let Some(action) = page.cursor.navigate(input, ControlsItem::ALL.len())
+            {
+                page.apply(&mut world.settings.controls, action);
+            }
+        }
+        GameState::Achievements => {}
+        GameState::Running => {
+            let ground = world.difficulty.ground_y(&world.tuning);
+            if input.dash_held && world.player.stamina > 0.0 {
+                // 冲刺时水平速度等于冲刺速度（滑铲冲量不会超过它），由此区分刚开始冲刺与持续冲刺。
+                if world.player.vel.x < world.tuning.player.dash_speed {
+                    world.events.push(GameEvent::DashStarted);
+                }
+                world.stats.dash_time += dt;
+            }
+            let was_sliding = world.player.sliding;
+            let takeoff = control_player(&mut world.player, input, dt, ground, &world.tuning);
+            if let Some(takeoff) = takeoff {
+                world.events.push(GameEvent::Jumped {
+                    air: takeoff.air,
+                    origin: takeoff.dust_origin,
+                });
+            }
+            if world.player.sliding && !was_sliding {
+                let p = &world.player;
+                world.events.push(GameEvent::SlideStarted {
+                    origin: p.pos + vec2(PLAYER_SIZE.x * 0.5, PLAYER_SIZE.y),
+                });
+            }
+        }
+        GameState::Paused => {
+            let action = world.pause_menu.navigate(input, PauseMenuItem::ALL.len());
+            if action == Some(MenuAction::Confirm) {
+                match PauseMenuItem::ALL[world.pause_menu.selected] {
+                    PauseMenuItem::Resume => world.state = GameState::Running,
+                    PauseMenuItem::QuitToMenu => {
+                        world.record_run();
+                        world.state = GameState::Menu { fade: 0.0 };
+                    }
+                }
+            }
+        }
+        GameState::GameOver { cooldown } => {
+            if cooldown <= 0.0 {
+                if input.menu_confirm {
+                    world.reset_run();
+                    world.state = GameState::Running;
+                }
+                if input.pause_pressed {
+                    world.state = GameState::Menu { fade: 0.0 };
+                }
+            }
+        }
+    }
+}
+
+/// 一次起跳：是否为空中跳跃，以及扬尘的位置。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Takeoff {
+    pub air: bool,
+    pub dust_origin: Vec2,
+}
+
+/// 把一帧输入作用到奔跑中的玩家：跳跃、冲刺与滑铲。
+/// 滑铲贴地按下开始并扣除体力，至少持续最短时长，起跳会立即取消滑铲。
+/// 离地后的土狼时间内仍可地面起跳；没有可用跳跃时按下的跳跃会被缓冲，落地后自动起跳。
+/// 地面起跳与空中跳跃都在松开跳跃键时截断上升速度，按住越久跳得越高。
+/// 起跳时返回 [`Takeoff`]，由调用方决定是否发出事件。
+pub fn control_player(
+    p: &mut Player,
+    input: &InputFrame,
+    dt: f32,
+    ground: f32,
+    tuning: &Tuning,
+) -> Option<Takeoff> {
+    let mut takeoff = None;
+    p.jump_buffer_timer = (p.jump_buffer_timer - dt).max(0.0);
+    let wants_jump = input.jump_pressed || p.jump_buffer_timer > 0.0;
+    if wants_jump && (p.on_ground(ground) || p.coyote_timer > 0.0) {
+        p.vel.y = -tuning.player.jump_velocity;
+        p.action = PlayerAction::Jump;
+        p.action_timer = 0.32;
+        p.air_jumps_left = p.air_jumps;
+        p.coyote_timer = 0.0;
+        p.jump_buffer_timer = 0.0;
+        p.animator.squash(-0.6);
+        takeoff = Some(Takeoff {
+            air: false,
+            dust_origin: p.pos + vec2(PLAYER_SIZE.x * 0.5, PLAYER_SIZE.y),
+        });
+    } else if input.jump_pressed && p.air_jumps_left > 0 {
+        p.vel.y = -tuning.player.air_jump_velocity;
+        p.action = PlayerAction::DoubleJump;
+        p.action_timer = 0.28;
+        p.air_jumps_left -= 1;
+        p.animator.squash(-0.4);
+        takeoff = Some(Takeoff {
+            air: true,
+            dust_origin: p.pos + vec2(PLAYER_SIZE.x * 0.5, PLAYER_SIZE.y * 0.4),
+        });
+    } else if input.jump_pressed {
+        p.jump_buffer_timer = tuning.player.jump_buffer;
+    }
+    // 截断后剩余的上升速度恰好够到最低跳跃高度，刚起跳就松开也不会低于它。
+    if !input.jump_held {
+        p.vel.y = p.vel.y.max(-tuning.player.jump_cut_velocity());
+    }
+
+    let dashing = input.dash_held && p.stamina > 0.0;
+    if dashing {
+        p.vel.x = tuning.player.dash_speed;
+        p.stamina = (p.stamina - tuning.stamina.consume_rate * dt).max(0.0);
+        p.action = PlayerAction::Dash;
+        p.action_timer = 0.2;
+    } else if !input.dash_held {
+        p.stamina = (p.stamina + p.stamina_recover_rate * dt).min(p.max_stamina);
+        p.vel.x = 0.0;
+    }
+
+    let slide = &tuning.slide;
+    p.slide_timer = (p.slide_timer - dt).max(0.0);
+    p.invulnerable_timer = (p.invulnerable_timer - dt).max(0.0);
+    if takeoff.is_some() || !p.on_ground(ground) {
+        // 起跳取消滑铲，最短时长与无敌窗口一并作废。
+        p.sliding = false;
+        p.slide_timer = 0.0;
+        p.invulnerable_timer = 0.0;
+    } else if input.slide_held && !p.sliding && p.stamina >= slide.stamina_cost {
+        // 体力不足一次消耗时无法起滑，连续滑铲不能无限续上无敌窗口。
+        p.sliding = true;
+        p.slide_timer = slide.min_duration;
+        p.invulnerable_timer = slide.invulnerability;
+        p.stamina -= slide.stamina_cost;
+    } else if p.sliding && !input.slide_held && p.slide_timer == 0.0 {
+        p.sliding = false;
+        if p.action == PlayerAction::Slide {
+            p.action = PlayerAction::Running;
+        }
+    }
+    if p.sliding {
+        p.action = PlayerAction::Slide;
+        // 冲刺时由冲刺速度接管，否则起滑冲量在最短时长内衰减。
+        if !dashing {
+            p.vel.x = slide.speed * p.slide_timer / slide.min_duration;
+        }
+    }
+    takeoff
+}
@@ -0,0 +1,37 @@
+//! Dino Run 的无窗口模拟核心：世界状态、实体与逐帧更新流程。
+//!
+//! 本库不依赖任何 macroquad 渲染接口，可在 CI 或工具中直接批量模拟，
+//! 渲染层只读取这里的状态并负责绘制。
+
+pub mod achievements;
+pub mod animation;
+pub mod audio;
+pub mod bindings;
+pub mod bot;
+pub mod collectibles;
+pub mod constants;
+pub mod events;
+pub mod gamepad;
+pub mod input;
+pub mod locale;
+pub mod menu;
+pub mod obstacles;
+pub mod particles;
+pub mod patterns;
+pub mod player;
+pub mod powerups;
+pub mod replay;
+pub mod rng;
+pub mod save;
+pub mod settings;
+pub mod shop;
+pub mod solver;
+pub mod stats;
+pub mod timestep;
+pub mod tuning;
+pub mod types;
+pub mod update;
+pub mod utils;
+pub mod world;
+
+pub use glam::{Vec2, vec2};
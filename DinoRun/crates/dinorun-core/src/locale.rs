@@ -0,0 +1,361 @@
+//! 界面文字的多语言表。
+//!
+//! 翻译菜单、设置、HUD、结算界面与成就等文案；升级与障碍名称等游戏数据仍为英文。
+//! 默认字体只覆盖 Latin-1，新增语言时需确认字符都在该范围内。
+
+use serde::{Deserialize, Serialize};
+
+/// 界面语言；在设置文件中以小写名称书写。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::English, Language::Spanish, Language::German];
+
+    /// 以该语言自身书写的名称，在任何界面语言下都这样显示。
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::German => "Deutsch",
+        }
+    }
+}
+
+/// 需要翻译的界面文字。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Text {
+    PressSpace,
+    StartRun,
+    Shop,
+    Achievements,
+    Settings,
+    AttractMode,
+    InstantShield,
+    ResetProgress,
+    BestDistance,
+    ShopTitle,
+    Currency,
+    Max,
+    ShopHint,
+    AchievementsTitle,
+    Unlocked,
+    AchievementUnlocked,
+    AchievementPitHopper,
+    GoalPitHopper,
+    AchievementSawDodger,
+    GoalSawDodger,
+    AchievementOnARoll,
+    GoalOnARoll,
+    AchievementTreasureHunter,
+    GoalTreasureHunter,
+    AchievementFrequentFlyer,
+    GoalFrequentFlyer,
+    AchievementPowerHungry,
+    GoalPowerHungry,
+    AchievementLuckyBreak,
+    GoalLuckyBreak,
+    AchievementPurist,
+    GoalPurist,
+    AchievementMarathon,
+    GoalMarathon,
+    BackHint,
+    SettingsTitle,
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    Fullscreen,
+    Vsync,
+    ScreenShake,
+    ParticleDensity,
+    ShowFps,
+    Language,
+    Colorblind,
+    Controls,
+    On,
+    Off,
+    Low,
+    Medium,
+    High,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+    SettingsHint,
+    ControlsTitle,
+    ActionJump,
+    ActionDash,
+    ActionSlide,
+    ActionPause,
+    ActionMenuUp,
+    ActionMenuDown,
+    ActionMenuLeft,
+    ActionMenuRight,
+    ActionConfirm,
+    ActionClearKey,
+    ActionWatchReplay,
+    ActionReplayPause,
+    ActionReplayStep,
+    ActionReplaySlower,
+    ActionReplayFaster,
+    ActionReplayRestart,
+    ActionReplayExit,
+    ResetDefaults,
+    ControlsHint,
+    ReplayHint,
+    PressKey,
+    KeyMoved,
+    KeyRefused,
+    Distance,
+    Best,
+    Combo,
+    Shield,
+    ScoreBoost,
+    TimeSlow,
+    Paused,
+    Resume,
+    QuitToMenu,
+    RunOver,
+    KilledBy,
+    NewBest,
+    ThisRun,
+    Time,
+    Coins,
+    Gems,
+    MaxCombo,
+    BestStreak,
+    Jumps,
+    DashTime,
+    PowerUps,
+    CurrencyEarned,
+    Retry,
+    GameOverHint,
+    AttractBanner,
+}
+
+impl Text {
+    /// 取该语言下的文字。
+    pub fn get(self, language: Language) -> &'static str {
+        let [english, spanish, german] = self.translations();
+        match language {
+            Language::English => english,
+            Language::Spanish => spanish,
+            Language::German => german,
+        }
+    }
+
+    /// 取该语言下的文字，并依次用 `args` 替换其中的 `{}`。
+    pub fn format(self, language: Language, args: &[&str]) -> String {
+        let mut pieces = self.get(language).split("{}");
+        let mut text = String::from(pieces.next().unwrap_or_default());
+        for (idx, piece) in pieces.enumerate() {
+            text.push_str(args.get(idx).copied().unwrap_or_default());
+            text.push_str(piece);
+        }
+        text
+    }
+
+    /// 按 [`Language::ALL`] 的顺序排列的译文。
+    fn translations(self) -> [&'static str; 3] {
+        match self {
+            Text::PressSpace => ["Press Space", "Pulsa Espacio", "Leertaste drücken"],
+            Text::StartRun => ["Start Run", "Jugar", "Lauf starten"],
+            Text::Shop => ["Shop", "Tienda", "Laden"],
+            Text::Achievements => ["Achievements", "Logros", "Erfolge"],
+            Text::Settings => ["Settings", "Ajustes", "Einstellungen"],
+            Text::AttractMode => ["Attract Mode", "Modo demo", "Demomodus"],
+            Text::InstantShield => ["Instant Shield", "Escudo inmediato", "Sofortschild"],
+            Text::ResetProgress => ["Reset Progress", "Borrar progreso", "Fortschritt löschen"],
+            Text::BestDistance => ["Best Distance", "Mejor distancia", "Bestweite"],
+            Text::ShopTitle => ["SHOP", "TIENDA", "LADEN"],
+            Text::Currency => ["Currency", "Monedas", "Währung"],
+            Text::Max => ["MAX", "MÁX", "MAX"],
+            Text::ShopHint => [
+                "Enter buy  Esc back",
+                "Enter comprar  Esc volver",
+                "Enter kaufen  Esc zurück",
+            ],
+            Text::AchievementsTitle => ["ACHIEVEMENTS", "LOGROS", "ERFOLGE"],
+            Text::Unlocked => ["unlocked", "desbloqueados", "freigeschaltet"],
+            Text::AchievementUnlocked => ["Achievement: {}", "Logro: {}", "Erfolg: {}"],
+            Text::AchievementPitHopper => ["Pit Hopper", "Saltafosos", "Grubenhüpfer"],
+            Text::GoalPitHopper => [
+                "Clear 10 pits in one run",
+                "Supera 10 fosos en una partida",
+                "10 Gruben in einem Lauf überwinden",
+            ],
+            Text::AchievementSawDodger => ["Saw Dodger", "Esquivasierras", "Sägenflitzer"],
+            Text::GoalSawDodger => [
+                "Clear 25 saws in one run",
+                "Supera 25 sierras en una partida",
+                "25 Sägen in einem Lauf überwinden",
+            ],
+            Text::AchievementOnARoll => ["On a Roll", "Imparable", "Voll in Fahrt"],
+            Text::GoalOnARoll => [
+                "Reach a x3.0 streak",
+                "Alcanza una racha de x3.0",
+                "Eine Serie von x3.0 erreichen",
+            ],
+            Text::AchievementTreasureHunter => ["Treasure Hunter", "Cazatesoros", "Schatzjäger"],
+            Text::GoalTreasureHunter => [
+                "Collect 5 gems in one run",
+                "Recoge 5 gemas en una partida",
+                "5 Edelsteine in einem Lauf sammeln",
+            ],
+            Text::AchievementFrequentFlyer => {
+                ["Frequent Flyer", "Viajero frecuente", "Vielflieger"]
+            }
+            Text::GoalFrequentFlyer => [
+                "Air jump 50 times in one run",
+                "Salta 50 veces en el aire en una partida",
+                "50 Luftsprünge in einem Lauf",
+            ],
+            Text::AchievementPowerHungry => ["Power Hungry", "Ansia de poder", "Machthungrig"],
+            Text::GoalPowerHungry => [
+                "Trigger 3 power-ups in one run",
+                "Activa 3 potenciadores en una partida",
+                "3 Power-ups in einem Lauf auslösen",
+            ],
+            Text::AchievementLuckyBreak => ["Lucky Break", "Golpe de suerte", "Glück gehabt"],
+            Text::GoalLuckyBreak => [
+                "Let a shield absorb a hit",
+                "Deja que un escudo absorba un golpe",
+                "Einen Treffer mit dem Schild abfangen",
+            ],
+            Text::AchievementPurist => ["Purist", "Purista", "Purist"],
+            Text::GoalPurist => [
+                "Survive 120 s without dashing",
+                "Sobrevive 120 s sin sprint",
+                "120 s ohne Sprinten überleben",
+            ],
+            Text::AchievementMarathon => ["Marathon", "Maratón", "Marathon"],
+            Text::GoalMarathon => ["Survive 300 s", "Sobrevive 300 s", "300 s überleben"],
+            Text::BackHint => ["Esc back", "Esc volver", "Esc zurück"],
+            Text::SettingsTitle => ["SETTINGS", "AJUSTES", "EINSTELLUNGEN"],
+            Text::MasterVolume => ["Master Volume", "Volumen general", "Gesamtlautstärke"],
+            Text::MusicVolume => ["Music Volume", "Volumen de música", "Musiklautstärke"],
+            Text::SfxVolume => ["Effects Volume", "Volumen de efectos", "Effektlautstärke"],
+            Text::Fullscreen => ["Fullscreen", "Pantalla completa", "Vollbild"],
+            Text::Vsync => [
+                "VSync (requires restart)",
+                "VSync (requiere reiniciar)",
+                "VSync (Neustart nötig)",
+            ],
+            Text::ScreenShake => ["Screen Shake", "Vibración de pantalla", "Bildschirmwackeln"],
+            Text::ParticleDensity => ["Particles", "Partículas", "Partikel"],
+            Text::ShowFps => ["Show FPS", "Mostrar FPS", "FPS anzeigen"],
+            Text::Language => ["Language", "Idioma", "Sprache"],
+            Text::Colorblind => [
+                "Colorblind Palette",
+                "Paleta daltónica",
+                "Farbenblind-Palette",
+            ],
+            Text::Controls => ["Controls", "Controles", "Steuerung"],
+            Text::On => ["On", "Sí", "An"],
+            Text::Off => ["Off", "No", "Aus"],
+            Text::Low => ["Low", "Baja", "Niedrig"],
+            Text::Medium => ["Medium", "Media", "Mittel"],
+            Text::High => ["High", "Alta", "Hoch"],
+            Text::Deuteranopia => ["Deuteranopia", "Deuteranopía", "Deuteranopie"],
+            Text::Protanopia => ["Protanopia", "Protanopía", "Protanopie"],
+            Text::Tritanopia => ["Tritanopia", "Tritanopía", "Tritanopie"],
+            Text::SettingsHint => [
+                "Left/Right change  Esc back",
+                "Izq./Der. cambiar  Esc volver",
+                "Links/Rechts ändern  Esc zurück",
+            ],
+            Text::ControlsTitle => ["CONTROLS", "CONTROLES", "STEUERUNG"],
+            Text::ActionJump => ["Jump", "Saltar", "Springen"],
+            Text::ActionDash => ["Dash", "Sprint", "Sprinten"],
+            Text::ActionSlide => ["Slide", "Deslizar", "Rutschen"],
+            Text::ActionPause => ["Pause / Back", "Pausa / Volver", "Pause / Zurück"],
+            Text::ActionMenuUp => ["Menu Up", "Menú arriba", "Menü hoch"],
+            Text::ActionMenuDown => ["Menu Down", "Menú abajo", "Menü runter"],
+            Text::ActionMenuLeft => ["Menu Left", "Menú izquierda", "Menü links"],
+            Text::ActionMenuRight => ["Menu Right", "Menú derecha", "Menü rechts"],
+            Text::ActionConfirm => ["Confirm", "Aceptar", "Bestätigen"],
+            Text::ActionClearKey => ["Clear Key", "Borrar tecla", "Taste löschen"],
+            Text::ActionWatchReplay => ["Watch Replay", "Ver repetición", "Replay ansehen"],
+            Text::ActionReplayPause => ["Replay Pause", "Repet. pausa", "Replay Pause"],
+            Text::ActionReplayStep => ["Replay Step", "Repet. paso", "Replay Schritt"],
+            Text::ActionReplaySlower => ["Replay Slower", "Repet. más lenta", "Replay langsamer"],
+            Text::ActionReplayFaster => ["Replay Faster", "Repet. más rápida", "Replay schneller"],
+            Text::ActionReplayRestart => {
+                ["Replay Restart", "Repet. reiniciar", "Replay neu starten"]
+            }
+            Text::ActionReplayExit => ["Replay Exit", "Repet. salir", "Replay beenden"],
+            Text::ResetDefaults => [
+                "Reset to Defaults",
+                "Restablecer",
+                "Standard wiederherstellen",
+            ],
+            Text::ControlsHint => [
+                "{} rebind  {}/{} slot  {} clear  {} back",
+                "{} cambiar  {}/{} casilla  {} borrar  {} volver",
+                "{} belegen  {}/{} Platz  {} löschen  {} zurück",
+            ],
+            Text::ReplayHint => [
+                "{} pause  {} step  {}/{} speed  {} restart  {} exit",
+                "{} pausa  {} paso  {}/{} velocidad  {} reiniciar  {} salir",
+                "{} Pause  {} Schritt  {}/{} Tempo  {} neu starten  {} beenden",
+            ],
+            Text::PressKey => [
+                "Press a key or button for {}  (Esc cancels)",
+                "Pulsa una tecla o botón para {}  (Esc cancela)",
+                "Taste oder Knopf für {} drücken  (Esc bricht ab)",
+            ],
+            Text::KeyMoved => [
+                "{} was removed from {}",
+                "{} se quitó de {}",
+                "{} wurde von {} entfernt",
+            ],
+            Text::KeyRefused => [
+                "{} is the only key for {}",
+                "{} es la única tecla de {}",
+                "{} ist die einzige Taste für {}",
+            ],
+            Text::Distance => ["Distance", "Distancia", "Distanz"],
+            Text::Best => ["Best", "Récord", "Rekord"],
+            Text::Combo => ["Combo", "Combo", "Kombo"],
+            Text::Shield => ["Shield", "Escudo", "Schild"],
+            Text::ScoreBoost => ["Score Boost", "Puntos extra", "Punktebonus"],
+            Text::TimeSlow => ["Time Slow", "Cámara lenta", "Zeitlupe"],
+            Text::Paused => ["PAUSED", "PAUSA", "PAUSE"],
+            Text::Resume => ["Resume", "Seguir", "Fortsetzen"],
+            Text::QuitToMenu => ["Quit to Menu", "Salir al menú", "Zum Hauptmenü"],
+            Text::RunOver => ["RUN OVER", "FIN DE LA PARTIDA", "LAUF VORBEI"],
+            Text::KilledBy => ["Killed by", "Derribado por", "Erwischt von"],
+            Text::NewBest => ["NEW BEST!", "¡NUEVO RÉCORD!", "NEUER REKORD!"],
+            Text::ThisRun => ["This run", "Esta partida", "Dieser Lauf"],
+            Text::Time => ["Time", "Tiempo", "Zeit"],
+            Text::Coins => ["Coins", "Monedas", "Münzen"],
+            Text::Gems => ["Gems", "Gemas", "Edelsteine"],
+            Text::MaxCombo => ["Max combo", "Combo máximo", "Max. Kombo"],
+            Text::BestStreak => ["Best streak", "Mejor racha", "Beste Serie"],
+            Text::Jumps => ["Jumps", "Saltos", "Sprünge"],
+            Text::DashTime => ["Dash time", "Tiempo de sprint", "Sprintzeit"],
+            Text::PowerUps => ["Power-ups", "Potenciadores", "Power-ups"],
+            Text::CurrencyEarned => ["Currency earned", "Monedas ganadas", "Verdiente Währung"],
+            Text::Retry => [
+                "Press {} to retry",
+                "Pulsa {} para reintentar",
+                "{} für neuen Versuch",
+            ],
+            Text::GameOverHint => [
+                "{} for menu  -  {} to watch replay",
+                "{} menú  -  {} ver repetición",
+                "{} Menü  -  {} Wiederholung ansehen",
+            ],
+            Text::AttractBanner => [
+                "ATTRACT MODE - press any key",
+                "MODO DEMO - pulsa cualquier tecla",
+                "DEMOMODUS - beliebige Taste drücken",
+            ],
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! 列表菜单模型：光标移动、控件取值与各菜单页的条目。
+//!
+//! 菜单页用带 `ALL` 的枚举描述条目，[`MenuCursor`] 负责上下移动并把确认与左右键交给页面解释；
+//! 渲染层按条目的 [`Widget`] 绘制按钮、开关、滑块或选项。
+
+use crate::input::InputFrame;
+use crate::locale::{Language, Text};
+use crate::settings::{ColorblindMode, ParticleDensity, Settings};
+
+/// 滑块每次左右调整的步长。
+pub const SLIDER_STEP: f32 = 0.1;
+
+/// 菜单条目的控件与当前值。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Widget {
+    /// 确认后执行动作。
+    Button,
+    Toggle(bool),
+    /// 0.0..=1.0 的滑块。
+    Slider(f32),
+    /// 在若干选项间循环，显示当前选项的文字。
+    Choice(&'static str),
+}
+
+/// 需要菜单页处理的操作。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuAction {
+    Confirm,
+    /// 左右调整，`-1` 为向左，`1` 为向右。
+    Adjust(i32),
+}
+
+/// 列表菜单的光标。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MenuCursor {
+    pub selected: usize,
+}
+
+impl MenuCursor {
+    /// 按上下键在 `len` 个条目间移动（到头不循环），返回本帧的确认或左右调整。
+    pub fn navigate(&mut self, input: &InputFrame, len: usize) -> Option<MenuAction> {
+        if input.menu_up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if input.menu_down {
+            self.selected = (self.selected + 1).min(len.saturating_sub(1));
+        }
+        if input.menu_confirm {
+            Some(MenuAction::Confirm)
+        } else if input.menu_left {
+            Some(MenuAction::Adjust(-1))
+        } else if input.menu_right {
+            Some(MenuAction::Adjust(1))
+        } else {
+            None
+        }
+    }
+}
+
+/// 主菜单条目，顺序即显示顺序。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MainMenuItem {
+    StartRun,
+    Shop,
+    Achievements,
+    Settings,
+    AttractMode,
+    InstantShield,
+    ResetProgress,
+}
+
+impl MainMenuItem {
+    pub const ALL: [MainMenuItem; 7] = [
+        MainMenuItem::StartRun,
+        MainMenuItem::Shop,
+        MainMenuItem::Achievements,
+        MainMenuItem::Settings,
+        MainMenuItem::AttractMode,
+        MainMenuItem::InstantShield,
+        MainMenuItem::ResetProgress,
+    ];
+
+    pub fn label(self) -> Text {
+        match self {
+            MainMenuItem::StartRun => Text::StartRun,
+            MainMenuItem::Shop => Text::Shop,
+            MainMenuItem::Achievements => Text::Achievements,
+            MainMenuItem::Settings => Text::Settings,
+            MainMenuItem::AttractMode => Text::AttractMode,
+            MainMenuItem::InstantShield => Text::InstantShield,
+            MainMenuItem::ResetProgress => Text::ResetProgress,
+        }
+    }
+}
+
+/// 暂停菜单条目，顺序即显示顺序。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PauseMenuItem {
+    Resume,
+    /// 放弃本局并回到主菜单，本局距离照常计入最佳成绩。
+    QuitToMenu,
+}
+
+impl PauseMenuItem {
+    pub const ALL: [PauseMenuItem; 2] = [PauseMenuItem::Resume, PauseMenuItem::QuitToMenu];
+
+    pub fn label(self) -> Text {
+        match self {
+            PauseMenuItem::Resume => Text::Resume,
+            PauseMenuItem::QuitToMenu => Text::QuitToMenu,
+        }
+    }
+}
+
+/// 设置页条目，顺序即显示顺序。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingsItem {
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    Fullscreen,
+    Vsync,
+    ScreenShake,
+    ParticleDensity,
+    ShowFps,
+    Language,
+    Colorblind,
+    /// 打开按键设置页。
+    Controls,
+}
+
+impl SettingsItem {
+    pub const ALL: [SettingsItem; 11] = [
+        SettingsItem::MasterVolume,
+        SettingsItem::MusicVolume,
+        SettingsItem::SfxVolume,
+        SettingsItem::Fullscreen,
+        SettingsItem::Vsync,
+        SettingsItem::ScreenShake,
+        SettingsItem::ParticleDensity,
+        SettingsItem::ShowFps,
+        SettingsItem::Language,
+        SettingsItem::Colorblind,
+        SettingsItem::Controls,
+    ];
+
+    pub fn label(self) -> Text {
+        match self {
+            SettingsItem::MasterVolume => Text::MasterVolume,
+            SettingsItem::MusicVolume => Text::MusicVolume,
+            SettingsItem::SfxVolume => Text::SfxVolume,
+            SettingsItem::Fullscreen => Text::Fullscreen,
+            SettingsItem::Vsync => Text::Vsync,
+            SettingsItem::ScreenShake => Text::ScreenShake,
+            SettingsItem::ParticleDensity => Text::ParticleDensity,
+            SettingsItem::ShowFps => Text::ShowFps,
+            SettingsItem::Language => Text::Language,
+            SettingsItem::Colorblind => Text::Colorblind,
+            SettingsItem::Controls => Text::Controls,
+        }
+    }
+
+    /// 条目当前的控件与取值；选项文字使用设置中的界面语言。
+    pub fn widget(self, settings: &Settings) -> Widget {
+        let language = settings.language;
+        match self {
+            SettingsItem::MasterVolume => Widget::Slider(settings.master_volume),
+            SettingsItem::MusicVolume => Widget::Slider(settings.music_volume),
+            SettingsItem::SfxVolume => Widget::Slider(settings.sfx_volume),
+            SettingsItem::Fullscreen => Widget::Toggle(settings.fullscreen),
+            SettingsItem::Vsync => Widget::Toggle(settings.vsync),
+            SettingsItem::ScreenShake => Widget::Slider(settings.screen_shake),
+            SettingsItem::ParticleDensity => {
+                Widget::Choice(settings.particle_density.label().get(language))
+            }
+            SettingsItem::ShowFps => Widget::Toggle(settings.show_fps),
+            SettingsItem::Language => Widget::Choice(language.name()),
+            SettingsItem::Colorblind => Widget::Choice(settings.colorblind.label().get(language)),
+            SettingsItem::Controls => Widget::Button,
+        }
+    }
+
+    /// 把确认或左右调整作用到设置：开关翻转，滑块按步长增减，选项循环切换。
+    pub fn apply(self, settings: &mut Settings, action: MenuAction) {
+        let delta = match action {
+            MenuAction::Confirm => 1,
+            MenuAction::Adjust(delta) => delta,
+        };
+        match self {
+            SettingsItem::MasterVolume => slide(&mut settings.master_volume, action),
+            SettingsItem::MusicVolume => slide(&mut settings.music_volume, action),
+            SettingsItem::SfxVolume => slide(&mut settings.sfx_volume, action),
+            SettingsItem::Fullscreen => settings.fullscreen = !settings.fullscreen,
+            SettingsItem::Vsync => settings.vsync = !settings.vsync,
+            SettingsItem::ScreenShake => slide(&mut settings.screen_shake, action),
+            SettingsItem::ParticleDensity => {
+                settings.particle_density =
+                    cycle(&ParticleDensity::ALL, settings.particle_density, delta);
+            }
+            SettingsItem::ShowFps => settings.show_fps = !settings.show_fps,
+            SettingsItem::Language => {
+                settings.language = cycle(&Language::ALL, settings.language, delta);
+            }
+            SettingsItem::Colorblind => {
+                settings.colorblind = cycle(&ColorblindMode::ALL, settings.colorblind, delta);
+            }
+            // 切换页面由调用方处理。
+            SettingsItem::Controls => {}
+        }
+    }
+}
+
+/// 滑块只响应左右调整；按步长取整，避免反复调整后累积浮点误差。
+fn slide(value: &mut f32, action: MenuAction) {
+    if let MenuAction::Adjust(delta) = action {
+        let steps = (*value / SLIDER_STEP).round() as i32 + delta;
+        *value = (steps as f32 * SLIDER_STEP).clamp(0.0, 1.0);
+    }
+}
+
+/// 在 `all` 中从 `current` 出发循环移动 `delta` 项。
+fn cycle<T: Copy + PartialEq>(all: &[T], current: T, delta: i32) -> T {
+    let index = all.iter().position(|item| *item == current).unwrap_or(0) as i32;
+    all[(index + delta).rem_euclid(all.len() as i32) as usize]
+}
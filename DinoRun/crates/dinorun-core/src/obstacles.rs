@@ -0,0 +1,126 @@
+use crate::animation::Animator;
+use crate::constants::{PLAYER_SIZE, SCREEN_HEIGHT};
+use crate::types::ObstacleKind;
+use crate::utils::Rect;
+
+/// 运行时障碍物实体，包含碰撞盒与附加动画信息。
+#[derive(Clone)]
+pub struct Obstacle {
+    pub kind: ObstacleKind,
+    pub rect: Rect,
+    pub hurt_box: Rect,
+    /// 上一个模拟步结束时的外形，供渲染插值。
+    pub prev_rect: Rect,
+    pub saw_angle: f32,
+    /// 圆锯与无人机的精灵动画；木箱、深坑与激光没有动画。
+    pub animator: Option<Animator>,
+    /// 是否已经完整地落到玩家身后。
+    pub cleared: bool,
+    /// 迄今为止与玩家的最近距离，用于判定擦身而过。
+    pub closest: f32,
+}
+
+impl Obstacle {
+    /// 在给定横坐标放置木箱，底部贴地。
+    pub fn crate_box(x: f32, width: f32, height: f32, ground_y: f32) -> Self {
+        let rect = Rect::new(x, ground_y - height, width, height);
+        Self::with_rect(ObstacleKind::Crate, rect, rect, None)
+    }
+
+    /// 放置直径为 `size` 的圆锯，`altitude` 为锯片底部离地高度。
+    pub fn saw(x: f32, size: f32, altitude: f32, ground_y: f32, angle: f32) -> Self {
+        let rect = Rect::new(x, ground_y - size - altitude, size, size);
+        let mut obstacle = Self::with_rect(
+            ObstacleKind::Saw,
+            rect,
+            rect.inflate(-12.0, -12.0),
+            Some(Animator::new("saw")),
+        );
+        obstacle.saw_angle = angle;
+        obstacle
+    }
+
+    /// 放置宽度为 `width` 的深坑。
+    pub fn pit(x: f32, width: f32, ground_y: f32) -> Self {
+        let rect = Rect::new(x, ground_y - 4.0, width, 32.0);
+        Self::with_rect(ObstacleKind::Pit, rect, rect, None)
+    }
+
+    /// 放置无人机，`altitude` 为机身底部离地高度。
+    /// 飞得比玩家还低的无人机吊在从画面上方垂下的缆绳上，缆绳同样致命，只能滑铲从下方通过。
+    pub fn drone(x: f32, altitude: f32, ground_y: f32) -> Self {
+        let (width, height) = (78.0, 48.0);
+        let rect = Rect::new(x, ground_y - height - altitude, width, height);
+        let body = Rect::new(rect.x + 8.0, rect.y + 8.0, width - 20.0, height - 20.0);
+        let hurt_box = if altitude < PLAYER_SIZE.y {
+            let top = -SCREEN_HEIGHT;
+            Rect::new(body.x, top, body.w, body.y + body.h - top)
+        } else {
+            body
+        };
+        Self::with_rect(
+            ObstacleKind::Drone,
+            rect,
+            hurt_box,
+            Some(Animator::new("drone")),
+        )
+    }
+
+    /// 放置宽度为 `width` 的激光，光束从画面上方射下，末端离地 `altitude`。
+    /// 光束一直延伸到画面之外，跳不过去，只能滑铲从下方通过。
+    pub fn laser(x: f32, width: f32, altitude: f32, ground_y: f32) -> Self {
+        let top = -SCREEN_HEIGHT;
+        let rect = Rect::new(x, top, width, ground_y - altitude - top);
+        Self::with_rect(ObstacleKind::Laser, rect, rect.inflate(-4.0, 0.0), None)
+    }
+
+    fn with_rect(
+        kind: ObstacleKind,
+        rect: Rect,
+        hurt_box: Rect,
+        animator: Option<Animator>,
+    ) -> Self {
+        Self {
+            kind,
+            rect,
+            hurt_box,
+            prev_rect: rect,
+            saw_angle: 0.0,
+            animator,
+            cleared: false,
+            closest: f32::INFINITY,
+        }
+    }
+
+    /// 按时间推进障碍的运动与动画。
+    pub fn update(&mut self, dt: f32, speed: f32) {
+        self.rect.x -= speed * dt;
+        self.hurt_box.x -= speed * dt;
+        if let Some(animator) = self.animator.as_mut() {
+            animator.update(dt);
+        }
+        if self.kind == ObstacleKind::Saw {
+            self.saw_angle += 6.4 * dt;
+        }
+        if self.kind == ObstacleKind::Drone {
+            let bob = self.saw_angle.sin() * 22.0 * dt;
+            self.rect.y += bob;
+            self.hurt_box.y += bob;
+        }
+    }
+
+    /// 是否为挂在缆绳上的低空无人机：碰撞盒一直延伸到画面上方。
+    pub fn is_tethered(&self) -> bool {
+        self.kind == ObstacleKind::Drone && self.hurt_box.y < self.rect.y
+    }
+
+    /// 在上一步与当前步之间插值出渲染外形。
+    pub fn interpolated_rect(&self, alpha: f32) -> Rect {
+        self.prev_rect.lerp(&self.rect, alpha)
+    }
+
+    /// 判断障碍是否完全离开屏幕，用于回收。
+    pub fn is_offscreen(&self) -> bool {
+        self.rect.x + self.rect.w < -200.0
+    }
+}
@@ -0,0 +1,216 @@
+use crate::constants::*;
+use crate::events::GameEvent;
+use crate::rng::Rng;
+use crate::types::{CollectibleKind, Color, FloatingText, PowerUpKind};
+use crate::utils::Rect;
+use glam::{Vec2, vec2};
+
+/// 粒子对象用于表现尘土、爆裂等瞬时特效。
+#[derive(Clone, Default)]
+pub struct Particle {
+    pub active: bool,
+    pub pos: Vec2,
+    pub vel: Vec2,
+    pub lifetime: f32,
+    pub max_lifetime: f32,
+    pub color: Color,
+}
+
+/// 在玩家踩地时生成尘土特效。
+pub fn spawn_dust(particles: &mut [Particle], rng: &mut Rng, origin: Vec2, count: usize) {
+    for particle in particles.iter_mut().filter(|p| !p.active).take(count) {
+        particle.active = true;
+        particle.pos = origin;
+        particle.vel = vec2(rng.gen_range(-90.0, 90.0), rng.gen_range(-240.0, -120.0));
+        particle.color = Color::from_rgba(222, 205, 162, 255);
+        // 使用随机寿命让尘土在短时间内自然散去。
+        particle.max_lifetime = rng.gen_range(0.35, 0.6);
+        particle.lifetime = particle.max_lifetime;
+    }
+}
+
+/// ScoreBoost 生效时从玩家身后飘散的金色拖尾。
+pub fn spawn_boost_trail(particles: &mut [Particle], rng: &mut Rng, origin: Vec2) {
+    for particle in particles.iter_mut().filter(|p| !p.active).take(1) {
+        particle.active = true;
+        particle.pos = origin + vec2(rng.gen_range(-6.0, 6.0), rng.gen_range(-24.0, 24.0));
+        particle.vel = vec2(rng.gen_range(-160.0, -80.0), rng.gen_range(-60.0, 20.0));
+        particle.color = Color::from_rgba(255, 214, 90, 255);
+        // 拖尾寿命较短，保持紧贴玩家的流光感。
+        particle.max_lifetime = rng.gen_range(0.25, 0.45);
+        particle.lifetime = particle.max_lifetime;
+    }
+}
+
+/// 玩法事件对应的粒子与提示文字；没有视觉反馈的事件直接忽略。
+pub fn spawn_event_effect(
+    particles: &mut [Particle],
+    rng: &mut Rng,
+    floating_texts: &mut Vec<FloatingText>,
+    player_pos: Vec2,
+    event: &GameEvent,
+) {
+    match *event {
+        GameEvent::Jumped { air, origin } => {
+            spawn_dust(particles, rng, origin, if air { 6 } else { 8 });
+        }
+        GameEvent::SlideStarted { origin } => spawn_dust(particles, rng, origin, 5),
+        GameEvent::Collected {
+            kind, value, rect, ..
+        } => spawn_collect_effect(particles, rng, floating_texts, kind, value, rect),
+        GameEvent::PowerUpStarted { kind, at } => {
+            spawn_power_up_effect(particles, rng, floating_texts, kind, at, player_pos);
+        }
+        GameEvent::ShieldBroke { rect } => spawn_shield_burst(particles, rng, rect),
+        GameEvent::Died { at, .. } => spawn_death_effect(particles, rng, floating_texts, at),
+        GameEvent::NearMiss(_) => floating_texts.push(FloatingText {
+            text: "Close!".to_string(),
+            pos: player_pos + vec2(PLAYER_SIZE.x, -12.0),
+            vel: vec2(0.0, -36.0),
+            timer: 0.8,
+            max_timer: 0.8,
+            color: Color::from_rgba(255, 170, 120, 255),
+        }),
+        GameEvent::RunStarted
+        | GameEvent::Landed { .. }
+        | GameEvent::DashStarted
+        | GameEvent::ObstacleCleared(_)
+        | GameEvent::PowerUpEnded(_)
+        | GameEvent::Survived { .. } => {}
+    }
+}
+
+/// 护盾抵挡伤害时触发的爆裂特效。
+fn spawn_shield_burst(particles: &mut [Particle], rng: &mut Rng, rect: Rect) {
+    for particle in particles.iter_mut().filter(|p| !p.active).take(32) {
+        particle.active = true;
+        particle.pos = vec2(rect.x + rect.w * 0.5, rect.y + rect.h * 0.5);
+        particle.vel = vec2(rng.gen_range(-260.0, 260.0), rng.gen_range(-260.0, 260.0));
+        particle.color = Color::from_rgba(120, 200, 255, 255);
+        // 护盾爆裂粒子寿命更长，强调反馈。
+        particle.max_lifetime = rng.gen_range(0.4, 0.8);
+        particle.lifetime = particle.max_lifetime;
+    }
+}
+
+/// 玩家失败时的爆散特效与提示文字。
+fn spawn_death_effect(
+    particles: &mut [Particle],
+    rng: &mut Rng,
+    floating_texts: &mut Vec<FloatingText>,
+    pos: Vec2,
+) {
+    for particle in particles.iter_mut() {
+        if !particle.active {
+            particle.active = true;
+            particle.pos = pos;
+            particle.vel = vec2(rng.gen_range(-380.0, 380.0), rng.gen_range(-380.0, 120.0));
+            // 死亡特效采用红色并延长寿命，使其更显著。
+            particle.color = Color::RED;
+            particle.max_lifetime = rng.gen_range(0.7, 1.1);
+            particle.lifetime = particle.max_lifetime;
+        }
+    }
+    floating_texts.push(FloatingText {
+        text: "Run Lost".to_string(),
+        pos,
+        vel: vec2(0.0, -28.0),
+        timer: 1.6,
+        max_timer: 1.6,
+        color: Color::WHITE,
+    });
+}
+
+/// 收集物被拾取时的闪光与提示文字。
+fn spawn_collect_effect(
+    particles: &mut [Particle],
+    rng: &mut Rng,
+    floating_texts: &mut Vec<FloatingText>,
+    kind: CollectibleKind,
+    value: u32,
+    rect: Rect,
+) {
+    for particle in particles.iter_mut().filter(|p| !p.active).take(14) {
+        particle.active = true;
+        particle.pos = vec2(rect.x + rect.w * 0.5, rect.y);
+        particle.vel = vec2(rng.gen_range(-110.0, 110.0), rng.gen_range(-220.0, -60.0));
+        particle.color = match kind {
+            CollectibleKind::Coin => Color::from_rgba(255, 215, 0, 255),
+            CollectibleKind::Gem => Color::from_rgba(80, 200, 255, 255),
+        };
+        // 不同寿命营造拾取闪光的层次感。
+        particle.max_lifetime = rng.gen_range(0.4, 0.8);
+        particle.lifetime = particle.max_lifetime;
+    }
+    let label = match kind {
+        CollectibleKind::Coin => format!("+{value}"),
+        CollectibleKind::Gem => format!("+{value} Combo!"),
+    };
+    floating_texts.push(FloatingText {
+        text: label,
+        pos: vec2(rect.x, rect.y),
+        vel: vec2(0.0, -40.0),
+        timer: 1.0,
+        max_timer: 1.0,
+        color: Color::from_rgba(255, 255, 255, 255),
+    });
+}
+
+/// 拾取强化道具时的光环特效与提示文字。
+fn spawn_power_up_effect(
+    particles: &mut [Particle],
+    rng: &mut Rng,
+    floating_texts: &mut Vec<FloatingText>,
+    kind: PowerUpKind,
+    center: Vec2,
+    player_pos: Vec2,
+) {
+    let (label, color) = match kind {
+        PowerUpKind::Shield => ("Shield!", Color::from_rgba(120, 200, 255, 255)),
+        PowerUpKind::ScoreBoost => ("Score Boost!", Color::from_rgba(255, 214, 90, 255)),
+        PowerUpKind::TimeSlow => ("Slow Time!", Color::from_rgba(190, 140, 255, 255)),
+    };
+    for particle in particles.iter_mut().filter(|p| !p.active).take(20) {
+        particle.active = true;
+        particle.pos = center;
+        particle.vel = vec2(rng.gen_range(-200.0, 200.0), rng.gen_range(-200.0, 200.0));
+        particle.color = color;
+        particle.max_lifetime = rng.gen_range(0.4, 0.7);
+        particle.lifetime = particle.max_lifetime;
+    }
+    floating_texts.push(FloatingText {
+        text: label.to_string(),
+        pos: player_pos + vec2(PLAYER_SIZE.x * 0.5, -24.0),
+        vel: vec2(0.0, -42.0),
+        timer: 1.2,
+        max_timer: 1.2,
+        color: Color::GOLD,
+    });
+}
+
+/// 推进所有粒子的生命周期与速度，并考虑场景卷轴影响。
+pub fn update_particles(particles: &mut [Particle], dt: f32, scroll_speed: f32) {
+    for particle in particles {
+        if !particle.active {
+            continue;
+        }
+        // 衰减寿命并受水平卷轴与重力影响。
+        particle.lifetime -= dt;
+        if particle.lifetime <= 0.0 {
+            particle.active = false;
+            continue;
+        }
+        particle.pos += particle.vel * dt;
+        particle.vel.x -= scroll_speed * 0.32 * dt;
+        particle.vel.y += PARTICLE_GRAVITY * dt;
+    }
+}
+
+/// 更新浮动文本的位置与寿命。
+pub fn update_floating_texts(floating_texts: &mut Vec<FloatingText>, dt: f32) {
+    for text in floating_texts.iter_mut() {
+        text.pos += text.vel * dt;
+        text.timer -= dt;
+    }
+    floating_texts.retain(|text| text.timer > 0.0);
+}
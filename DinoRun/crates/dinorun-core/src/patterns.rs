@@ -0,0 +1,256 @@
+//! 障碍图案：由数据文件描述的一组组障碍（chunk），按难度与权重抽取后整体生成。
+//!
+//! 每个图案列出障碍的种类与相对横向偏移，可选地指定尺寸与离地高度：
+//!
+//! ```toml
+//! [[chunk]]
+//! name = "saw-pit-saw"
+//! min_time = 50.0
+//! weight = 1.0
+//! obstacles = [
+//!     { kind = "saw", x = 0.0 },
+//!     { kind = "pit", x = 300.0, size = 150.0 },
+//!     { kind = "saw", x = 620.0 },
+//! ]
+//! ```
+//!
+//! 加载时会检查图案内的障碍是否互相重叠。
+
+use crate::constants::*;
+use crate::obstacles::Obstacle;
+use crate::rng::Rng;
+use crate::types::ObstacleKind;
+use crate::utils::Rect;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 图案数据文件的默认位置（相对于工作目录）。
+pub const PATTERNS_PATH: &str = "assets/patterns.toml";
+
+/// 随程序内置的图案库，数据文件缺失或无效时使用。
+const BUILTIN_PATTERNS: &str = include_str!("../../../assets/patterns.toml");
+
+/// 图案中的单个障碍，坐标相对于图案起点。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChunkObstacle {
+    pub kind: ObstacleKind,
+    /// 相对图案起点的横向偏移（像素）。
+    pub x: f32,
+    /// 主尺寸：木箱、深坑与激光的宽度、圆锯的直径；无人机忽略此项。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<f32>,
+    /// 木箱高度。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<f32>,
+    /// 圆锯、无人机与激光底部的离地高度。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f32>,
+}
+
+impl ChunkObstacle {
+    fn size_or_default(&self) -> f32 {
+        self.size.unwrap_or(match self.kind {
+            ObstacleKind::Crate => 68.0,
+            ObstacleKind::Saw => 78.0,
+            ObstacleKind::Pit => 180.0,
+            ObstacleKind::Drone => 78.0,
+            ObstacleKind::Laser => 24.0,
+        })
+    }
+
+    /// 以 `origin_x` 为图案起点生成障碍；`rng` 只用于表现（圆锯初始角度）。
+    pub fn build(&self, origin_x: f32, ground_y: f32, rng: &mut Rng) -> Obstacle {
+        let x = origin_x + self.x;
+        let size = self.size_or_default();
+        match self.kind {
+            ObstacleKind::Crate => {
+                Obstacle::crate_box(x, size, self.height.unwrap_or(76.0), ground_y)
+            }
+            ObstacleKind::Saw => Obstacle::saw(
+                x,
+                size,
+                self.altitude.unwrap_or(0.0),
+                ground_y,
+                rng.gen_range(0.0, 360.0),
+            ),
+            ObstacleKind::Pit => Obstacle::pit(x, size, ground_y),
+            ObstacleKind::Drone => Obstacle::drone(x, self.altitude.unwrap_or(180.0), ground_y),
+            ObstacleKind::Laser => {
+                Obstacle::laser(x, size, self.altitude.unwrap_or(56.0), ground_y)
+            }
+        }
+    }
+}
+
+/// 一组作为整体生成的障碍。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Chunk {
+    pub name: String,
+    /// 对局进行到该时间（秒）后才会出现。
+    #[serde(default)]
+    pub min_time: f32,
+    /// 在所有可用图案中被抽中的相对权重。
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+    pub obstacles: Vec<ChunkObstacle>,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+impl Chunk {
+    /// 图案的总宽度：最右侧障碍的右边缘。
+    pub fn length(&self) -> f32 {
+        self.layout(BASE_GROUND_Y)
+            .iter()
+            .map(|rect| rect.x + rect.w)
+            .fold(0.0, f32::max)
+    }
+
+    /// 以原点为起点、按给定地面高度排布后的障碍外形，用于校验与测量。
+    pub fn layout(&self, ground_y: f32) -> Vec<Rect> {
+        let mut rng = Rng::new(0);
+        self.obstacles
+            .iter()
+            .map(|o| o.build(0.0, ground_y, &mut rng).rect)
+            .collect()
+    }
+
+    fn validate(&self) -> Result<(), PatternError> {
+        let invalid = |reason: String| PatternError::Invalid {
+            chunk: self.name.clone(),
+            reason,
+        };
+        if self.obstacles.is_empty() {
+            return Err(invalid("has no obstacles".into()));
+        }
+        if !self.min_time.is_finite() || self.min_time < 0.0 {
+            return Err(invalid(format!(
+                "min_time must be at least 0 (got {})",
+                self.min_time
+            )));
+        }
+        if !self.weight.is_finite() || self.weight <= 0.0 {
+            return Err(invalid(format!(
+                "weight must be greater than 0 (got {})",
+                self.weight
+            )));
+        }
+        for (idx, o) in self.obstacles.iter().enumerate() {
+            let dims = [Some(o.x), o.size, o.height, o.altitude];
+            if dims.iter().flatten().any(|v| !v.is_finite() || *v < 0.0) {
+                return Err(invalid(format!(
+                    "obstacle {idx} has a negative or non-finite dimension"
+                )));
+            }
+        }
+        let rects = self.layout(BASE_GROUND_Y);
+        for (a, rect_a) in rects.iter().enumerate() {
+            for (b, rect_b) in rects.iter().enumerate().skip(a + 1) {
+                if rect_a.overlaps(rect_b) {
+                    return Err(invalid(format!("obstacles {a} and {b} overlap")));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 全部图案。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PatternLibrary {
+    #[serde(rename = "chunk")]
+    pub chunks: Vec<Chunk>,
+}
+
+impl Default for PatternLibrary {
+    fn default() -> Self {
+        Self::parse(BUILTIN_PATTERNS).expect("built-in patterns are valid")
+    }
+}
+
+impl PatternLibrary {
+    /// 解析图案文本并校验每个图案。
+    pub fn parse(text: &str) -> Result<Self, PatternError> {
+        let library: PatternLibrary = toml::from_str(text).map_err(PatternError::Parse)?;
+        for chunk in &library.chunks {
+            chunk.validate()?;
+        }
+        if !library.chunks.iter().any(|c| c.min_time == 0.0) {
+            return Err(PatternError::NoStartingChunk);
+        }
+        Ok(library)
+    }
+
+    /// 读取图案数据文件。
+    pub fn load(path: &Path) -> Result<Self, PatternError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// 编码为 TOML 文本，与 [`PatternLibrary::parse`] 互逆。
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("patterns contain only plain data")
+    }
+
+    /// 按权重抽取一个在 `time` 时可用的图案。
+    pub fn choose(&self, time: f32, rng: &mut Rng) -> &Chunk {
+        let available = || self.chunks.iter().filter(|c| c.min_time <= time);
+        let total: f32 = available().map(|c| c.weight).sum();
+        let mut roll = rng.gen_range(0.0, total);
+        let mut last = None;
+        for chunk in available() {
+            if roll < chunk.weight {
+                return chunk;
+            }
+            roll -= chunk.weight;
+            last = Some(chunk);
+        }
+        // 浮点误差导致没有命中时取最后一个可用图案；解析时已保证至少有一个。
+        last.expect("at least one chunk is available from the start")
+    }
+}
+
+/// 读取图案数据时可能出现的错误。
+#[derive(Debug)]
+pub enum PatternError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// 某个图案的数据不合法。
+    Invalid {
+        chunk: String,
+        reason: String,
+    },
+    /// 没有开局即可出现的图案。
+    NoStartingChunk,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Io(err) => write!(f, "failed to read patterns: {err}"),
+            PatternError::Parse(err) => write!(f, "invalid patterns: {}", err.message()),
+            PatternError::Invalid { chunk, reason } => write!(f, "chunk `{chunk}` {reason}"),
+            PatternError::NoStartingChunk => {
+                write!(
+                    f,
+                    "no chunk has min_time = 0, nothing can spawn at the start"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl From<io::Error> for PatternError {
+    fn from(err: io::Error) -> Self {
+        PatternError::Io(err)
+    }
+}
@@ -0,0 +1,186 @@
+use crate::animation::Animator;
+use crate::constants::*;
+use crate::shop::Upgrades;
+use crate::tuning::{PlayerTuning, Tuning};
+use crate::types::PlayerAction;
+use crate::utils::Rect;
+use glam::{Vec2, vec2};
+
+/// 玩家实体包含物理状态、动作状态机与体力信息。
+#[derive(Clone)]
+pub struct Player {
+    pub pos: Vec2,
+    /// 上一个模拟步结束时的位置，供渲染插值。
+    pub prev_pos: Vec2,
+    pub vel: Vec2,
+    pub action: PlayerAction,
+    pub action_timer: f32,
+    pub stamina: f32,
+    /// 体力上限与恢复速率，受商店升级影响。
+    pub max_stamina: f32,
+    pub stamina_recover_rate: f32,
+    /// 每次离地可用的空中跳跃次数，以及当前剩余次数。
+    pub air_jumps: u32,
+    pub air_jumps_left: u32,
+    /// 剩余的土狼时间：离地后短时间内仍按在地面上处理起跳。
+    pub coyote_timer: f32,
+    /// 剩余的跳跃缓冲时间：大于 0 时落地即自动起跳。
+    pub jump_buffer_timer: f32,
+    /// 是否正在滑铲；滑铲期间动作保持为 [`PlayerAction::Slide`]。
+    pub sliding: bool,
+    /// 本次滑铲剩余的最短时长，起滑冲量随之衰减。
+    pub slide_timer: f32,
+    /// 起滑后剩余的无敌时间。
+    pub invulnerable_timer: f32,
+    pub combo: u32,
+    pub dash_cooldown: f32,
+    pub animator: Animator,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Player {
+    /// 返回一个处于初始位置、没有任何升级的玩家。
+    pub fn new() -> Self {
+        Self::with_upgrades(&Upgrades::default(), &Tuning::default())
+    }
+
+    /// 按已购买的升级与当前调参构建新开局的玩家。
+    pub fn with_upgrades(upgrades: &Upgrades, tuning: &Tuning) -> Self {
+        let max_stamina = upgrades.max_stamina(tuning);
+        let air_jumps = upgrades.air_jumps();
+        Self {
+            pos: PLAYER_START,
+            prev_pos: PLAYER_START,
+            vel: Vec2::ZERO,
+            action: PlayerAction::Running,
+            action_timer: 0.0,
+            stamina: max_stamina,
+            max_stamina,
+            stamina_recover_rate: upgrades.stamina_recover_rate(tuning),
+            air_jumps,
+            air_jumps_left: air_jumps,
+            coyote_timer: 0.0,
+            jump_buffer_timer: 0.0,
+            sliding: false,
+            slide_timer: 0.0,
+            invulnerable_timer: 0.0,
+            combo: 0,
+            dash_cooldown: 0.0,
+            animator: Animator::new("run"),
+        }
+    }
+
+    /// 计算当前动作下的碰撞盒（滑铲时高度减小）。
+    pub fn rect(&self) -> Rect {
+        let mut offset = 0.0;
+        if self.action == PlayerAction::Slide {
+            offset = PLAYER_SIZE.y * 0.45;
+        }
+        Rect::new(
+            self.pos.x,
+            self.pos.y + offset,
+            PLAYER_SIZE.x,
+            PLAYER_SIZE.y - offset,
+        )
+    }
+
+    /// 在上一步与当前步之间插值出渲染位置。
+    pub fn interpolated_pos(&self, alpha: f32) -> Vec2 {
+        self.prev_pos.lerp(self.pos, alpha)
+    }
+
+    /// 是否处于起滑的无敌窗口内：障碍不会造成伤害，深坑仍然致命。
+    pub fn is_invulnerable(&self) -> bool {
+        self.sliding && self.invulnerable_timer > 0.0
+    }
+
+    /// 判断玩家底部是否接触地面，用于跳跃与滑铲判定。
+    pub fn on_ground(&self, ground: f32) -> bool {
+        (self.pos.y + PLAYER_SIZE.y - ground).abs() < 0.5
+    }
+
+    /// 增加连击计数，封顶以避免 UI 溢出。
+    pub fn add_combo(&mut self) {
+        self.combo = (self.combo + 1).min(999);
+    }
+
+    /// 中断连击链路。
+    pub fn reset_combo(&mut self) {
+        self.combo = 0;
+    }
+}
+
+/// 推进玩家物理状态与动作状态机；本步从空中落地时返回触地时的下落速度。
+pub fn update_player(
+    player: &mut Player,
+    dt: f32,
+    ground: f32,
+    tuning: &PlayerTuning,
+) -> Option<f32> {
+    let mut landed = None;
+    player.action_timer = (player.action_timer - dt).max(0.0);
+    let airborne = !player.on_ground(ground);
+    player.vel.y += tuning.gravity * dt;
+    player.vel.y = player.vel.y.min(tuning.terminal_velocity);
+    player.pos += vec2(player.vel.x * dt, player.vel.y * dt);
+
+    if player.pos.y + PLAYER_SIZE.y >= ground {
+        // 落地瞬间按下落速度压扁。
+        if airborne {
+            player
+                .animator
+                .squash(player.vel.y / tuning.terminal_velocity * 1.4);
+            landed = Some(player.vel.y);
+        }
+        player.pos.y = ground - PLAYER_SIZE.y;
+        player.vel.y = 0.0;
+        player.air_jumps_left = player.air_jumps;
+        if player.action != PlayerAction::Dash && !player.sliding {
+            player.action = PlayerAction::Running;
+        }
+    }
+
+    // 站在地面上时持续刷新土狼时间，离地后开始倒计时。
+    if player.on_ground(ground) {
+        player.coyote_timer = tuning.coyote_time;
+    } else {
+        player.coyote_timer = (player.coyote_timer - dt).max(0.0);
+    }
+
+    if player.pos.y + PLAYER_SIZE.y < ground - 4.0 && player.action == PlayerAction::Running {
+        player.action = PlayerAction::Jump;
+    }
+
+    if player.action_timer == 0.0
+        && player.action != PlayerAction::Running
+        && !player.sliding
+        && player.on_ground(ground)
+    {
+        player.action = PlayerAction::Running;
+    }
+
+    if player.dash_cooldown > 0.0 {
+        player.dash_cooldown -= dt;
+    }
+
+    player.animator.play(animation_clip(player));
+    player.animator.update(dt);
+    landed
+}
+
+/// 根据动作与竖直速度选择玩家的动画片段。
+fn animation_clip(player: &Player) -> &'static str {
+    match player.action {
+        PlayerAction::Running => "run",
+        PlayerAction::Jump if player.vel.y > 0.0 => "fall",
+        PlayerAction::Jump => "jump",
+        PlayerAction::DoubleJump => "double_jump",
+        PlayerAction::Slide => "slide",
+        PlayerAction::Dash => "dash",
+    }
+}
@@ -0,0 +1,55 @@
+use crate::constants::*;
+use crate::rng::Rng;
+use crate::types::PowerUpKind;
+use crate::utils::Rect;
+
+/// 场景中可见的强化道具，玩家触碰后通过 `PowerUp::activate` 生效。
+#[derive(Clone)]
+pub struct PowerUpPickup {
+    pub kind: PowerUpKind,
+    pub rect: Rect,
+    /// 上一个模拟步结束时的外形，供渲染插值。
+    pub prev_rect: Rect,
+    pub float_phase: f32,
+}
+
+impl PowerUpPickup {
+    /// 在屏幕右侧的空中生成道具，高度需要跳跃或二段跳才能够到。
+    pub fn new(kind: PowerUpKind, ground_y: f32, rng: &mut Rng) -> Self {
+        let size = 36.0;
+        let x = SCREEN_WIDTH + rng.gen_range(80.0, 260.0);
+        let y = ground_y - PLAYER_SIZE.y - rng.gen_range(60.0, 200.0);
+        let rect = Rect::new(x, y, size, size);
+        Self {
+            kind,
+            rect,
+            prev_rect: rect,
+            float_phase: rng.gen_range(0.0, 360.0),
+        }
+    }
+
+    /// 更新位置与漂浮相位，响应整体卷轴速度。
+    pub fn update(&mut self, dt: f32, speed: f32) {
+        self.rect.x -= speed * dt;
+        self.float_phase += dt * 2.4;
+    }
+
+    /// 在上一步与当前步之间插值出渲染外形。
+    pub fn interpolated_rect(&self, alpha: f32) -> Rect {
+        self.prev_rect.lerp(&self.rect, alpha)
+    }
+
+    /// 判断道具是否完全离开屏幕，用于回收。
+    pub fn is_offscreen(&self) -> bool {
+        self.rect.x + self.rect.w < -60.0
+    }
+}
+
+/// 按权重抽取下一种强化道具。
+pub fn choose_power_up_kind(rng: &mut Rng) -> PowerUpKind {
+    match rng.gen_range(0.0, 1.0) {
+        v if v < 0.4 => PowerUpKind::Shield,
+        v if v < 0.75 => PowerUpKind::ScoreBoost,
+        _ => PowerUpKind::TimeSlow,
+    }
+}
@@ -0,0 +1,325 @@
+//! 对局录像：记录种子与逐步输入，编码为紧凑的 `.dreplay` 二进制文件。
+//!
+//! 文件布局（小端序）：
+//! `b"DRPL"` 魔数、`u16` 格式版本、`u16` 模拟频率、`u64` 种子、
+//! 按 [`UpgradeKind::ALL`] 顺序排列的 `u8` 升级等级、调参 TOML、障碍图案 TOML
+//! （两段文本均为 `u32` 长度加 UTF-8 内容）、`u32` 总步数，随后是若干 `(u16 连续步数, u8 输入位)` 的游程编码块。
+
+use crate::constants::*;
+use crate::input::{InputFrame, ScriptedInput};
+use crate::patterns::{PatternError, PatternLibrary};
+use crate::shop::{UpgradeKind, Upgrades};
+use crate::tuning::{Tuning, TuningError};
+use crate::types::GameState;
+use crate::update::step_world;
+use crate::world::World;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const REPLAY_MAGIC: [u8; 4] = *b"DRPL";
+pub const REPLAY_VERSION: u16 = 8;
+/// 录像文件的扩展名。
+pub const REPLAY_EXTENSION: &str = "dreplay";
+
+const UPGRADES_LEN: usize = UpgradeKind::ALL.len();
+/// 不含调参与图案文本的固定头部长度。
+const HEADER_LEN: usize = 4 + 2 + 2 + 8 + UPGRADES_LEN + 4 + 4 + 4;
+
+/// 读取录像时可能出现的错误。
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    /// 文件不是录像（魔数不符）。
+    BadMagic,
+    /// 录像由不兼容的版本写出。
+    UnsupportedVersion {
+        found: u16,
+        expected: u16,
+    },
+    /// 录像的模拟频率与当前版本不同，回放必然失步。
+    StepRateMismatch {
+        found: u16,
+        expected: u16,
+    },
+    /// 数据长度与头部声明不一致。
+    Truncated,
+    /// 内嵌的调参无法解析。
+    Tuning(TuningError),
+    /// 内嵌的障碍图案无法解析。
+    Patterns(PatternError),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io(err) => write!(f, "failed to read replay: {err}"),
+            ReplayError::BadMagic => write!(f, "not a .dreplay file"),
+            ReplayError::UnsupportedVersion { found, expected } => write!(
+                f,
+                "replay format version {found} is not supported (expected {expected})"
+            ),
+            ReplayError::StepRateMismatch { found, expected } => write!(
+                f,
+                "replay was recorded at {found} Hz but the simulation runs at {expected} Hz"
+            ),
+            ReplayError::Truncated => write!(f, "replay data is truncated or corrupted"),
+            ReplayError::Tuning(err) => write!(f, "replay has invalid tuning: {err}"),
+            ReplayError::Patterns(err) => write!(f, "replay has invalid patterns: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(err: io::Error) -> Self {
+        ReplayError::Io(err)
+    }
+}
+
+/// 一局完整的录像：种子、开局时的升级等级，加上每个模拟步的输入。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replay {
+    pub seed: u64,
+    /// 升级会改变玩家手感，回放必须使用录制时的等级。
+    pub upgrades: Upgrades,
+    /// 录制时生效的调参，回放不受之后修改调参文件的影响。
+    pub tuning: Tuning,
+    /// 录制时使用的障碍图案库。
+    pub patterns: PatternLibrary,
+    /// 第一帧对应开局的那一步，输入恒为空。
+    pub frames: Vec<InputFrame>,
+}
+
+impl Replay {
+    /// 把世界复位到录像开局时的状态。
+    pub fn start(&self, world: &mut World) {
+        world.upgrades = self.upgrades.clone();
+        world.set_tuning(self.tuning.clone());
+        world.patterns = self.patterns.clone();
+        world.reset_run_seeded(self.seed);
+        world.state = GameState::Running;
+    }
+
+    /// 生成按录像顺序输出输入帧的输入来源。
+    pub fn input_source(&self) -> ScriptedInput {
+        ScriptedInput::new(self.frames.clone())
+    }
+
+    /// 无窗口地从头回放到结束，`world` 停留在录像的终局状态，用于校验与离线分析。
+    pub fn play_to_end(&self, world: &mut World) {
+        self.start(world);
+        for frame in &self.frames {
+            step_world(world, frame, SIM_DT);
+        }
+    }
+
+    /// 编码为 `.dreplay` 字节流。
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.frames.len() / 8);
+        bytes.extend_from_slice(&REPLAY_MAGIC);
+        bytes.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(SIM_HZ as u16).to_le_bytes());
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        for kind in UpgradeKind::ALL {
+            bytes.push(self.upgrades.level(kind).min(u8::MAX as u32) as u8);
+        }
+        write_text(&mut bytes, &self.tuning.to_toml());
+        write_text(&mut bytes, &self.patterns.to_toml());
+        bytes.extend_from_slice(&(self.frames.len() as u32).to_le_bytes());
+
+        let mut iter = self.frames.iter().map(frame_to_bits).peekable();
+        while let Some(bits) = iter.next() {
+            let mut run: u16 = 1;
+            while run < u16::MAX && iter.peek() == Some(&bits) {
+                iter.next();
+                run += 1;
+            }
+            bytes.extend_from_slice(&run.to_le_bytes());
+            bytes.push(bits);
+        }
+        bytes
+    }
+
+    /// 从 `.dreplay` 字节流解码，版本或频率不符时明确报错。
+    pub fn decode(bytes: &[u8]) -> Result<Self, ReplayError> {
+        if bytes.len() < 4 || bytes[0..4] != REPLAY_MAGIC {
+            return Err(ReplayError::BadMagic);
+        }
+        if bytes.len() < HEADER_LEN {
+            return Err(ReplayError::Truncated);
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion {
+                found: version,
+                expected: REPLAY_VERSION,
+            });
+        }
+        let step_hz = u16::from_le_bytes([bytes[6], bytes[7]]);
+        if step_hz != SIM_HZ as u16 {
+            return Err(ReplayError::StepRateMismatch {
+                found: step_hz,
+                expected: SIM_HZ as u16,
+            });
+        }
+        let mut seed = [0u8; 8];
+        seed.copy_from_slice(&bytes[8..16]);
+        let seed = u64::from_le_bytes(seed);
+        let mut upgrades = Upgrades::default();
+        for (kind, level) in UpgradeKind::ALL.iter().zip(&bytes[16..16 + UPGRADES_LEN]) {
+            upgrades.set_level(*kind, *level as u32);
+        }
+        let mut at = 16 + UPGRADES_LEN;
+        let tuning = Tuning::parse(read_text(bytes, &mut at)?).map_err(ReplayError::Tuning)?;
+        let patterns =
+            PatternLibrary::parse(read_text(bytes, &mut at)?).map_err(ReplayError::Patterns)?;
+        if bytes.len() < at + 4 {
+            return Err(ReplayError::Truncated);
+        }
+        let count = read_u32(bytes, at) as usize;
+        at += 4;
+        // 步数来自文件，预分配前先确认后续的游程块装得下，损坏的头部不会申请巨量内存。
+        if count > (bytes.len() - at) / 3 * u16::MAX as usize {
+            return Err(ReplayError::Truncated);
+        }
+
+        let mut frames = Vec::with_capacity(count);
+        for chunk in bytes[at..].chunks(3) {
+            if chunk.len() != 3 {
+                return Err(ReplayError::Truncated);
+            }
+            let run = u16::from_le_bytes([chunk[0], chunk[1]]) as usize;
+            if run == 0 || frames.len() + run > count {
+                return Err(ReplayError::Truncated);
+            }
+            frames.extend(std::iter::repeat_n(frame_from_bits(chunk[2]), run));
+        }
+        if frames.len() != count {
+            return Err(ReplayError::Truncated);
+        }
+        Ok(Self {
+            seed,
+            upgrades,
+            tuning,
+            patterns,
+            frames,
+        })
+    }
+
+    /// 写入文件，必要时创建上级目录。
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.encode())
+    }
+
+    /// 从文件读取录像。
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        Self::decode(&fs::read(path)?)
+    }
+}
+
+/// 逐步观察世界，自动在开局时开始录制、在对局结束时产出录像。
+#[derive(Default)]
+pub struct ReplayRecorder {
+    recording: Option<Replay>,
+}
+
+impl ReplayRecorder {
+    /// 在每个模拟步之后调用；`was_in_run` 为该步执行前 [`World::in_run`] 的值。
+    /// 对局结束的那一步返回完整录像。
+    pub fn observe(
+        &mut self,
+        was_in_run: bool,
+        input: &InputFrame,
+        world: &World,
+    ) -> Option<Replay> {
+        match (was_in_run, world.in_run()) {
+            (false, true) => {
+                // 开局步的输入已被菜单消费，对局本身看到的是空输入。
+                self.recording = Some(Replay {
+                    seed: world.run_seed,
+                    upgrades: world.upgrades.clone(),
+                    tuning: world.tuning.clone(),
+                    patterns: world.patterns.clone(),
+                    frames: vec![InputFrame::default()],
+                });
+                None
+            }
+            (true, true) => {
+                // 对局中途热重载了调参，录像已无法复现，直接放弃。
+                if self
+                    .recording
+                    .as_ref()
+                    .is_some_and(|replay| replay.tuning != world.tuning)
+                {
+                    self.recording = None;
+                }
+                if let Some(replay) = self.recording.as_mut() {
+                    replay.frames.push(*input);
+                }
+                None
+            }
+            (true, false) => {
+                let mut replay = self.recording.take()?;
+                replay.frames.push(*input);
+                Some(replay)
+            }
+            (false, false) => None,
+        }
+    }
+}
+
+/// 写入 `u32` 长度前缀的 UTF-8 文本。
+fn write_text(bytes: &mut Vec<u8>, text: &str) {
+    bytes.extend_from_slice(&(text.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(text.as_bytes());
+}
+
+/// 读取 `u32` 长度前缀的 UTF-8 文本，并把 `at` 移到文本之后。
+fn read_text<'a>(bytes: &'a [u8], at: &mut usize) -> Result<&'a str, ReplayError> {
+    if bytes.len() < *at + 4 {
+        return Err(ReplayError::Truncated);
+    }
+    let len = read_u32(bytes, *at) as usize;
+    let start = *at + 4;
+    let text = bytes
+        .get(start..start + len)
+        .ok_or(ReplayError::Truncated)?;
+    *at = start + len;
+    std::str::from_utf8(text).map_err(|_| ReplayError::Truncated)
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+/// 菜单左右键只在对局之外使用，录像不记录。
+fn frame_to_bits(frame: &InputFrame) -> u8 {
+    (frame.jump_pressed as u8)
+        | (frame.dash_held as u8) << 1
+        | (frame.slide_held as u8) << 2
+        | (frame.pause_pressed as u8) << 3
+        | (frame.menu_up as u8) << 4
+        | (frame.menu_down as u8) << 5
+        | (frame.menu_confirm as u8) << 6
+        | (frame.jump_held as u8) << 7
+}
+
+fn frame_from_bits(bits: u8) -> InputFrame {
+    InputFrame {
+        jump_pressed: bits & 1 != 0,
+        dash_held: bits & (1 << 1) != 0,
+        slide_held: bits & (1 << 2) != 0,
+        pause_pressed: bits & (1 << 3) != 0,
+        menu_up: bits & (1 << 4) != 0,
+        menu_down: bits & (1 << 5) != 0,
+        menu_confirm: bits & (1 << 6) != 0,
+        jump_held: bits & (1 << 7) != 0,
+        ..InputFrame::default()
+    }
+}
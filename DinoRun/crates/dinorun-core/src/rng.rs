@@ -0,0 +1,60 @@
+/// 可设定种子的 SplitMix64 伪随机数生成器，状态仅为一个 `u64`，便于保存与复现。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// 以给定种子创建生成器，相同种子总是产生相同序列。
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// 从先前保存的内部状态恢复生成器。
+    pub const fn from_state(state: u64) -> Self {
+        Self { state }
+    }
+
+    /// 返回内部状态，可原样写入存档或录像。
+    pub const fn state(&self) -> u64 {
+        self.state
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// 返回 `[0, 1)` 区间内的均匀分布浮点数。
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// 返回 `[low, high)` 区间内的随机数，用法与 macroquad 的 `gen_range` 一致。
+    pub fn gen_range(&mut self, low: f32, high: f32) -> f32 {
+        low + (high - low) * self.next_f32()
+    }
+}
+
+/// World 持有的随机源：玩法与表现特效分流，粒子数量变化不会扰动障碍布局。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameRng {
+    /// 障碍、收集物、强化等影响结果的随机数。
+    pub gameplay: Rng,
+    /// 粒子、昼夜相位等纯视觉随机数。
+    pub cosmetic: Rng,
+}
+
+impl GameRng {
+    /// 由一个种子派生两条互不相关的随机流。
+    pub fn new(seed: u64) -> Self {
+        let mut splitter = Rng::new(seed);
+        Self {
+            gameplay: Rng::new(splitter.next_u64()),
+            cosmetic: Rng::new(splitter.next_u64()),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! 持久化存档：最佳成绩、货币、商店升级等跨进程保留的进度。
+//!
+//! 存档是带版本号的 TOML 文件，位于平台数据目录（Linux 下遵循 XDG）。
+//! 写入先落到临时文件再原子替换；读取失败时把坏文件备份后回退到空存档，
+//! 绝不静默覆盖玩家进度。
+
+use crate::shop::Upgrades;
+use crate::stats::RunStats;
+use crate::world::World;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 当前存档格式版本。新增带默认值的字段无需递增；重命名或调整结构时递增并补充迁移函数。
+pub const SAVE_VERSION: u32 = 1;
+const SAVE_FILE_NAME: &str = "save.toml";
+const APP_DIR_NAME: &str = "dinorun";
+
+/// 迁移表：第 `i` 项把版本 `i + 1` 的存档升级到版本 `i + 2`。
+const MIGRATIONS: &[fn(&mut toml::Table)] = &[];
+const _: () = assert!(MIGRATIONS.len() == SAVE_VERSION as usize - 1);
+
+/// 写入磁盘的进度快照。缺失的字段取默认值，便于旧存档平滑升级。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveData {
+    pub version: u32,
+    pub best_score: f32,
+    pub currency: u32,
+    pub upgrades: Upgrades,
+    /// 距离最远一局的统计，供结算界面对比。
+    pub best_run: Option<RunStats>,
+    /// 已解锁成就的 id。
+    pub achievements: BTreeSet<String>,
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        Self {
+            version: SAVE_VERSION,
+            best_score: 0.0,
+            currency: 0,
+            upgrades: Upgrades::default(),
+            best_run: None,
+            achievements: BTreeSet::new(),
+        }
+    }
+}
+
+impl SaveData {
+    /// 从世界中提取需要持久化的进度。
+    pub fn capture(world: &World) -> Self {
+        Self {
+            version: SAVE_VERSION,
+            best_score: world.best_score,
+            currency: world.currency,
+            upgrades: world.upgrades.clone(),
+            best_run: world.best_run.clone(),
+            achievements: world.achievements.unlocked.clone(),
+        }
+    }
+
+    /// 把进度写回世界。
+    pub fn apply(&self, world: &mut World) {
+        world.best_score = self.best_score;
+        world.currency = self.currency;
+        world.upgrades = self.upgrades.clone();
+        world.achievements.unlocked = self.achievements.clone();
+        // 早期存档只有最佳距离，没有整局统计。
+        world.best_run = self
+            .best_run
+            .clone()
+            .or_else(|| (self.best_score > 0.0).then(|| RunStats::from_distance(self.best_score)));
+    }
+
+    /// 解析存档文本，按需执行版本迁移。
+    pub fn parse(text: &str) -> Result<Self, SaveError> {
+        let mut table: toml::Table = text.parse().map_err(SaveError::Parse)?;
+        let version = match table.get("version") {
+            Some(toml::Value::Integer(v)) => u32::try_from(*v)
+                .ok()
+                .filter(|version| *version >= 1)
+                .ok_or(SaveError::InvalidVersion(*v))?,
+            _ => return Err(SaveError::MissingVersion),
+        };
+        if version > SAVE_VERSION {
+            return Err(SaveError::NewerVersion(version));
+        }
+        for migrate in &MIGRATIONS[(version - 1) as usize..] {
+            migrate(&mut table);
+        }
+        table.insert("version".into(), toml::Value::Integer(SAVE_VERSION.into()));
+        let data: SaveData = table.try_into().map_err(SaveError::Parse)?;
+        Ok(data)
+    }
+}
+
+/// 读取存档时可能出现的错误。
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+    /// 文件缺少版本号，无法判断如何迁移。
+    MissingVersion,
+    /// 版本号不是正整数或超出范围。
+    InvalidVersion(i64),
+    /// 存档来自更新版本的游戏。
+    NewerVersion(u32),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "{err}"),
+            SaveError::Parse(err) => write!(f, "invalid save data: {}", err.message()),
+            SaveError::Serialize(err) => write!(f, "failed to encode save data: {err}"),
+            SaveError::MissingVersion => write!(f, "save file has no version"),
+            SaveError::InvalidVersion(v) => write!(f, "save file version {v} is not valid"),
+            SaveError::NewerVersion(v) => write!(
+                f,
+                "save file version {v} is newer than supported version {SAVE_VERSION}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+/// 加载结果：数据本身，以及需要告知玩家的提示（例如坏档已被备份）。
+pub struct LoadOutcome {
+    pub data: SaveData,
+    pub warning: Option<String>,
+}
+
+/// 存档文件的读写入口。
+pub struct SaveStore {
+    path: PathBuf,
+}
+
+impl SaveStore {
+    /// 使用指定路径的存档，主要用于测试与工具。
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// 使用平台数据目录下的默认存档位置。
+    pub fn open_default() -> Self {
+        Self::new(data_dir().join(SAVE_FILE_NAME))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 读取存档；文件不存在时返回空存档，损坏时备份原文件后返回空存档。
+    pub fn load(&self) -> LoadOutcome {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return LoadOutcome {
+                    data: SaveData::default(),
+                    warning: None,
+                };
+            }
+            Err(err) => {
+                return LoadOutcome {
+                    data: SaveData::default(),
+                    warning: Some(format!("could not read {}: {err}", self.path.display())),
+                };
+            }
+        };
+        match SaveData::parse(&text) {
+            Ok(data) => LoadOutcome {
+                data,
+                warning: None,
+            },
+            Err(err) => {
+                let warning = match self.back_up() {
+                    Ok(backup) => format!(
+                        "save file was unreadable ({err}); backed up to {}",
+                        backup.display()
+                    ),
+                    Err(backup_err) => format!(
+                        "save file was unreadable ({err}) and could not be backed up: {backup_err}"
+                    ),
+                };
+                LoadOutcome {
+                    data: SaveData::default(),
+                    warning: Some(warning),
+                }
+            }
+        }
+    }
+
+    /// 原子写入存档。
+    pub fn save(&self, data: &SaveData) -> Result<(), SaveError> {
+        let text = toml::to_string_pretty(data).map_err(SaveError::Serialize)?;
+        write_atomic(&self.path, &text)?;
+        Ok(())
+    }
+
+    /// 把无法读取的存档改名保留，返回备份路径。
+    fn back_up(&self) -> io::Result<PathBuf> {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let mut backup = self.path.with_extension(format!("toml.bak-{stamp}"));
+        let mut suffix = 1;
+        while backup.exists() {
+            backup = self
+                .path
+                .with_extension(format!("toml.bak-{stamp}-{suffix}"));
+            suffix += 1;
+        }
+        fs::rename(&self.path, &backup)?;
+        Ok(backup)
+    }
+}
+
+/// 原子写入：先写临时文件并刷盘，再重命名覆盖正式文件；目录不存在时自动创建。
+pub(crate) fn write_atomic(path: &Path, text: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("toml.tmp");
+    {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(text.as_bytes())?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path)
+}
+
+/// 游戏数据目录：Linux 为 `$XDG_DATA_HOME/dinorun`（默认 `~/.local/share/dinorun`），
+/// 其他平台使用各自的应用数据目录；都不可用时退回当前目录。
+pub fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|dir| dir.join(APP_DIR_NAME))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
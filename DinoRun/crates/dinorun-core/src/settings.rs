@@ -0,0 +1,210 @@
+//! 玩家设置：音量、显示与无障碍等与进度无关的偏好，保存在平台配置目录下的 `dinorun/settings.toml`。
+//!
+//! 设置文件损坏时只提示并使用默认值，不做备份；下次修改设置时会被覆盖。
+
+use crate::bindings::Bindings;
+use crate::locale::{Language, Text};
+use crate::save::{SaveError, write_atomic};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SETTINGS_FILE_NAME: &str = "settings.toml";
+const APP_DIR_NAME: &str = "dinorun";
+
+/// 当前设置文件版本；没有版本号的文件视为版本 1。
+pub const SETTINGS_VERSION: u32 = 2;
+
+/// 迁移表：第 `i` 项把版本 `i + 1` 的设置升级到版本 `i + 2`。
+const MIGRATIONS: &[fn(&mut Settings)] = &[add_pad_bindings];
+const _: () = assert!(MIGRATIONS.len() == SETTINGS_VERSION as usize - 1);
+
+/// 版本 2 加入手柄：旧文件的 `[controls]` 表里没有手柄按键，补上默认的手柄绑定。
+fn add_pad_bindings(settings: &mut Settings) {
+    settings.controls.add_default_pad_keys();
+}
+
+fn legacy_version() -> u32 {
+    1
+}
+
+/// 玩家设置。缺失的字段取默认值，越界的数值在加载时被修正。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// 文件格式版本，加载时据此迁移旧文件。
+    #[serde(default = "legacy_version")]
+    pub version: u32,
+    /// 总音量与音乐、音效的分音量，范围 0.0..=1.0。
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub fullscreen: bool,
+    /// 垂直同步只能在创建窗口时设置，修改后下次启动生效。
+    pub vsync: bool,
+    /// 屏幕震动强度，范围 0.0..=1.0，0 为关闭。
+    pub screen_shake: f32,
+    pub particle_density: ParticleDensity,
+    pub show_fps: bool,
+    pub language: Language,
+    pub colorblind: ColorblindMode,
+    /// 按键绑定，写在文件末尾的 `[controls]` 表中。
+    pub controls: Bindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            master_volume: 0.8,
+            music_volume: 0.6,
+            sfx_volume: 0.8,
+            fullscreen: false,
+            vsync: true,
+            screen_shake: 1.0,
+            particle_density: ParticleDensity::High,
+            show_fps: false,
+            language: Language::English,
+            colorblind: ColorblindMode::Off,
+            controls: Bindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// 解析设置文本，按需执行版本迁移并整理数值。
+    /// 来自更新版本的文件不报错，未知字段同样被忽略。
+    pub fn parse(text: &str) -> Result<Self, SaveError> {
+        let mut settings: Settings = toml::from_str(text).map_err(SaveError::Parse)?;
+        let from = settings.version.clamp(1, SETTINGS_VERSION);
+        for migrate in &MIGRATIONS[(from - 1) as usize..] {
+            migrate(&mut settings);
+        }
+        settings.version = SETTINGS_VERSION;
+        Ok(settings.sanitized())
+    }
+
+    /// 把数值限制在合法范围内，非法数值（如 NaN）换成默认值，并整理按键绑定。
+    pub fn sanitized(self) -> Self {
+        let defaults = Self::default();
+        let unit = |value: f32, default: f32| {
+            if value.is_finite() {
+                value.clamp(0.0, 1.0)
+            } else {
+                default
+            }
+        };
+        Self {
+            master_volume: unit(self.master_volume, defaults.master_volume),
+            music_volume: unit(self.music_volume, defaults.music_volume),
+            sfx_volume: unit(self.sfx_volume, defaults.sfx_volume),
+            screen_shake: unit(self.screen_shake, defaults.screen_shake),
+            controls: self.controls.sanitized(),
+            ..self
+        }
+    }
+
+    /// 音乐的实际音量（已乘总音量）。
+    pub fn music_gain(&self) -> f32 {
+        self.master_volume * self.music_volume
+    }
+
+    /// 音效的实际音量（已乘总音量）。
+    pub fn sfx_gain(&self) -> f32 {
+        self.master_volume * self.sfx_volume
+    }
+}
+
+/// 粒子特效的绘制密度；只影响显示，模拟中的粒子不变。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParticleDensity {
+    Low,
+    Medium,
+    High,
+}
+
+impl ParticleDensity {
+    pub const ALL: [ParticleDensity; 3] = [
+        ParticleDensity::Low,
+        ParticleDensity::Medium,
+        ParticleDensity::High,
+    ];
+
+    pub fn label(self) -> Text {
+        match self {
+            ParticleDensity::Low => Text::Low,
+            ParticleDensity::Medium => Text::Medium,
+            ParticleDensity::High => Text::High,
+        }
+    }
+}
+
+/// 色盲友好配色，按色觉类型替换收集物、强化道具与对比高亮等需要区分的颜色。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorblindMode {
+    Off,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl ColorblindMode {
+    pub const ALL: [ColorblindMode; 4] = [
+        ColorblindMode::Off,
+        ColorblindMode::Deuteranopia,
+        ColorblindMode::Protanopia,
+        ColorblindMode::Tritanopia,
+    ];
+
+    pub fn label(self) -> Text {
+        match self {
+            ColorblindMode::Off => Text::Off,
+            ColorblindMode::Deuteranopia => Text::Deuteranopia,
+            ColorblindMode::Protanopia => Text::Protanopia,
+            ColorblindMode::Tritanopia => Text::Tritanopia,
+        }
+    }
+}
+
+/// 设置文件的读写入口。
+pub struct SettingsStore {
+    path: PathBuf,
+}
+
+impl SettingsStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// 使用平台配置目录下的默认位置（Linux 为 `$XDG_CONFIG_HOME/dinorun`）。
+    pub fn open_default() -> Self {
+        let dir = dirs::config_dir()
+            .map(|dir| dir.join(APP_DIR_NAME))
+            .unwrap_or_else(|| PathBuf::from("."));
+        Self::new(dir.join(SETTINGS_FILE_NAME))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 读取设置；文件不存在时返回 `Ok(None)`。
+    pub fn load(&self) -> Result<Option<Settings>, SaveError> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        Settings::parse(&text).map(Some)
+    }
+
+    /// 原子写入设置。
+    pub fn save(&self, settings: &Settings) -> Result<(), SaveError> {
+        let text = toml::to_string_pretty(settings).map_err(SaveError::Serialize)?;
+        write_atomic(&self.path, &text)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use crate::tuning::Tuning;
+use serde::{Deserialize, Serialize};
+
+/// 商店中可购买的永久升级项。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpgradeKind {
+    MaxStamina,
+    StaminaRecovery,
+    PowerUpDuration,
+    ExtraJump,
+    StartingShield,
+}
+
+impl UpgradeKind {
+    /// 商店中的展示顺序，同时也是商店光标的索引。
+    pub const ALL: [UpgradeKind; 5] = [
+        UpgradeKind::MaxStamina,
+        UpgradeKind::StaminaRecovery,
+        UpgradeKind::PowerUpDuration,
+        UpgradeKind::ExtraJump,
+        UpgradeKind::StartingShield,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            UpgradeKind::MaxStamina => "Max Stamina",
+            UpgradeKind::StaminaRecovery => "Stamina Recovery",
+            UpgradeKind::PowerUpDuration => "Power-Up Duration",
+            UpgradeKind::ExtraJump => "Extra Air Jump",
+            UpgradeKind::StartingShield => "Starting Shield",
+        }
+    }
+
+    /// 每级升级带来的效果说明。
+    pub fn description(self) -> &'static str {
+        match self {
+            UpgradeKind::MaxStamina => "+20 stamina per level",
+            UpgradeKind::StaminaRecovery => "+25% stamina recovery per level",
+            UpgradeKind::PowerUpDuration => "+1.5 s power-up time per level",
+            UpgradeKind::ExtraJump => "One more jump in mid-air per level",
+            UpgradeKind::StartingShield => "Begin each run shielded, longer per level",
+        }
+    }
+
+    pub fn max_level(self) -> u32 {
+        match self {
+            UpgradeKind::MaxStamina | UpgradeKind::StaminaRecovery => 5,
+            UpgradeKind::PowerUpDuration => 4,
+            UpgradeKind::ExtraJump => 2,
+            UpgradeKind::StartingShield => 3,
+        }
+    }
+
+    /// 首级价格，之后每级按 [`UPGRADE_COST_GROWTH`] 递增。
+    fn base_cost(self) -> u32 {
+        match self {
+            UpgradeKind::MaxStamina => 150,
+            UpgradeKind::StaminaRecovery => 120,
+            UpgradeKind::PowerUpDuration => 200,
+            UpgradeKind::ExtraJump => 900,
+            UpgradeKind::StartingShield => 400,
+        }
+    }
+}
+
+/// 每升一级价格的增长倍数。
+const UPGRADE_COST_GROWTH: f32 = 1.8;
+
+/// 玩家已购买的升级等级，随存档持久化，在开局构建玩家时生效。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Upgrades {
+    pub max_stamina: u32,
+    pub stamina_recovery: u32,
+    pub powerup_duration: u32,
+    pub extra_jump: u32,
+    pub starting_shield: u32,
+}
+
+impl Upgrades {
+    pub fn level(&self, kind: UpgradeKind) -> u32 {
+        match kind {
+            UpgradeKind::MaxStamina => self.max_stamina,
+            UpgradeKind::StaminaRecovery => self.stamina_recovery,
+            UpgradeKind::PowerUpDuration => self.powerup_duration,
+            UpgradeKind::ExtraJump => self.extra_jump,
+            UpgradeKind::StartingShield => self.starting_shield,
+        }
+    }
+
+    fn level_mut(&mut self, kind: UpgradeKind) -> &mut u32 {
+        match kind {
+            UpgradeKind::MaxStamina => &mut self.max_stamina,
+            UpgradeKind::StaminaRecovery => &mut self.stamina_recovery,
+            UpgradeKind::PowerUpDuration => &mut self.powerup_duration,
+            UpgradeKind::ExtraJump => &mut self.extra_jump,
+            UpgradeKind::StartingShield => &mut self.starting_shield,
+        }
+    }
+
+    /// 直接设定等级，超出上限的值会被截断；用于读取录像等外部数据。
+    pub fn set_level(&mut self, kind: UpgradeKind, level: u32) {
+        *self.level_mut(kind) = level.min(kind.max_level());
+    }
+
+    /// 下一级的价格；已满级时返回 `None`。
+    pub fn next_cost(&self, kind: UpgradeKind) -> Option<u32> {
+        let level = self.level(kind);
+        if level >= kind.max_level() {
+            return None;
+        }
+        Some((kind.base_cost() as f32 * UPGRADE_COST_GROWTH.powi(level as i32)).round() as u32)
+    }
+
+    /// 尝试用货币购买下一级，成功时扣款并返回 `true`。
+    pub fn purchase(&mut self, kind: UpgradeKind, currency: &mut u32) -> bool {
+        match self.next_cost(kind) {
+            Some(cost) if *currency >= cost => {
+                *currency -= cost;
+                *self.level_mut(kind) += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn max_stamina(&self, tuning: &Tuning) -> f32 {
+        tuning.stamina.max + self.max_stamina as f32 * 20.0
+    }
+
+    pub fn stamina_recover_rate(&self, tuning: &Tuning) -> f32 {
+        tuning.stamina.recover_rate * (1.0 + self.stamina_recovery as f32 * 0.25)
+    }
+
+    pub fn powerup_duration(&self, tuning: &Tuning) -> f32 {
+        tuning.powerups.duration + self.powerup_duration as f32 * 1.5
+    }
+
+    /// 离地后还能进行的空中跳跃次数。
+    pub fn air_jumps(&self) -> u32 {
+        1 + self.extra_jump
+    }
+
+    /// 开局护盾的持续时间，未购买时为 0。
+    pub fn starting_shield_duration(&self) -> f32 {
+        match self.starting_shield {
+            0 => 0.0,
+            level => 2.0 + level as f32 * 2.0,
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! 可达性求解：给定玩家当前状态与即将到来的障碍，搜索一条能活下来的输入序列。
+//!
+//! 求解器直接调用游戏本身的 [`control_player`] 与 [`update_player`] 逐步模拟，
+//! 因此找到的输入序列交给 [`step_world`](crate::update::step_world) 重放时结果完全一致。
+//! 搜索按固定间隔选择动作，并合并相近的状态以控制规模：
+//! 返回的序列一定可行，但返回 `None` 只代表在这一搜索空间内没有找到。
+//!
+//! 护盾不计入求解（视为没有护盾），收集物被忽略。拾取强化道具可能改变时间流速，
+//! 让已经求得的序列失效，因此会改变流速的道具（TimeSlow，以及 TimeSlow 生效期间的
+//! 任何道具）被视为必须避开，结论偏保守。
+
+use crate::constants::*;
+use crate::input::{InputFrame, control_player};
+use crate::obstacles::Obstacle;
+use crate::player::{Player, update_player};
+use crate::powerups::PowerUpPickup;
+use crate::tuning::Tuning;
+use crate::types::{DifficultyTrack, ObstacleKind, PowerUpKind};
+use crate::utils::Rect;
+use crate::world::World;
+use std::collections::HashSet;
+
+/// 每隔多少个模拟步重新选择一次动作。
+const DECISION_STEPS: usize = 8;
+/// 最长的搜索时长（秒），超过仍未越过全部障碍视为无解。
+const HORIZON_SECONDS: f32 = 8.0;
+/// 每层保留的最多状态数。
+const MAX_STATES_PER_LAYER: usize = 64;
+
+/// 求解器始终按住跳跃键，只搜索满高度的跳跃。
+const IDLE: InputFrame = InputFrame {
+    jump_pressed: false,
+    jump_held: true,
+    dash_held: false,
+    slide_held: false,
+    pause_pressed: false,
+    menu_up: false,
+    menu_down: false,
+    menu_left: false,
+    menu_right: false,
+    menu_confirm: false,
+};
+
+/// 每个决策间隔可选的动作；跳跃只在间隔的第一步按下。
+const ACTIONS: [InputFrame; 4] = [
+    IDLE,
+    InputFrame {
+        jump_pressed: true,
+        ..IDLE
+    },
+    InputFrame {
+        slide_held: true,
+        ..IDLE
+    },
+    InputFrame {
+        dash_held: true,
+        ..IDLE
+    },
+];
+
+/// 一次求解所需的全部信息，与 [`World`] 解耦以便在测试中构造任意局面。
+#[derive(Clone)]
+pub struct Scenario {
+    pub player: Player,
+    pub obstacles: Vec<Obstacle>,
+    pub power_ups: Vec<PowerUpPickup>,
+    pub tuning: Tuning,
+    /// 对局已进行的时间，决定滚屏速度与地面高度。
+    pub time: f32,
+    /// TimeSlow 剩余时间；为 0 表示未生效。
+    pub time_slow_remaining: f32,
+}
+
+impl Scenario {
+    /// 以世界的当前局面构造求解问题。
+    pub fn from_world(world: &World) -> Self {
+        let time_slow = world.power_up.active && world.power_up.kind == PowerUpKind::TimeSlow;
+        Self {
+            player: world.player.clone(),
+            obstacles: world.obstacles.clone(),
+            power_ups: world.power_ups.clone(),
+            tuning: world.tuning.clone(),
+            time: world.difficulty.time,
+            time_slow_remaining: if time_slow { world.power_up.timer } else { 0.0 },
+        }
+    }
+
+    /// 搜索一条越过全部障碍的输入序列，从下一个模拟步开始逐步对应。
+    pub fn solve(&self) -> Option<Vec<InputFrame>> {
+        let mut unlimited = usize::MAX;
+        self.solve_within(&mut unlimited)
+    }
+
+    /// 同 [`Scenario::solve`]，但最多模拟 `budget` 个玩家步，并从中扣除实际用量；
+    /// 预算耗尽时放弃搜索并返回 `None`。
+    pub fn solve_within(&self, budget: &mut usize) -> Option<Vec<InputFrame>> {
+        let timeline = Timeline::build(self);
+        // 起始局面本身就已撞上：生成检查发生在本步碰撞处理之前，这一步的拾取与碰撞同样要算进去。
+        if timeline.current.hits(&self.player) {
+            return None;
+        }
+        let mut nodes: Vec<Node> = vec![Node {
+            player: self.player.clone(),
+            parent: usize::MAX,
+            action: 0,
+        }];
+        if timeline.steps.is_empty() {
+            return Some(Vec::new());
+        }
+        let mut layer: Vec<usize> = vec![0];
+        let layers = timeline.steps.len() / DECISION_STEPS;
+        for depth in 0..layers {
+            let mut seen = HashSet::new();
+            let mut next = Vec::new();
+            for &index in &layer {
+                for (action, input) in ACTIONS.iter().enumerate() {
+                    let mut player = nodes[index].player.clone();
+                    let start = depth * DECISION_STEPS;
+                    let mut alive = true;
+                    for step in start..start + DECISION_STEPS {
+                        if *budget == 0 {
+                            return None;
+                        }
+                        *budget -= 1;
+                        let mut frame = *input;
+                        frame.jump_pressed &= step == start;
+                        timeline.advance(step, &mut player, &frame, &self.tuning);
+                        if timeline.hits(step, &player) {
+                            alive = false;
+                            break;
+                        }
+                    }
+                    if !alive {
+                        continue;
+                    }
+                    let end = start + DECISION_STEPS - 1;
+                    nodes.push(Node {
+                        player,
+                        parent: index,
+                        action,
+                    });
+                    let id = nodes.len() - 1;
+                    if timeline.cleared(end, &nodes[id].player) {
+                        return Some(witness(&nodes, id));
+                    }
+                    if next.len() < MAX_STATES_PER_LAYER
+                        && seen.insert(state_key(&nodes[id].player))
+                    {
+                        next.push(id);
+                    }
+                }
+            }
+            if next.is_empty() {
+                return None;
+            }
+            layer = next;
+        }
+        None
+    }
+
+    /// 是否存在可行的输入序列。
+    pub fn is_survivable(&self) -> bool {
+        self.solve().is_some()
+    }
+
+    /// 在预算内是否找到了可行的输入序列；预算耗尽视为无解，结论偏保守。
+    pub fn is_survivable_within(&self, budget: &mut usize) -> bool {
+        self.solve_within(budget).is_some()
+    }
+}
+
+/// 搜索树中的一个节点：决策间隔结束时的玩家状态。
+struct Node {
+    player: Player,
+    parent: usize,
+    action: usize,
+}
+
+/// 从叶节点回溯出逐步的输入序列。
+fn witness(nodes: &[Node], leaf: usize) -> Vec<InputFrame> {
+    let mut actions = Vec::new();
+    let mut index = leaf;
+    while nodes[index].parent != usize::MAX {
+        actions.push(nodes[index].action);
+        index = nodes[index].parent;
+    }
+    actions.reverse();
+    let mut frames = Vec::with_capacity(actions.len() * DECISION_STEPS);
+    for action in actions {
+        for step in 0..DECISION_STEPS {
+            let mut frame = ACTIONS[action];
+            frame.jump_pressed &= step == 0;
+            frames.push(frame);
+        }
+    }
+    frames
+}
+
+/// 用于合并相近状态的量化键。
+fn state_key(p: &Player) -> (i32, i32, i32, u32, i32, i32, i32) {
+    (
+        (p.pos.x / 4.0).round() as i32,
+        (p.pos.y / 3.0).round() as i32,
+        (p.vel.y / 40.0).round() as i32,
+        p.air_jumps_left,
+        (p.stamina / 8.0).round() as i32,
+        (p.action_timer * 20.0).round() as i32 * 8 + p.action as i32,
+        // 滑铲剩余时长同时决定了冲量与无敌窗口。
+        (p.slide_timer * 20.0).round() as i32,
+    )
+}
+
+/// 障碍的运动与玩家无关，预先算出每一步的障碍位置与环境参数。
+struct Timeline {
+    /// 尚未推进时的局面。
+    current: StepEnv,
+    steps: Vec<StepEnv>,
+}
+
+struct StepEnv {
+    /// 应用输入时使用的地面高度（推进时间之前）。
+    input_ground: f32,
+    /// 推进玩家与碰撞判定时使用的地面高度。
+    ground: f32,
+    scaled_dt: f32,
+    /// 本步障碍更新后的 (种类, 外形, 碰撞盒)。
+    obstacles: Vec<(ObstacleKind, Rect, Rect)>,
+    /// 本步拾取后会改变时间流速的强化道具外形。
+    pickups: Vec<Rect>,
+}
+
+impl Timeline {
+    /// 逐步复现 `update_world` 中奔跑状态的时间推进与障碍移动。
+    fn build(scenario: &Scenario) -> Self {
+        // 玩家不会后退：所有障碍越过玩家当前位置后即可停止，最长不超过搜索时长。
+        let max_steps = (HORIZON_SECONDS / SIM_DT) as usize;
+        let mut difficulty = DifficultyTrack {
+            time: scenario.time,
+            ..DifficultyTrack::new()
+        };
+        let mut obstacles = scenario.obstacles.clone();
+        let mut power_ups = scenario.power_ups.clone();
+        let mut slow_remaining = scenario.time_slow_remaining;
+        let tuning = &scenario.tuning;
+        let ground = difficulty.ground_y(tuning);
+        let current = StepEnv::new(
+            ground,
+            ground,
+            SIM_DT,
+            &obstacles,
+            &power_ups,
+            slow_remaining > 0.0,
+        );
+        let mut steps = Vec::new();
+        // 步数取决策间隔的整数倍，最后一次决策也能完整模拟。
+        while steps.len() % DECISION_STEPS != 0
+            || (steps.len() < max_steps
+                && obstacles
+                    .iter()
+                    .any(|o| o.rect.x + o.rect.w >= scenario.player.pos.x))
+        {
+            let input_ground = difficulty.ground_y(tuning);
+            let time_scale = if slow_remaining > 0.0 {
+                tuning.powerups.time_slow_scale
+            } else {
+                1.0
+            };
+            let scaled_dt = SIM_DT * time_scale;
+            difficulty.time += scaled_dt;
+            slow_remaining -= scaled_dt;
+            let ground = difficulty.ground_y(tuning);
+            let speed = difficulty.scroll_speed(tuning);
+            for obstacle in &mut obstacles {
+                obstacle.update(scaled_dt, speed * time_scale);
+            }
+            for pickup in &mut power_ups {
+                pickup.update(scaled_dt, speed * time_scale);
+            }
+            steps.push(StepEnv::new(
+                input_ground,
+                ground,
+                scaled_dt,
+                &obstacles,
+                &power_ups,
+                slow_remaining > 0.0,
+            ));
+        }
+        Self { current, steps }
+    }
+
+    /// 按 `step_world` 的顺序推进玩家一个模拟步。
+    fn advance(&self, step: usize, player: &mut Player, input: &InputFrame, tuning: &Tuning) {
+        let env = &self.steps[step];
+        control_player(player, input, SIM_DT, env.input_ground, tuning);
+        update_player(player, env.scaled_dt, env.ground, &tuning.player);
+    }
+
+    fn hits(&self, step: usize, player: &Player) -> bool {
+        self.steps[step].hits(player)
+    }
+
+    /// 全部障碍是否都已经越过玩家。
+    fn cleared(&self, step: usize, player: &Player) -> bool {
+        self.steps[step]
+            .obstacles
+            .iter()
+            .all(|(_, rect, _)| rect.x + rect.w < player.pos.x)
+    }
+}
+
+impl StepEnv {
+    fn new(
+        input_ground: f32,
+        ground: f32,
+        scaled_dt: f32,
+        obstacles: &[Obstacle],
+        power_ups: &[PowerUpPickup],
+        slowed: bool,
+    ) -> Self {
+        Self {
+            input_ground,
+            ground,
+            scaled_dt,
+            obstacles: obstacles
+                .iter()
+                .map(|o| (o.kind, o.rect, o.hurt_box))
+                .collect(),
+            pickups: power_ups
+                .iter()
+                .filter(|p| slowed || p.kind == PowerUpKind::TimeSlow)
+                .map(|p| p.rect)
+                .collect(),
+        }
+    }
+
+    /// 与 `handle_collisions` 相同的致命碰撞判定，外加拾取会改变流速的道具。
+    fn hits(&self, player: &Player) -> bool {
+        let rect = player.rect();
+        self.pickups.iter().any(|pickup| pickup.overlaps(&rect))
+            || self.obstacles.iter().any(|(kind, obstacle, hurt_box)| {
+                if *kind == ObstacleKind::Pit {
+                    rect.x + rect.w > obstacle.x
+                        && rect.x < obstacle.x + obstacle.w
+                        && player.pos.y + PLAYER_SIZE.y >= self.ground - 4.0
+                } else {
+                    !player.is_invulnerable() && hurt_box.overlaps(&rect)
+                }
+            })
+    }
+}
@@ -0,0 +1,87 @@
+//! 单局统计：对局中逐步累积，结算界面展示并与个人最佳对比；最佳一局随存档保存。
+
+use crate::events::GameEvent;
+use crate::types::{CollectibleKind, ObstacleKind};
+use serde::{Deserialize, Serialize};
+
+/// 一局的统计数据。缺失的字段取默认值，便于旧存档与新增字段兼容。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunStats {
+    /// 撞上的障碍种类；仍在进行或存活到上限时为 `None`。
+    pub killed_by: Option<ObstacleKind>,
+    /// 距离与存活时间在结算时由 [`World::run_stats`](crate::world::World::run_stats) 填入。
+    pub distance: f32,
+    pub survival_time: f32,
+    pub coins: u32,
+    pub gems: u32,
+    pub max_combo: u32,
+    pub max_streak: f32,
+    /// 实际消耗体力冲刺的累计时长（秒），由输入处理逐步累加。
+    pub dash_time: f32,
+    /// 地面起跳与空中跳跃次数。
+    pub jumps: u32,
+    pub air_jumps: u32,
+    /// 拾取并触发的强化道具数量。
+    pub power_ups: u32,
+    /// 与障碍擦身而过的次数。
+    pub near_misses: u32,
+    /// 本局实际入账的货币，在发放货币处累加；演示模式下恒为 0。
+    pub currency_earned: u32,
+}
+
+impl Default for RunStats {
+    fn default() -> Self {
+        Self {
+            killed_by: None,
+            distance: 0.0,
+            survival_time: 0.0,
+            coins: 0,
+            gems: 0,
+            max_combo: 0,
+            // 连击倍率从 x1.0 起算。
+            max_streak: 1.0,
+            dash_time: 0.0,
+            jumps: 0,
+            air_jumps: 0,
+            power_ups: 0,
+            near_misses: 0,
+            currency_earned: 0,
+        }
+    }
+}
+
+impl RunStats {
+    /// 从玩法事件累积计数类统计。
+    pub fn observe(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::Jumped { air: true, .. } => self.air_jumps += 1,
+            GameEvent::Jumped { air: false, .. } => self.jumps += 1,
+            GameEvent::Collected {
+                kind,
+                streak,
+                combo,
+                ..
+            } => {
+                match kind {
+                    CollectibleKind::Coin => self.coins += 1,
+                    CollectibleKind::Gem => self.gems += 1,
+                }
+                self.max_streak = self.max_streak.max(streak);
+                self.max_combo = self.max_combo.max(combo);
+            }
+            GameEvent::PowerUpStarted { .. } => self.power_ups += 1,
+            GameEvent::NearMiss(_) => self.near_misses += 1,
+            GameEvent::Died { killed_by, .. } => self.killed_by = Some(killed_by),
+            _ => {}
+        }
+    }
+
+    /// 只有距离的记录，用于从只保存了最佳距离的旧存档恢复。
+    pub fn from_distance(distance: f32) -> Self {
+        Self {
+            distance,
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! 固定步长时钟：把渲染帧的真实时间换算成等长的模拟步，与帧率无关。
+
+use crate::constants::*;
+
+/// 固定步长累加器：把可变的帧间隔切分为若干个等长的模拟步。
+pub struct FixedTimestep {
+    accumulator: f32,
+    step: f32,
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new(SIM_HZ)
+    }
+}
+
+impl FixedTimestep {
+    /// 以给定频率（Hz）创建累加器。
+    pub fn new(hz: f32) -> Self {
+        Self {
+            accumulator: 0.0,
+            step: 1.0 / hz,
+        }
+    }
+
+    /// 单个模拟步的时长（秒）。
+    pub fn step_dt(&self) -> f32 {
+        self.step
+    }
+
+    /// 累积一帧的真实时间，返回本帧需要执行的模拟步数。
+    pub fn advance(&mut self, frame_dt: f32) -> u32 {
+        self.accumulator += frame_dt.clamp(0.0, MAX_FRAME_TIME);
+        let steps = (self.accumulator / self.step) as u32;
+        self.accumulator -= steps as f32 * self.step;
+        steps
+    }
+
+    /// 剩余时间占一步的比例，渲染层据此在上一步与当前步之间插值。
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+}
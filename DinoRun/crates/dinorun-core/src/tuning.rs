@@ -0,0 +1,488 @@
+//! 玩法调参：手感与节奏相关的数值集中在 [`Tuning`] 中，由 `tuning.toml` 提供。
+//!
+//! 文件中缺失的字段取内置默认值，未知字段视为拼写错误直接报错。
+//! [`TuningWatcher`] 轮询文件修改时间，供游戏在运行中热重载。
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// 调参文件的默认位置（相对于工作目录）。
+pub const TUNING_PATH: &str = "assets/tuning.toml";
+
+/// 全部可调数值。
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Tuning {
+    pub player: PlayerTuning,
+    pub stamina: StaminaTuning,
+    pub slide: SlideTuning,
+    pub scroll: ScrollTuning,
+    pub difficulty: DifficultyTuning,
+    pub scoring: ScoringTuning,
+    pub powerups: PowerUpTuning,
+}
+
+/// 跳跃、重力与冲刺。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlayerTuning {
+    pub gravity: f32,
+    pub terminal_velocity: f32,
+    /// 起跳时的向上速度，决定按住跳跃时的最高高度（见 [`PlayerTuning::max_jump_height`]）。
+    pub jump_velocity: f32,
+    /// 空中跳跃时的向上速度。
+    pub air_jump_velocity: f32,
+    pub dash_speed: f32,
+    /// 轻点跳跃时的最低高度（像素），空中跳跃同样适用。
+    pub min_jump_height: f32,
+    /// 离开地面后仍可以起跳的宽限时间（秒）。
+    pub coyote_time: f32,
+    /// 无法起跳时按下的跳跃被记住的时间（秒），期间落地会自动起跳。
+    pub jump_buffer: f32,
+}
+
+impl Default for PlayerTuning {
+    fn default() -> Self {
+        Self {
+            gravity: 2000.0,
+            terminal_velocity: 1400.0,
+            jump_velocity: 820.0,
+            air_jump_velocity: 780.0,
+            dash_speed: 820.0,
+            min_jump_height: 48.0,
+            coyote_time: 0.1,
+            jump_buffer: 0.12,
+        }
+    }
+}
+
+impl PlayerTuning {
+    /// 按住跳跃时地面起跳的最高高度（像素）。
+    pub fn max_jump_height(&self) -> f32 {
+        self.jump_velocity * self.jump_velocity / (2.0 * self.gravity)
+    }
+
+    /// 松开跳跃键后允许保留的最大上升速度，对应 `min_jump_height`。
+    pub fn jump_cut_velocity(&self) -> f32 {
+        (2.0 * self.gravity * self.min_jump_height).sqrt()
+    }
+}
+
+/// 冲刺体力的上限与消耗、恢复速率（升级前的基础值）。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StaminaTuning {
+    pub max: f32,
+    pub consume_rate: f32,
+    pub recover_rate: f32,
+}
+
+impl Default for StaminaTuning {
+    fn default() -> Self {
+        Self {
+            max: 100.0,
+            consume_rate: 48.0,
+            recover_rate: 22.0,
+        }
+    }
+}
+
+/// 滑铲的时长、消耗与起始时的冲量和无敌窗口。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SlideTuning {
+    /// 一次滑铲至少持续的时间（秒），提前松开也会滑完。
+    pub min_duration: f32,
+    /// 每次开始滑铲消耗的体力；剩余体力不足时不能起滑。
+    pub stamina_cost: f32,
+    /// 起滑时的向前速度，在最短时长内线性衰减到 0。
+    pub speed: f32,
+    /// 起滑后穿过障碍不受伤的时间（秒），深坑除外。
+    pub invulnerability: f32,
+}
+
+impl Default for SlideTuning {
+    fn default() -> Self {
+        Self {
+            min_duration: 0.35,
+            stamina_cost: 10.0,
+            speed: 180.0,
+            invulnerability: 0.1,
+        }
+    }
+}
+
+/// 滚屏速度与地面起伏。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScrollTuning {
+    pub base_speed: f32,
+    pub max_speed: f32,
+    /// 每秒增加的滚屏速度。
+    pub acceleration: f32,
+    pub ground_variation: f32,
+}
+
+impl Default for ScrollTuning {
+    fn default() -> Self {
+        Self {
+            base_speed: 360.0,
+            max_speed: 820.0,
+            acceleration: 12.0,
+            ground_variation: 40.0,
+        }
+    }
+}
+
+/// 障碍、收集物与强化道具的刷新节奏。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultyTuning {
+    /// 开局时障碍的刷新间隔（秒）。
+    pub obstacle_spacing: f32,
+    /// 每秒缩短的障碍间隔。
+    pub obstacle_spacing_decay: f32,
+    pub obstacle_min_spacing: f32,
+    /// 每次障碍间隔上叠加的随机抖动范围（秒）。
+    pub obstacle_jitter_min: f32,
+    pub obstacle_jitter_max: f32,
+    /// 加上抖动后障碍间隔的下限（秒）。
+    pub obstacle_min_interval: f32,
+    /// 收集物的基础刷新间隔（秒），随时间按正弦起伏。
+    pub collectible_interval: f32,
+    /// 起伏的幅度（秒）与角频率（弧度/秒）。
+    pub collectible_wave: f32,
+    pub collectible_wave_rate: f32,
+    /// 起伏后、加上抖动前的间隔下限（秒）。
+    pub collectible_wave_min: f32,
+    /// 每次收集物间隔上叠加的随机抖动范围（秒）。
+    pub collectible_jitter_min: f32,
+    pub collectible_jitter_max: f32,
+    /// 加上抖动后收集物间隔的下限（秒）。
+    pub collectible_min_interval: f32,
+    pub powerup_interval: f32,
+    /// 每秒增加的强化道具间隔。
+    pub powerup_interval_growth: f32,
+    pub powerup_max_interval: f32,
+    /// 每次强化道具间隔上叠加的随机抖动范围（秒）。
+    pub powerup_jitter_min: f32,
+    pub powerup_jitter_max: f32,
+    /// 加上抖动后强化道具间隔的下限（秒）。
+    pub powerup_min_interval: f32,
+}
+
+impl Default for DifficultyTuning {
+    fn default() -> Self {
+        Self {
+            obstacle_spacing: 1.4,
+            obstacle_spacing_decay: 0.012,
+            obstacle_min_spacing: 0.62,
+            obstacle_jitter_min: -0.22,
+            obstacle_jitter_max: 0.28,
+            obstacle_min_interval: 0.35,
+            collectible_interval: 1.0,
+            collectible_wave: 1.0,
+            collectible_wave_rate: 0.027,
+            collectible_wave_min: 0.3,
+            collectible_jitter_min: -0.3,
+            collectible_jitter_max: 0.5,
+            collectible_min_interval: 0.24,
+            powerup_interval: 9.0,
+            powerup_interval_growth: 0.04,
+            powerup_max_interval: 18.0,
+            powerup_jitter_min: -1.5,
+            powerup_jitter_max: 2.5,
+            powerup_min_interval: 4.0,
+        }
+    }
+}
+
+/// 收集物价值与连击倍率。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScoringTuning {
+    /// 每滚过 1 像素得到的距离分，再乘连击倍率。
+    pub scroll_score: f32,
+    /// 玩家自身每向前移动 1 像素得到的距离分（冲刺、滑铲的冲量）。
+    pub speed_score: f32,
+    pub coin_value: u32,
+    pub gem_value: u32,
+    /// 刷新收集物时出现宝石的概率。
+    pub gem_chance: f32,
+    /// 每次拾取增加的连击倍率。
+    pub streak_growth: f32,
+    /// 没有拾取时每秒衰减的连击倍率（按流速缩放后的时间计）。
+    pub streak_decay: f32,
+    pub streak_max: f32,
+    /// ScoreBoost 生效期间距离得分、拾取得分与货币的倍率。
+    pub score_boost_mult: f32,
+}
+
+impl Default for ScoringTuning {
+    fn default() -> Self {
+        Self {
+            scroll_score: 0.05,
+            speed_score: 0.07,
+            coin_value: 20,
+            gem_value: 120,
+            gem_chance: 0.2,
+            streak_growth: 0.08,
+            streak_decay: 0.72,
+            streak_max: 3.0,
+            score_boost_mult: 2.0,
+        }
+    }
+}
+
+/// 强化效果。
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PowerUpTuning {
+    /// 升级前的持续时间（秒）。
+    pub duration: f32,
+    /// TimeSlow 期间的时间缩放。
+    pub time_slow_scale: f32,
+}
+
+impl Default for PowerUpTuning {
+    fn default() -> Self {
+        Self {
+            duration: 6.0,
+            time_slow_scale: 0.6,
+        }
+    }
+}
+
+impl Tuning {
+    /// 解析调参文本并校验取值范围。
+    pub fn parse(text: &str) -> Result<Self, TuningError> {
+        let tuning: Tuning = toml::from_str(text).map_err(TuningError::Parse)?;
+        tuning.validate()?;
+        Ok(tuning)
+    }
+
+    /// 读取调参文件；文件不存在时使用内置默认值。
+    pub fn load(path: &Path) -> Result<Self, TuningError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(TuningError::Io(err)),
+        }
+    }
+
+    /// 编码为 TOML 文本，与 [`Tuning::parse`] 互逆。
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("tuning contains only plain numbers")
+    }
+
+    /// 检查数值是否会让模拟失去意义（非正的重力、倒置的上下限等）。
+    pub fn validate(&self) -> Result<(), TuningError> {
+        let p = &self.player;
+        positive("player.gravity", p.gravity)?;
+        positive("player.terminal_velocity", p.terminal_velocity)?;
+        positive("player.jump_velocity", p.jump_velocity)?;
+        positive("player.air_jump_velocity", p.air_jump_velocity)?;
+        positive("player.dash_speed", p.dash_speed)?;
+        in_range(
+            "player.min_jump_height",
+            p.min_jump_height,
+            0.0,
+            p.max_jump_height(),
+        )?;
+        non_negative("player.coyote_time", p.coyote_time)?;
+        non_negative("player.jump_buffer", p.jump_buffer)?;
+
+        let s = &self.stamina;
+        positive("stamina.max", s.max)?;
+        non_negative("stamina.consume_rate", s.consume_rate)?;
+        non_negative("stamina.recover_rate", s.recover_rate)?;
+
+        let sl = &self.slide;
+        positive("slide.min_duration", sl.min_duration)?;
+        non_negative("slide.stamina_cost", sl.stamina_cost)?;
+        in_range("slide.speed", sl.speed, 0.0, p.dash_speed)?;
+        in_range(
+            "slide.invulnerability",
+            sl.invulnerability,
+            0.0,
+            sl.min_duration,
+        )?;
+
+        let c = &self.scroll;
+        positive("scroll.base_speed", c.base_speed)?;
+        at_least("scroll.max_speed", c.max_speed, c.base_speed)?;
+        non_negative("scroll.acceleration", c.acceleration)?;
+        non_negative("scroll.ground_variation", c.ground_variation)?;
+
+        let d = &self.difficulty;
+        positive("difficulty.obstacle_spacing", d.obstacle_spacing)?;
+        non_negative(
+            "difficulty.obstacle_spacing_decay",
+            d.obstacle_spacing_decay,
+        )?;
+        positive("difficulty.obstacle_min_spacing", d.obstacle_min_spacing)?;
+        finite("difficulty.obstacle_jitter_min", d.obstacle_jitter_min)?;
+        at_least(
+            "difficulty.obstacle_jitter_max",
+            d.obstacle_jitter_max,
+            d.obstacle_jitter_min,
+        )?;
+        positive("difficulty.obstacle_min_interval", d.obstacle_min_interval)?;
+        positive("difficulty.collectible_interval", d.collectible_interval)?;
+        non_negative("difficulty.collectible_wave", d.collectible_wave)?;
+        non_negative("difficulty.collectible_wave_rate", d.collectible_wave_rate)?;
+        non_negative("difficulty.collectible_wave_min", d.collectible_wave_min)?;
+        finite(
+            "difficulty.collectible_jitter_min",
+            d.collectible_jitter_min,
+        )?;
+        at_least(
+            "difficulty.collectible_jitter_max",
+            d.collectible_jitter_max,
+            d.collectible_jitter_min,
+        )?;
+        positive(
+            "difficulty.collectible_min_interval",
+            d.collectible_min_interval,
+        )?;
+        positive("difficulty.powerup_interval", d.powerup_interval)?;
+        non_negative(
+            "difficulty.powerup_interval_growth",
+            d.powerup_interval_growth,
+        )?;
+        at_least(
+            "difficulty.powerup_max_interval",
+            d.powerup_max_interval,
+            d.powerup_interval,
+        )?;
+        finite("difficulty.powerup_jitter_min", d.powerup_jitter_min)?;
+        at_least(
+            "difficulty.powerup_jitter_max",
+            d.powerup_jitter_max,
+            d.powerup_jitter_min,
+        )?;
+        positive("difficulty.powerup_min_interval", d.powerup_min_interval)?;
+
+        let sc = &self.scoring;
+        non_negative("scoring.scroll_score", sc.scroll_score)?;
+        non_negative("scoring.speed_score", sc.speed_score)?;
+        in_range("scoring.gem_chance", sc.gem_chance, 0.0, 1.0)?;
+        non_negative("scoring.streak_growth", sc.streak_growth)?;
+        non_negative("scoring.streak_decay", sc.streak_decay)?;
+        at_least("scoring.streak_max", sc.streak_max, 1.0)?;
+        at_least("scoring.score_boost_mult", sc.score_boost_mult, 1.0)?;
+
+        let u = &self.powerups;
+        positive("powerups.duration", u.duration)?;
+        in_range("powerups.time_slow_scale", u.time_slow_scale, 0.05, 1.0)?;
+        Ok(())
+    }
+}
+
+fn positive(field: &'static str, value: f32) -> Result<(), TuningError> {
+    if value.is_finite() && value > 0.0 {
+        Ok(())
+    } else {
+        Err(TuningError::Invalid {
+            field,
+            reason: format!("must be greater than 0 (got {value})"),
+        })
+    }
+}
+
+fn finite(field: &'static str, value: f32) -> Result<(), TuningError> {
+    if value.is_finite() {
+        Ok(())
+    } else {
+        Err(TuningError::Invalid {
+            field,
+            reason: format!("must be a finite number (got {value})"),
+        })
+    }
+}
+
+fn non_negative(field: &'static str, value: f32) -> Result<(), TuningError> {
+    at_least(field, value, 0.0)
+}
+
+fn at_least(field: &'static str, value: f32, min: f32) -> Result<(), TuningError> {
+    if value.is_finite() && value >= min {
+        Ok(())
+    } else {
+        Err(TuningError::Invalid {
+            field,
+            reason: format!("must be at least {min} (got {value})"),
+        })
+    }
+}
+
+fn in_range(field: &'static str, value: f32, min: f32, max: f32) -> Result<(), TuningError> {
+    if value.is_finite() && (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(TuningError::Invalid {
+            field,
+            reason: format!("must be between {min} and {max} (got {value})"),
+        })
+    }
+}
+
+/// 读取调参文件时可能出现的错误。
+#[derive(Debug)]
+pub enum TuningError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// 数值超出允许范围。
+    Invalid {
+        field: &'static str,
+        reason: String,
+    },
+}
+
+impl fmt::Display for TuningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TuningError::Io(err) => write!(f, "failed to read tuning: {err}"),
+            TuningError::Parse(err) => write!(f, "invalid tuning: {err}"),
+            TuningError::Invalid { field, reason } => write!(f, "{field} {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for TuningError {}
+
+/// 轮询调参文件的修改时间，文件变化后重新加载。
+pub struct TuningWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl TuningWatcher {
+    /// 以当前文件状态为基准开始监视，首次 [`poll`](Self::poll) 不会重复加载。
+    pub fn new(path: PathBuf) -> Self {
+        let modified = modified_time(&path);
+        Self { path, modified }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 文件自上次检查后有变化时返回重新加载的结果，否则返回 `None`。
+    pub fn poll(&mut self) -> Option<Result<Tuning, TuningError>> {
+        let modified = modified_time(&self.path);
+        if modified == self.modified {
+            return None;
+        }
+        self.modified = modified;
+        Some(Tuning::load(&self.path))
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -0,0 +1,180 @@
+use crate::tuning::Tuning;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// 与渲染后端无关的 RGBA 颜色，分量范围为 0.0..=1.0。
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const WHITE: Color = Color::new(1.0, 1.0, 1.0, 1.0);
+    pub const RED: Color = Color::new(0.90, 0.16, 0.22, 1.0);
+    pub const GOLD: Color = Color::new(1.0, 0.80, 0.0, 1.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// 以 0-255 的整数分量构造颜色。
+    pub const fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        )
+    }
+}
+
+/// 玩家当前执行的动作，驱动动画、碰撞盒以及体力消耗逻辑。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PlayerAction {
+    Running,
+    Jump,
+    DoubleJump,
+    Slide,
+    Dash,
+}
+
+/// 强化道具的种类，用于决定触发的增益效果。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerUpKind {
+    Shield,
+    ScoreBoost,
+    TimeSlow,
+}
+
+/// 场景中可生成的障碍物类型；在图案数据文件中以小写名称书写。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ObstacleKind {
+    Crate,
+    Saw,
+    Pit,
+    Drone,
+    /// 从高处射下的激光，只能滑铲通过。
+    Laser,
+}
+
+impl ObstacleKind {
+    pub const ALL: [ObstacleKind; 5] = [
+        ObstacleKind::Crate,
+        ObstacleKind::Saw,
+        ObstacleKind::Pit,
+        ObstacleKind::Drone,
+        ObstacleKind::Laser,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ObstacleKind::Crate => "Crate",
+            ObstacleKind::Saw => "Saw",
+            ObstacleKind::Pit => "Pit",
+            ObstacleKind::Drone => "Drone",
+            ObstacleKind::Laser => "Laser",
+        }
+    }
+}
+
+/// 可收集物体的分类，与得分与货币奖励相关联。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollectibleKind {
+    Coin,
+    Gem,
+}
+
+/// 游戏状态机的枚举，涵盖了所有可见流程。
+#[derive(Clone, Copy, PartialEq)]
+pub enum GameState {
+    Splash {
+        timer: f32,
+    },
+    Menu {
+        fade: f32,
+    },
+    /// 升级商店，从主菜单进入。
+    Shop,
+    /// 成就列表，从主菜单进入。
+    Achievements,
+    /// 设置页，从主菜单进入。
+    Settings,
+    /// 按键设置页，从设置页进入。
+    Controls,
+    Running,
+    Paused,
+    GameOver {
+        cooldown: f32,
+    },
+}
+
+/// 浮动提示文字，用于表现提示与得分反馈。
+pub struct FloatingText {
+    pub text: String,
+    pub pos: Vec2,
+    pub vel: Vec2,
+    pub timer: f32,
+    pub max_timer: f32,
+    pub color: Color,
+}
+
+/// 动态难度曲线的状态结构，掌控障碍与滚屏节奏。
+pub struct DifficultyTrack {
+    pub time: f32,
+    pub rng_obstacle_timer: f32,
+    pub rng_collectible_timer: f32,
+    pub rng_powerup_timer: f32,
+}
+
+impl Default for DifficultyTrack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DifficultyTrack {
+    /// 使用默认值初始化曲线，适用于新开一局的初始节奏。
+    pub fn new() -> Self {
+        Self {
+            time: 0.0,
+            rng_obstacle_timer: 1.6,
+            rng_collectible_timer: 1.2,
+            rng_powerup_timer: 6.0,
+        }
+    }
+
+    /// 依据时间推移提升滚动速度，直到达到上限。
+    pub fn scroll_speed(&self, tuning: &Tuning) -> f32 {
+        let scroll = &tuning.scroll;
+        (scroll.base_speed + self.time * scroll.acceleration).min(scroll.max_speed)
+    }
+
+    /// 让地面高度随时间轻微波动，营造灵动感。
+    pub fn ground_y(&self, tuning: &Tuning) -> f32 {
+        use crate::constants::*;
+        BASE_GROUND_Y + (self.time * 0.1).sin() * tuning.scroll.ground_variation
+    }
+
+    /// 返回下次障碍刷新的间隔，时间越久越短。
+    pub fn obstacle_interval(&self, tuning: &Tuning) -> f32 {
+        let d = &tuning.difficulty;
+        (d.obstacle_spacing - self.time * d.obstacle_spacing_decay).max(d.obstacle_min_spacing)
+    }
+
+    /// 返回强化道具的刷新间隔，越往后越稀有。
+    pub fn powerup_interval(&self, tuning: &Tuning) -> f32 {
+        let d = &tuning.difficulty;
+        (d.powerup_interval + self.time * d.powerup_interval_growth).min(d.powerup_max_interval)
+    }
+
+    /// 返回收集物的刷新间隔，带有周期性的呼吸感。
+    pub fn collectible_interval(&self, tuning: &Tuning) -> f32 {
+        let d = &tuning.difficulty;
+        (d.collectible_interval + d.collectible_wave * (self.time * d.collectible_wave_rate).sin())
+            .max(d.collectible_wave_min)
+    }
+}
@@ -0,0 +1,425 @@
+use crate::achievements::process_events;
+use crate::collectibles::Collectible;
+use crate::constants::*;
+use crate::events::GameEvent;
+use crate::input::{InputFrame, apply_input};
+use crate::obstacles::Obstacle;
+use crate::particles::{
+    spawn_boost_trail, spawn_event_effect, update_floating_texts, update_particles,
+};
+use crate::player::update_player;
+use crate::powerups::{PowerUpPickup, choose_power_up_kind};
+use crate::solver::Scenario;
+use crate::types::{CollectibleKind, GameState, ObstacleKind, PowerUpKind};
+use crate::utils::Rect;
+use crate::world::World;
+use glam::vec2;
+
+/// 图案无解时最多重抽的次数。
+const MAX_CHUNK_REROLLS: usize = 4;
+/// 所有重抽都无解时，推迟多久再尝试生成（秒）。
+const CHUNK_RETRY_DELAY: f32 = 0.1;
+/// 强化道具会让玩家无路可走时，推迟多久再尝试生成（秒）。
+const POWERUP_RETRY_DELAY: f32 = 0.5;
+/// 每个模拟步内生成检查最多模拟的玩家步数，约合发布构建下 4 ms。
+/// 预算耗尽的检查视为无解，生成推迟到稍后，以免单帧卡顿。
+pub const SOLVER_BUDGET: usize = 60_000;
+/// 越过障碍时与伤害判定框的距离小于该值（像素）即算擦身而过。
+const NEAR_MISS_DISTANCE: f32 = 14.0;
+
+/// 执行一个完整的模拟步：先应用输入，再推进世界，最后把本步事件分发给订阅者。
+pub fn step_world(world: &mut World, input: &InputFrame, dt: f32) {
+    world.events.clear();
+    apply_input(world, input, dt);
+    update_world(world, dt);
+    dispatch_events(world);
+}
+
+/// 把本步事件依次交给核心内的订阅者：单局统计、粒子与提示文字、成就。
+/// 队列保留到下一步开始，渲染层可在步后读取。
+fn dispatch_events(world: &mut World) {
+    let mut died = false;
+    for event in &world.events {
+        world.stats.observe(event);
+        spawn_event_effect(
+            &mut world.particles,
+            &mut world.rng.cosmetic,
+            &mut world.floating_texts,
+            world.player.pos,
+            event,
+        );
+        died |= matches!(event, GameEvent::Died { .. });
+    }
+    if died {
+        world.record_run();
+    }
+    process_events(world);
+}
+
+/// 根据当前 GameState 推进世界状态，是游戏逻辑的核心调度函数；`dt` 应为固定步长。
+pub fn update_world(world: &mut World, dt: f32) {
+    world.store_previous_positions();
+    match world.state {
+        GameState::Splash { ref mut timer } => {
+            *timer += dt;
+            if *timer > 2.6 {
+                world.state = GameState::Menu { fade: 0.0 };
+            }
+        }
+        GameState::Menu { ref mut fade } => {
+            *fade = (*fade + dt / MENU_FADE_TIME).min(1.0);
+            world.day_phase = (world.day_phase + dt / DAY_NIGHT_DURATION) % 1.0;
+            update_particles(
+                &mut world.particles,
+                dt,
+                world.tuning.scroll.base_speed * 0.2,
+            );
+        }
+        GameState::Shop | GameState::Achievements | GameState::Settings | GameState::Controls => {
+            world.day_phase = (world.day_phase + dt / DAY_NIGHT_DURATION) % 1.0;
+            update_particles(
+                &mut world.particles,
+                dt,
+                world.tuning.scroll.base_speed * 0.2,
+            );
+        }
+        GameState::Running => {
+            // TimeSlow 强化会缩放时间，影响所有更新逻辑。
+            let time_slow = world.power_up.active && world.power_up.kind == PowerUpKind::TimeSlow;
+            let time_scale = if time_slow {
+                world.tuning.powerups.time_slow_scale
+            } else {
+                1.0
+            };
+            let scaled_dt = dt * time_scale;
+            world.difficulty.time += scaled_dt;
+            let seconds_before = world.survival_time as u32;
+            world.survival_time += scaled_dt;
+            if world.survival_time as u32 > seconds_before {
+                world.events.push(GameEvent::Survived {
+                    seconds: world.survival_time as u32,
+                });
+            }
+            world.day_phase = (world.day_phase + scaled_dt / DAY_NIGHT_DURATION) % 1.0;
+            let active_power_up = world.power_up.active.then_some(world.power_up.kind);
+            world.power_up.update(scaled_dt);
+            if let Some(kind) = active_power_up
+                && !world.power_up.active
+            {
+                world.events.push(GameEvent::PowerUpEnded(kind));
+            }
+            world.slow_mo_factor = time_scale;
+
+            // 逐个子系统更新：玩家、障碍、收集物、碰撞与粒子。
+            let ground = world.difficulty.ground_y(&world.tuning);
+            if let Some(speed) =
+                update_player(&mut world.player, scaled_dt, ground, &world.tuning.player)
+            {
+                world.events.push(GameEvent::Landed { speed });
+            }
+            let mut budget = SOLVER_BUDGET;
+            update_obstacles(world, scaled_dt, ground, &mut budget);
+            update_collectibles(world, scaled_dt, ground);
+            update_power_ups(world, scaled_dt, ground, &mut budget);
+            handle_collisions(world, ground, scaled_dt);
+            update_particles(
+                &mut world.particles,
+                scaled_dt,
+                world.difficulty.scroll_speed(&world.tuning),
+            );
+            update_floating_texts(&mut world.floating_texts, scaled_dt);
+            let scoring = &world.tuning.scoring;
+            let distance = (world.difficulty.scroll_speed(&world.tuning)
+                * scaled_dt
+                * world.streak_mult
+                * scoring.scroll_score)
+                + (world.player.vel.x * scaled_dt * scoring.speed_score);
+            add_score(world, distance);
+
+            // ScoreBoost 期间在玩家身后留下金色拖尾。
+            if world.score_boost_active() && world.rng.cosmetic.next_f32() < 0.4 {
+                let origin = world.player.pos + vec2(0.0, PLAYER_SIZE.y * 0.5);
+                spawn_boost_trail(&mut world.particles, &mut world.rng.cosmetic, origin);
+            }
+        }
+        GameState::Paused => {
+            world.pause_flash = (world.pause_flash - dt).max(0.0);
+        }
+        GameState::GameOver { ref mut cooldown } => {
+            *cooldown = (*cooldown - dt).max(0.0);
+            // 演示模式在结算画面停留片刻后自动开始下一局。
+            if world.autoplay && *cooldown <= 0.0 {
+                world.start_autoplay();
+                return;
+            }
+            update_particles(
+                &mut world.particles,
+                dt * 0.75,
+                world.tuning.scroll.base_speed * 0.5,
+            );
+            update_floating_texts(&mut world.floating_texts, dt);
+        }
+    }
+}
+
+/// 按当前倍率累加得分，并记录 ScoreBoost 额外带来的部分。
+fn add_score(world: &mut World, base: f32) {
+    let gained = base * world.score_multiplier();
+    world.score += gained;
+    world.boost_score += gained - base;
+}
+
+/// 按难度抽取障碍图案整体生成，并更新已有障碍的位置。
+fn update_obstacles(world: &mut World, dt: f32, ground: f32, budget: &mut usize) {
+    let speed = world.difficulty.scroll_speed(&world.tuning);
+    world.difficulty.rng_obstacle_timer -= dt;
+    if world.difficulty.rng_obstacle_timer <= 0.0 {
+        world.difficulty.rng_obstacle_timer = match spawn_chunk(world, dt, ground, budget) {
+            // 等整个图案滚过之后再计算常规间隔，避免图案之间互相叠压。
+            Some(length) => {
+                let d = &world.tuning.difficulty;
+                let interval = length / (speed * world.slow_mo_factor)
+                    + world.difficulty.obstacle_interval(&world.tuning)
+                    + world
+                        .rng
+                        .gameplay
+                        .gen_range(d.obstacle_jitter_min, d.obstacle_jitter_max);
+                interval.max(d.obstacle_min_interval)
+            }
+            None => CHUNK_RETRY_DELAY,
+        };
+    }
+    let player_rect = world.player.rect();
+    for obstacle in &mut world.obstacles {
+        obstacle.update(dt, speed * world.slow_mo_factor);
+        if obstacle.cleared {
+            continue;
+        }
+        obstacle.closest = obstacle.closest.min(obstacle.hurt_box.gap(&player_rect));
+        if obstacle.rect.x + obstacle.rect.w < player_rect.x {
+            obstacle.cleared = true;
+            world.events.push(GameEvent::ObstacleCleared(obstacle.kind));
+            // 深坑按是否踩空判定，没有“擦身而过”。
+            if obstacle.kind != ObstacleKind::Pit && obstacle.closest < NEAR_MISS_DISTANCE {
+                world.events.push(GameEvent::NearMiss(obstacle.kind));
+            }
+        }
+    }
+    world.obstacles.retain(|o| !o.is_offscreen());
+}
+
+/// 抽取一个玩家仍有办法越过的图案并生成，返回图案长度。
+/// 连续重抽都无解或求解预算耗尽时不生成并返回 `None`，稍后再试以拉开与前方障碍的距离。
+fn spawn_chunk(world: &mut World, dt: f32, ground: f32, budget: &mut usize) -> Option<f32> {
+    let speed = world.difficulty.scroll_speed(&world.tuning) * world.slow_mo_factor;
+    let origin = SCREEN_WIDTH + 80.0;
+    for _ in 0..MAX_CHUNK_REROLLS {
+        if *budget == 0 {
+            break;
+        }
+        let chunk = world
+            .patterns
+            .choose(world.difficulty.time, &mut world.rng.gameplay);
+        let candidates: Vec<Obstacle> = chunk
+            .obstacles
+            .iter()
+            .map(|o| o.build(origin, ground, &mut world.rng.cosmetic))
+            .collect();
+
+        // 生成发生在本步移动障碍与道具之前，求解局面需要先把它们推进到本步结束的位置。
+        let mut scenario = Scenario::from_world(world);
+        scenario.obstacles.extend(candidates.iter().cloned());
+        for obstacle in &mut scenario.obstacles {
+            obstacle.update(dt, speed);
+        }
+        for pickup in &mut scenario.power_ups {
+            pickup.update(dt, speed);
+        }
+        if scenario.is_survivable_within(budget) {
+            let length = chunk.length();
+            world.obstacles.extend(candidates);
+            return Some(length);
+        }
+    }
+    None
+}
+
+/// 刷新收集物并推进其动画。
+fn update_collectibles(world: &mut World, dt: f32, ground: f32) {
+    let speed = world.difficulty.scroll_speed(&world.tuning);
+    world.difficulty.rng_collectible_timer -= dt;
+    if world.difficulty.rng_collectible_timer <= 0.0 {
+        let rng = &mut world.rng.gameplay;
+        let scoring = &world.tuning.scoring;
+        let (kind, value) = if rng.gen_range(0.0, 1.0) > 1.0 - scoring.gem_chance {
+            (CollectibleKind::Gem, scoring.gem_value)
+        } else {
+            (CollectibleKind::Coin, scoring.coin_value)
+        };
+        world
+            .collectibles
+            .push(Collectible::new(kind, value, ground, rng));
+        let d = &world.tuning.difficulty;
+        let interval = world.difficulty.collectible_interval(&world.tuning)
+            + rng.gen_range(d.collectible_jitter_min, d.collectible_jitter_max);
+        world.difficulty.rng_collectible_timer = interval.max(d.collectible_min_interval);
+    }
+    for item in &mut world.collectibles {
+        item.update(dt, speed * world.slow_mo_factor);
+    }
+    world.collectibles.retain(|c| !c.is_offscreen());
+}
+
+/// 按难度曲线的节奏刷新强化道具并推进其动画。
+fn update_power_ups(world: &mut World, dt: f32, ground: f32, budget: &mut usize) {
+    let speed = world.difficulty.scroll_speed(&world.tuning);
+    world.difficulty.rng_powerup_timer -= dt;
+    if world.difficulty.rng_powerup_timer <= 0.0 {
+        let rng = &mut world.rng.gameplay;
+        let kind = choose_power_up_kind(rng);
+        let pickup = PowerUpPickup::new(kind, ground, rng);
+        // 拾取道具可能改变时间流速，同样只在玩家仍有活路时生成，否则稍后再试。
+        if power_up_is_safe(world, &pickup, dt, speed, budget) {
+            world.power_ups.push(pickup);
+            let rng = &mut world.rng.gameplay;
+            let d = &world.tuning.difficulty;
+            let interval = world.difficulty.powerup_interval(&world.tuning)
+                + rng.gen_range(d.powerup_jitter_min, d.powerup_jitter_max);
+            world.difficulty.rng_powerup_timer = interval.max(d.powerup_min_interval);
+        } else {
+            world.difficulty.rng_powerup_timer = POWERUP_RETRY_DELAY;
+        }
+    }
+    for pickup in &mut world.power_ups {
+        pickup.update(dt, speed * world.slow_mo_factor);
+    }
+    world.power_ups.retain(|p| !p.is_offscreen());
+}
+
+/// 加入新道具后玩家是否仍有活路；障碍已在本步移动过，道具尚未移动。
+fn power_up_is_safe(
+    world: &World,
+    pickup: &PowerUpPickup,
+    dt: f32,
+    speed: f32,
+    budget: &mut usize,
+) -> bool {
+    let mut scenario = Scenario::from_world(world);
+    scenario.power_ups.push(pickup.clone());
+    for pickup in &mut scenario.power_ups {
+        pickup.update(dt, speed * world.slow_mo_factor);
+    }
+    scenario.is_survivable_within(budget)
+}
+
+/// 统一处理玩家与障碍、收集物和强化之间的交互。
+fn handle_collisions(world: &mut World, ground: f32, dt: f32) {
+    let player_rect = world.player.rect();
+    let shielded = world.power_up.active && world.power_up.kind == PowerUpKind::Shield;
+    // 起滑的无敌窗口内穿过障碍不受伤，深坑照常判定。
+    let invulnerable = world.player.is_invulnerable();
+    let mut dead = None;
+    let mut shield_hit_info: Option<(usize, Rect)> = None;
+
+    for (i, obstacle) in world.obstacles.iter().enumerate() {
+        if obstacle.kind == ObstacleKind::Pit {
+            if player_rect.x + player_rect.w > obstacle.rect.x
+                && player_rect.x < obstacle.rect.x + obstacle.rect.w
+                && world.player.pos.y + PLAYER_SIZE.y >= ground - 4.0
+            {
+                dead = Some(obstacle.kind);
+                break;
+            }
+        } else if !invulnerable && obstacle.hurt_box.overlaps(&player_rect) {
+            if shielded {
+                shield_hit_info = Some((i, obstacle.rect));
+                break;
+            } else {
+                dead = Some(obstacle.kind);
+                break;
+            }
+        }
+    }
+
+    if let Some((idx, rect)) = shield_hit_info {
+        world.obstacles.remove(idx);
+        world.events.push(GameEvent::ShieldBroke { rect });
+        world
+            .events
+            .push(GameEvent::PowerUpEnded(PowerUpKind::Shield));
+        world.power_up.deactivate();
+        world.player.reset_combo();
+    }
+
+    if let Some(kind) = dead {
+        world.state = GameState::GameOver { cooldown: 0.8 };
+        world.events.push(GameEvent::Died {
+            killed_by: kind,
+            at: world.player.pos + PLAYER_SIZE * 0.5,
+        });
+        if !world.autoplay {
+            let payout = (world.score as u32 / 10) + world.player.combo;
+            world.currency += payout;
+            world.stats.currency_earned += payout;
+            world.boost_currency += world.boost_score as u32 / 10;
+        }
+        world.player.reset_combo();
+        return;
+    }
+
+    let mut collected = Vec::new();
+    for (idx, item) in world.collectibles.iter().enumerate() {
+        if item.rect.overlaps(&player_rect) {
+            collected.push(idx);
+        }
+    }
+    collected.sort_by(|a, b| b.cmp(a));
+    for idx in collected.iter().copied() {
+        let item = world.collectibles.remove(idx);
+        add_score(world, item.value as f32 * world.streak_mult);
+        let coins = item.value / 4;
+        let boosted = (coins as f32 * world.score_multiplier()) as u32;
+        if !world.autoplay {
+            world.currency += boosted;
+            world.stats.currency_earned += boosted;
+            world.boost_currency += boosted - coins;
+        }
+        world.player.add_combo();
+        world.streak_mult = (world.streak_mult + world.tuning.scoring.streak_growth)
+            .min(world.tuning.scoring.streak_max);
+        world.events.push(GameEvent::Collected {
+            kind: item.kind,
+            value: item.value,
+            rect: item.rect,
+            streak: world.streak_mult,
+            combo: world.player.combo,
+        });
+    }
+
+    if collected.is_empty() {
+        world.streak_mult = (world.streak_mult - world.tuning.scoring.streak_decay * dt).max(1.0);
+    }
+
+    let mut picked = Vec::new();
+    for (idx, pickup) in world.power_ups.iter().enumerate() {
+        if pickup.rect.overlaps(&player_rect) {
+            picked.push(idx);
+        }
+    }
+    for idx in picked.into_iter().rev() {
+        let pickup = world.power_ups.remove(idx);
+        if world.power_up.active {
+            world
+                .events
+                .push(GameEvent::PowerUpEnded(world.power_up.kind));
+        }
+        world.power_up.activate(pickup.kind);
+        world.events.push(GameEvent::PowerUpStarted {
+            kind: pickup.kind,
+            at: vec2(
+                pickup.rect.x + pickup.rect.w * 0.5,
+                pickup.rect.y + pickup.rect.h * 0.5,
+            ),
+        });
+    }
+}
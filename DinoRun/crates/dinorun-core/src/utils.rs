@@ -0,0 +1,49 @@
+/// 轴对齐矩形，用于碰撞盒与实体外形，字段与渲染层的矩形一一对应。
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+impl Rect {
+    pub const fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// 判断两个矩形是否相交（边缘相切不算重叠）。
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.x < other.x + other.w
+            && self.x + self.w > other.x
+            && self.y < other.y + other.h
+            && self.y + self.h > other.y
+    }
+
+    /// 两个矩形之间的距离：水平与竖直间隙中较大的一个，相交时为 0。
+    pub fn gap(&self, other: &Rect) -> f32 {
+        let dx = (other.x - (self.x + self.w)).max(self.x - (other.x + other.w));
+        let dy = (other.y - (self.y + self.h)).max(self.y - (other.y + other.h));
+        dx.max(dy).max(0.0)
+    }
+
+    /// 向四周扩张（或以负值收缩）矩形，方便碰撞检测调整。
+    pub fn inflate(&self, amount_x: f32, amount_y: f32) -> Rect {
+        Rect::new(
+            self.x - amount_x,
+            self.y - amount_y,
+            self.w + amount_x * 2.0,
+            self.h + amount_y * 2.0,
+        )
+    }
+
+    /// 按比例 `t` 在两个矩形之间线性插值。
+    pub fn lerp(&self, other: &Rect, t: f32) -> Rect {
+        Rect::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.w + (other.w - self.w) * t,
+            self.h + (other.h - self.h) * t,
+        )
+    }
+}
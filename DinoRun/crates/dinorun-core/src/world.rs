@@ -0,0 +1,294 @@
+use crate::achievements::Achievements;
+use crate::bindings::ControlsPage;
+use crate::collectibles::Collectible;
+use crate::constants::*;
+use crate::events::GameEvent;
+use crate::menu::MenuCursor;
+use crate::obstacles::Obstacle;
+use crate::particles::Particle;
+use crate::patterns::PatternLibrary;
+use crate::player::Player;
+use crate::powerups::PowerUpPickup;
+use crate::rng::{GameRng, Rng};
+use crate::settings::Settings;
+use crate::shop::Upgrades;
+use crate::stats::RunStats;
+use crate::tuning::{PowerUpTuning, Tuning};
+use crate::types::*;
+
+/// 表示一次强化效果的运行时状态。
+pub struct PowerUp {
+    pub active: bool,
+    pub kind: PowerUpKind,
+    pub timer: f32,
+    /// 每次激活的持续时间，受商店升级影响。
+    pub duration: f32,
+}
+
+impl Default for PowerUp {
+    fn default() -> Self {
+        Self {
+            active: false,
+            kind: PowerUpKind::Shield,
+            timer: 0.0,
+            duration: PowerUpTuning::default().duration,
+        }
+    }
+}
+
+impl PowerUp {
+    /// 激活指定类型的强化效果并重置倒计时。
+    pub fn activate(&mut self, kind: PowerUpKind) {
+        self.active = true;
+        self.kind = kind;
+        self.timer = self.duration;
+    }
+
+    /// 关闭强化效果并清零计时。
+    pub fn deactivate(&mut self) {
+        self.active = false;
+        self.timer = 0.0;
+    }
+
+    /// 根据帧间隔推进计时器，自动在归零时失效。
+    pub fn update(&mut self, dt: f32) {
+        if self.active {
+            self.timer -= dt;
+            if self.timer <= 0.0 {
+                self.deactivate();
+            }
+        }
+    }
+}
+
+/// 统一管理玩家实体、场景元素以及游戏状态机的根容器。
+pub struct World {
+    pub player: Player,
+    pub particles: Vec<Particle>,
+    pub obstacles: Vec<Obstacle>,
+    pub collectibles: Vec<Collectible>,
+    pub power_ups: Vec<PowerUpPickup>,
+    pub floating_texts: Vec<FloatingText>,
+    pub power_up: PowerUp,
+    pub state: GameState,
+    pub difficulty: DifficultyTrack,
+    /// 主菜单、商店与设置页的光标。
+    pub main_menu: MenuCursor,
+    pub pause_menu: MenuCursor,
+    pub shop_menu: MenuCursor,
+    pub settings_menu: MenuCursor,
+    /// 按键设置页的光标与改绑状态。
+    pub controls_page: ControlsPage,
+    /// 玩家设置，由设置页修改，渲染层与音频据此调整表现。
+    pub settings: Settings,
+    pub score: f32,
+    pub best_score: f32,
+    pub currency: u32,
+    /// 已购买的永久升级，开局时应用到玩家与强化效果。
+    pub upgrades: Upgrades,
+    /// 当前生效的调参，所有系统从这里读取手感与节奏数值。
+    pub tuning: Tuning,
+    /// 障碍图案库，生成障碍时按难度抽取。
+    pub patterns: PatternLibrary,
+    /// 演示模式：由内置机器人游玩，不计入最佳成绩与货币，结束后自动重开。
+    pub autoplay: bool,
+    pub survival_time: f32,
+    pub slow_mo_factor: f32,
+    pub streak_mult: f32,
+    pub day_phase: f32,
+    pub pause_flash: f32,
+    /// 本局 ScoreBoost 额外带来的得分与货币，用于结算展示。
+    pub boost_score: f32,
+    pub boost_currency: u32,
+    /// 本局逐步累积的统计。
+    pub stats: RunStats,
+    /// 距离最远的一局，随存档保存。
+    pub best_run: Option<RunStats>,
+    /// 本局结算前的最佳记录，供结算界面对比。
+    pub previous_best: Option<RunStats>,
+    /// 本模拟步内发生的玩法事件。
+    pub events: Vec<GameEvent>,
+    /// 成就解锁记录与本局进度。
+    pub achievements: Achievements,
+    /// 当前局的随机源，完全由 `run_seed` 决定。
+    pub rng: GameRng,
+    /// 当前局使用的种子，可用于复现与回放。
+    pub run_seed: u64,
+    /// 为每一局派生新种子的随机流。
+    pub seed_source: Rng,
+}
+
+impl World {
+    /// 游戏全局状态的聚合体，贯穿输入、更新与渲染；`seed` 决定之后所有对局的随机序列。
+    pub fn new(seed: u64) -> Self {
+        Self {
+            player: Player::new(),
+            particles: vec![Particle::default(); MAX_PARTICLES],
+            obstacles: Vec::new(),
+            collectibles: Vec::new(),
+            power_ups: Vec::new(),
+            floating_texts: Vec::new(),
+            power_up: PowerUp::default(),
+            state: GameState::Splash { timer: 0.0 },
+            difficulty: DifficultyTrack::new(),
+            main_menu: MenuCursor::default(),
+            pause_menu: MenuCursor::default(),
+            shop_menu: MenuCursor::default(),
+            settings_menu: MenuCursor::default(),
+            controls_page: ControlsPage::default(),
+            settings: Settings::default(),
+            score: 0.0,
+            best_score: 0.0,
+            currency: 0,
+            upgrades: Upgrades::default(),
+            tuning: Tuning::default(),
+            patterns: PatternLibrary::default(),
+            autoplay: false,
+            survival_time: 0.0,
+            slow_mo_factor: 1.0,
+            streak_mult: 1.0,
+            day_phase: 0.0,
+            pause_flash: 0.0,
+            boost_score: 0.0,
+            boost_currency: 0,
+            stats: RunStats::default(),
+            best_run: None,
+            previous_best: None,
+            events: Vec::new(),
+            achievements: Achievements::default(),
+            rng: GameRng::new(seed),
+            run_seed: seed,
+            seed_source: Rng::new(seed),
+        }
+    }
+
+    /// ScoreBoost 是否正在生效。
+    pub fn score_boost_active(&self) -> bool {
+        self.power_up.active && self.power_up.kind == PowerUpKind::ScoreBoost
+    }
+
+    /// 当前的得分与货币倍率。
+    pub fn score_multiplier(&self) -> f32 {
+        if self.score_boost_active() {
+            self.tuning.scoring.score_boost_mult
+        } else {
+            1.0
+        }
+    }
+
+    /// 是否处于一局之中（奔跑或暂停）。
+    pub fn in_run(&self) -> bool {
+        matches!(self.state, GameState::Running | GameState::Paused)
+    }
+
+    /// 记录各实体在本步开始前的位置，渲染层据此做插值。
+    pub fn store_previous_positions(&mut self) {
+        self.player.prev_pos = self.player.pos;
+        for obstacle in &mut self.obstacles {
+            obstacle.prev_rect = obstacle.rect;
+        }
+        for item in &mut self.collectibles {
+            item.prev_rect = item.rect;
+        }
+        for pickup in &mut self.power_ups {
+            pickup.prev_rect = pickup.rect;
+        }
+    }
+
+    /// 替换调参；对局中途也可调用，玩家的体力上限等派生数值随之刷新。
+    pub fn set_tuning(&mut self, tuning: Tuning) {
+        self.tuning = tuning;
+        let stamina_ratio = self.player.stamina / self.player.max_stamina;
+        self.player.max_stamina = self.upgrades.max_stamina(&self.tuning);
+        self.player.stamina_recover_rate = self.upgrades.stamina_recover_rate(&self.tuning);
+        self.player.stamina = stamina_ratio * self.player.max_stamina;
+        self.power_up.duration = self.upgrades.powerup_duration(&self.tuning);
+    }
+
+    /// 恢复到初始状态，用于开始新一轮奔跑；种子取自 `seed_source`。
+    pub fn reset_run(&mut self) {
+        let seed = self.seed_source.next_u64();
+        self.reset_run_seeded(seed);
+    }
+
+    /// 以指定种子开始新一轮奔跑，相同种子与输入必然得到相同的对局。
+    pub fn reset_run_seeded(&mut self, seed: u64) {
+        self.run_seed = seed;
+        self.rng = GameRng::new(seed);
+        self.player = Player::with_upgrades(&self.upgrades, &self.tuning);
+        self.obstacles.clear();
+        self.collectibles.clear();
+        self.power_ups.clear();
+        self.floating_texts.clear();
+        self.power_up.deactivate();
+        self.power_up.duration = self.upgrades.powerup_duration(&self.tuning);
+        let shield = self.upgrades.starting_shield_duration();
+        if shield > 0.0 {
+            self.power_up.activate(PowerUpKind::Shield);
+            self.power_up.timer = shield;
+        }
+        self.difficulty = DifficultyTrack::new();
+        self.score = 0.0;
+        self.survival_time = 0.0;
+        self.slow_mo_factor = 1.0;
+        self.streak_mult = 1.0;
+        self.boost_score = 0.0;
+        self.boost_currency = 0;
+        self.stats = RunStats::default();
+        self.achievements.start_run();
+        self.previous_best = None;
+        self.events.push(GameEvent::RunStarted);
+        self.day_phase = self.rng.cosmetic.gen_range(0.0, 1.0);
+    }
+
+    /// 本局到目前为止的统计，补上距离与存活时间。
+    pub fn run_stats(&self) -> RunStats {
+        RunStats {
+            distance: self.score,
+            survival_time: self.survival_time,
+            ..self.stats.clone()
+        }
+    }
+
+    /// 结算本局统计：记下结算前的最佳记录，距离更远时刷新最佳成绩。
+    /// 死亡与中途退出都经过这里；演示模式不计入。
+    pub fn record_run(&mut self) {
+        self.stats = self.run_stats();
+        if self.autoplay {
+            return;
+        }
+        self.previous_best = self.best_run.clone();
+        if self.is_new_best() {
+            self.best_score = self.best_score.max(self.score);
+            self.best_run = Some(self.stats.clone());
+        }
+    }
+
+    /// 本局是否刷新了结算前的最佳距离；演示模式从不计入。
+    pub fn is_new_best(&self) -> bool {
+        !self.autoplay
+            && self
+                .previous_best
+                .as_ref()
+                .is_none_or(|best| self.score > best.distance)
+    }
+
+    /// 进入演示模式并开始一局，种子取自 `seed_source`。
+    pub fn start_autoplay(&mut self) {
+        let seed = self.seed_source.next_u64();
+        self.start_autoplay_seeded(seed);
+    }
+
+    /// 以指定种子进入演示模式并开始一局。
+    pub fn start_autoplay_seeded(&mut self, seed: u64) {
+        self.reset_run_seeded(seed);
+        self.autoplay = true;
+        self.state = GameState::Running;
+    }
+
+    /// 退出演示模式，回到主菜单。
+    pub fn stop_autoplay(&mut self) {
+        self.autoplay = false;
+        self.state = GameState::Menu { fade: 1.0 };
+    }
+}
@@ -0,0 +1,55 @@
+//! 成就测试：单局进度在每次开局时清零，包括模拟步之外的开局；解锁提示使用界面语言。
+
+mod common;
+
+use common::{press, running_world};
+use dinorun_core::achievements::{ACHIEVEMENTS, process_events};
+use dinorun_core::events::GameEvent;
+use dinorun_core::input::InputFrame;
+use dinorun_core::locale::Language;
+use dinorun_core::utils::Rect;
+
+#[test]
+fn reset_outside_a_step_clears_run_progress() {
+    let purist = ACHIEVEMENTS.iter().find(|a| a.id == "purist").unwrap();
+    let mut world = running_world(1);
+    let dash = InputFrame {
+        dash_held: true,
+        ..InputFrame::default()
+    };
+    press(&mut world, dash);
+    assert!(world.events.contains(&GameEvent::DashStarted));
+
+    // 与回放复位、演示模式一样在模拟步之外开局，RunStarted 不会被分发。
+    world.reset_run_seeded(2);
+    // 清掉每步生成的障碍，让玩家不冲刺地一直跑下去。
+    while world.survival_time < 121.0 {
+        press(&mut world, InputFrame::default());
+        world.obstacles.clear();
+        assert!(world.in_run());
+    }
+    assert!(world.achievements.is_unlocked(purist));
+}
+
+#[test]
+fn unlock_toast_follows_the_language() {
+    for (language, toast) in [
+        (Language::English, "Achievement: Lucky Break"),
+        (Language::Spanish, "Logro: Golpe de suerte"),
+        (Language::German, "Erfolg: Glück gehabt"),
+    ] {
+        let mut world = running_world(1);
+        world.settings.language = language;
+        world.floating_texts.clear();
+        world.events.push(GameEvent::ShieldBroke {
+            rect: Rect::new(0.0, 0.0, 40.0, 40.0),
+        });
+        process_events(&mut world);
+        let texts: Vec<&str> = world
+            .floating_texts
+            .iter()
+            .map(|t| t.text.as_str())
+            .collect();
+        assert_eq!(texts, [toast]);
+    }
+}
@@ -0,0 +1,151 @@
+//! 动画测试：数据文件的解析与拒绝、按时间取帧、随玩家动作切换片段与落地形变的回弹。
+
+mod common;
+
+use common::{GROUND, grounded_player, jump, step};
+use dinorun_core::animation::{AnimationError, AnimationSet, Animator};
+use dinorun_core::constants::SIM_DT;
+use dinorun_core::input::InputFrame;
+use dinorun_core::tuning::Tuning;
+use dinorun_core::utils::Rect;
+use glam::Vec2;
+use std::path::Path;
+
+const CLIPS: &str = r#"
+[clips.run]
+loop = true
+frames = [
+    { rect = [0, 0, 32, 32], duration = 0.1 },
+    { rect = [32, 0, 32, 32], duration = 0.2 },
+]
+
+[clips.land]
+frames = [
+    { rect = [0, 32, 32, 32], duration = 0.1 },
+    { rect = [32, 32, 32, 32], duration = 0.1 },
+]
+"#;
+
+#[test]
+fn parse_rejects_empty_clips_and_bad_durations() {
+    assert!(matches!(
+        AnimationSet::parse("[clips.idle]\nframes = []\n"),
+        Err(AnimationError::EmptyClip(name)) if name == "idle"
+    ));
+    for duration in ["0.0", "-0.1", "nan", "inf"] {
+        let text =
+            format!("[clips.run]\nframes = [{{ rect = [0, 0, 32, 32], duration = {duration} }}]\n");
+        let result = AnimationSet::parse(&text);
+        assert!(
+            matches!(&result, Err(AnimationError::BadDuration(name)) if name == "run"),
+            "duration {duration}: {result:?}"
+        );
+    }
+    assert!(matches!(
+        AnimationSet::parse("[clips.run]\nloop = true\n"),
+        Err(AnimationError::Parse(_))
+    ));
+}
+
+#[test]
+fn frame_at_loops_or_holds_the_last_frame() {
+    let set = AnimationSet::parse(CLIPS).unwrap();
+
+    let run = set.clip("run").unwrap();
+    assert!((run.duration() - 0.3).abs() < 1e-6);
+    assert_eq!(run.frame_at(0.05).rect, Rect::new(0.0, 0.0, 32.0, 32.0));
+    assert_eq!(run.frame_at(0.15).rect, Rect::new(32.0, 0.0, 32.0, 32.0));
+    // 循环片段超出总时长后从头播放。
+    assert_eq!(run.frame_at(0.35).rect, Rect::new(0.0, 0.0, 32.0, 32.0));
+    assert_eq!(
+        run.frame_at(3.0 + 0.15).rect,
+        Rect::new(32.0, 0.0, 32.0, 32.0)
+    );
+
+    // 非循环片段停在最后一帧。
+    let land = set.clip("land").unwrap();
+    assert!(!land.looping);
+    assert_eq!(land.frame_at(0.05).rect, Rect::new(0.0, 32.0, 32.0, 32.0));
+    assert_eq!(land.frame_at(10.0).rect, Rect::new(32.0, 32.0, 32.0, 32.0));
+
+    // 查询不存在的片段时交给调用方退回占位绘制。
+    assert!(set.frame(&Animator::new("dash")).is_none());
+    let mut animator = Animator::new("run");
+    animator.update(0.15);
+    assert_eq!(set.frame(&animator), Some(&run.frames[1]));
+}
+
+#[test]
+fn shipped_file_has_every_player_clip() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/animations.toml");
+    let set = AnimationSet::load(&path).unwrap();
+    for name in ["run", "jump", "fall", "double_jump", "slide", "dash"] {
+        assert!(set.clip(name).is_some(), "missing clip {name}");
+    }
+}
+
+#[test]
+fn play_keeps_time_on_the_same_clip() {
+    let mut animator = Animator::new("run");
+    animator.update(0.2);
+    animator.play("run");
+    assert_eq!(animator.time, 0.2);
+    animator.play("jump");
+    assert_eq!(animator.clip, "jump");
+    assert_eq!(animator.time, 0.0);
+}
+
+#[test]
+fn clips_follow_the_player_action() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    assert_eq!(player.animator.clip, "run");
+
+    assert!(step(&mut player, &jump(), GROUND, &tuning).is_some());
+    assert_eq!(player.animator.clip, "jump");
+    // 起跳拉伸。
+    assert!(player.animator.scale().y > 1.0);
+
+    let idle = InputFrame::default();
+    let mut steps = 0;
+    while player.vel.y <= 0.0 {
+        step(&mut player, &idle, GROUND, &tuning);
+        steps += 1;
+        assert!(steps < 600, "never reached the apex");
+    }
+    assert_eq!(player.animator.clip, "fall");
+
+    while !player.on_ground(GROUND) {
+        step(&mut player, &idle, GROUND, &tuning);
+        steps += 1;
+        assert!(steps < 600, "never landed");
+    }
+    // 切回的片段从头播放，落地这一步只推进了一个步长。
+    assert_eq!(player.animator.clip, "run");
+    assert_eq!(player.animator.time, SIM_DT);
+}
+
+#[test]
+fn landing_squash_recovers() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    let idle = InputFrame::default();
+    step(&mut player, &jump(), GROUND, &tuning);
+    let mut steps = 0;
+    while !player.on_ground(GROUND) {
+        step(&mut player, &idle, GROUND, &tuning);
+        steps += 1;
+        assert!(steps < 600, "never landed");
+    }
+
+    // 落地压扁：变宽变矮。
+    let landed = player.animator.scale();
+    assert!(landed.x > 1.0 && landed.y < 1.0, "{landed:?}");
+
+    // 一秒内回弹到原形。
+    for _ in 0..120 {
+        step(&mut player, &idle, GROUND, &tuning);
+    }
+    let scale = player.animator.scale();
+    assert!(scale.abs_diff_eq(Vec2::ONE, 1e-4), "{scale:?}");
+}
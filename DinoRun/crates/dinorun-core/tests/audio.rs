@@ -0,0 +1,84 @@
+//! 音频调度测试：用记录调用的后端代替真实播放，检查音效触发与音乐切换。
+
+mod common;
+
+use common::running_world;
+use dinorun_core::audio::{AudioBackend, AudioDirector, MusicTrack, Sfx};
+use dinorun_core::constants::SIM_DT;
+use dinorun_core::input::InputFrame;
+use dinorun_core::types::PowerUpKind;
+use dinorun_core::update::step_world;
+use dinorun_core::world::World;
+
+/// 记录播放过的音效与各条音乐最后的音量。
+#[derive(Default)]
+struct Recorder {
+    played: Vec<(Sfx, f32)>,
+    music: [f32; 3],
+}
+
+impl AudioBackend for Recorder {
+    fn play(&mut self, sfx: Sfx, volume: f32) {
+        self.played.push((sfx, volume));
+    }
+
+    fn set_music_volume(&mut self, track: MusicTrack, volume: f32) {
+        self.music[track as usize] = volume;
+    }
+}
+
+/// 推进足够长的时间让交叉淡变完成。
+fn settle(director: &mut AudioDirector, world: &World, backend: &mut Recorder) {
+    for _ in 0..120 {
+        director.update(world, 1.0 / 60.0, backend);
+    }
+}
+
+#[test]
+fn jump_plays_scaled_sfx() {
+    let mut world = running_world(7);
+    let mut director = AudioDirector::default();
+    let mut backend = Recorder::default();
+    let jump = InputFrame {
+        jump_pressed: true,
+        ..InputFrame::default()
+    };
+    step_world(&mut world, &jump, SIM_DT);
+    director.on_step(&world, &mut backend);
+    assert_eq!(backend.played, vec![(Sfx::Jump, world.settings.sfx_gain())]);
+}
+
+#[test]
+fn music_follows_state_and_time_slow() {
+    let mut world = World::new(7);
+    let mut director = AudioDirector::default();
+    let mut backend = Recorder::default();
+
+    settle(&mut director, &world, &mut backend);
+    assert_eq!(backend.music, [world.settings.music_gain(), 0.0, 0.0]);
+
+    world = running_world(7);
+    settle(&mut director, &world, &mut backend);
+    assert_eq!(backend.music, [0.0, world.settings.music_gain(), 0.0]);
+
+    world.power_up.activate(PowerUpKind::TimeSlow);
+    settle(&mut director, &world, &mut backend);
+    assert_eq!(backend.music, [0.0, 0.0, world.settings.music_gain()]);
+}
+
+#[test]
+fn muted_settings_stay_silent() {
+    let mut world = running_world(7);
+    world.settings.master_volume = 0.0;
+    let mut director = AudioDirector::default();
+    let mut backend = Recorder::default();
+    let jump = InputFrame {
+        jump_pressed: true,
+        ..InputFrame::default()
+    };
+    step_world(&mut world, &jump, SIM_DT);
+    director.on_step(&world, &mut backend);
+    settle(&mut director, &world, &mut backend);
+    assert!(backend.played.is_empty());
+    assert_eq!(backend.music, [0.0; 3]);
+}
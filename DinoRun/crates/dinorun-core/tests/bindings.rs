@@ -0,0 +1,156 @@
+//! 按键绑定测试：改绑的冲突处理、按键设置页的流程与设置文件中的读写。
+
+mod common;
+
+use common::{back, confirm, press};
+use dinorun_core::bindings::{Action, Bindings, ControlsItem, Rebind};
+use dinorun_core::menu::SettingsItem;
+use dinorun_core::settings::{SETTINGS_VERSION, Settings, SettingsStore};
+use dinorun_core::types::GameState;
+use dinorun_core::world::World;
+
+#[test]
+fn defaults_share_keys_only_across_contexts() {
+    let bindings = Bindings::default();
+    assert!(bindings.conflicts().is_empty());
+    // Space 既跳跃又确认，Up 既跳跃又是菜单上移。
+    assert!(bindings.keys(Action::Jump).contains(&"Space".to_string()));
+    assert!(
+        bindings
+            .keys(Action::Confirm)
+            .contains(&"Space".to_string())
+    );
+    assert!(!Action::Jump.overlaps(Action::Confirm));
+    assert!(Action::Pause.overlaps(Action::Confirm));
+}
+
+#[test]
+fn rebinding_moves_keys_within_a_context() {
+    let keys = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+    let mut bindings = Bindings {
+        dash: keys(&["LeftShift", "RightShift"]),
+        slide: keys(&["Down", "S"]),
+        ..Bindings::default()
+    };
+    assert_eq!(
+        bindings.bind(Action::Dash, 0, "S"),
+        Rebind::Moved {
+            from: Action::Slide
+        }
+    );
+    assert_eq!(bindings.keys(Action::Dash), ["S", "RightShift"]);
+    assert_eq!(bindings.keys(Action::Slide), ["Down"]);
+
+    // Slide 只剩一个键，不能再被拿走，也不能清除。
+    assert_eq!(
+        bindings.bind(Action::Jump, 3, "Down"),
+        Rebind::Refused {
+            owner: Action::Slide
+        }
+    );
+    assert_eq!(
+        bindings.clear(Action::Slide, 0),
+        Rebind::Refused {
+            owner: Action::Slide
+        }
+    );
+
+    // 菜单动作可以使用玩法中的键。
+    assert_eq!(bindings.bind(Action::MenuUp, 3, "W"), Rebind::Bound);
+    assert_eq!(bindings.keys(Action::MenuUp)[3], "W");
+    assert!(bindings.conflicts().is_empty());
+}
+
+#[test]
+fn interface_keys_are_bindable_actions() {
+    let mut bindings = Bindings::default();
+    assert!(bindings.hit(Action::ClearKey, &["Backspace"]));
+    assert!(bindings.hit(Action::WatchReplay, &["R"]));
+    assert!(bindings.hit(Action::ReplayExit, &["Escape"]));
+    // 回放界面自成一个场景，与暂停共用 Esc、与跳跃共用 Space 不算冲突。
+    assert!(!Action::ReplayExit.overlaps(Action::Pause));
+    assert!(!Action::ReplayPause.overlaps(Action::Jump));
+    assert!(Action::ReplayRestart.overlaps(Action::ReplayExit));
+
+    // 菜单场景内改绑会从清除按键处拿走 Backspace。
+    assert_eq!(
+        bindings.bind(Action::Confirm, 0, "Backspace"),
+        Rebind::Moved {
+            from: Action::ClearKey
+        }
+    );
+    assert!(!bindings.hit(Action::ClearKey, &["Backspace"]));
+    assert!(bindings.hit(Action::Confirm, &["Backspace"]));
+
+    // 回放重播与观看录像分属不同场景，都可以用 R。
+    assert_eq!(
+        bindings.bind(Action::ReplayStep, 0, "R"),
+        Rebind::Moved {
+            from: Action::ReplayRestart
+        }
+    );
+    assert!(bindings.hit(Action::WatchReplay, &["R"]));
+    assert!(bindings.conflicts().is_empty());
+}
+
+#[test]
+fn controls_page_captures_keys() {
+    let mut world = World::new(1);
+    world.state = GameState::Settings;
+    world.settings_menu.selected = SettingsItem::ALL.len() - 1;
+    assert_eq!(
+        SettingsItem::ALL[world.settings_menu.selected],
+        SettingsItem::Controls
+    );
+    press(&mut world, confirm());
+    assert!(world.state == GameState::Controls);
+    assert_eq!(
+        world.controls_page.selected(),
+        ControlsItem::Action(Action::Jump)
+    );
+
+    press(&mut world, confirm());
+    assert!(world.controls_page.capturing);
+    // 等待按键时菜单输入被忽略，返回键也不会离开页面。
+    press(&mut world, back());
+    assert!(world.state == GameState::Controls);
+
+    world
+        .controls_page
+        .capture(&mut world.settings.controls, "K");
+    assert!(!world.controls_page.capturing);
+    assert_eq!(
+        world.settings.controls.keys(Action::Jump),
+        ["K", "Up", "W", "PadSouth"]
+    );
+
+    press(&mut world, back());
+    assert!(world.state == GameState::Settings);
+}
+
+#[test]
+fn bindings_round_trip_through_settings_file() {
+    let dir = std::env::temp_dir().join(format!("dinorun-bindings-{}", std::process::id()));
+    let store = SettingsStore::new(dir.join("settings.toml"));
+    let mut settings = Settings::default();
+    settings.controls.bind(Action::Jump, 0, "J");
+    settings.controls.bind(Action::Pause, 1, "P");
+    store.save(&settings).unwrap();
+    assert_eq!(store.load().unwrap(), Some(settings));
+
+    // 清空的动作恢复默认按键，重复的按键只保留一个。
+    std::fs::write(
+        store.path(),
+        format!(
+            "version = {SETTINGS_VERSION}\n[controls]\nconfirm = []\njump = [\"J\", \"J\", \"Up\"]\n"
+        ),
+    )
+    .unwrap();
+    let loaded = store.load().unwrap().unwrap();
+    assert_eq!(
+        loaded.controls.keys(Action::Confirm),
+        Bindings::default().keys(Action::Confirm)
+    );
+    assert_eq!(loaded.controls.keys(Action::Jump), ["J", "Up"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,71 @@
+//! 机器人测试：同一种子的对局可复现、不改变存档进度，以及分布统计的口径。
+
+use dinorun_core::bot::{Distribution, play_run};
+use dinorun_core::types::GameState;
+use dinorun_core::world::World;
+
+#[test]
+fn play_run_is_deterministic_per_seed() {
+    let first = play_run(&mut World::new(1), 42, 60.0);
+    let second = play_run(&mut World::new(99), 42, 60.0);
+    assert_eq!(first, second);
+    assert_eq!(first.seed, 42);
+    assert!(first.stats.survival_time > 0.0);
+    assert!(first.stats.survival_time <= 60.0);
+    if first.stats.killed_by.is_none() {
+        assert_eq!(first.stats.survival_time, 60.0);
+    }
+
+    // 同一个世界先玩其他种子，不影响之后的结果。
+    let mut world = World::new(1);
+    play_run(&mut world, 7, 20.0);
+    assert_eq!(play_run(&mut world, 42, 60.0), first);
+}
+
+#[test]
+fn play_run_leaves_progress_untouched() {
+    let mut world = World::new(3);
+    let run = play_run(&mut world, 5, 30.0);
+    assert!(run.stats.distance > 0.0);
+    assert!(!world.autoplay);
+    assert!(matches!(world.state, GameState::Menu { .. }));
+    assert_eq!(world.best_score, 0.0);
+    assert_eq!(world.currency, 0);
+    assert!(world.best_run.is_none());
+}
+
+#[test]
+fn distribution_summarizes_samples() {
+    assert_eq!(Distribution::from_samples(&[]), None);
+
+    let samples = [7.0, 3.0, 10.0, 1.0, 5.0, 9.0, 2.0, 8.0, 4.0, 6.0];
+    let dist = Distribution::from_samples(&samples).unwrap();
+    assert_eq!(
+        dist,
+        Distribution {
+            count: 10,
+            mean: 5.5,
+            min: 1.0,
+            p10: 1.0,
+            median: 5.0,
+            p90: 9.0,
+            max: 10.0,
+        }
+    );
+    assert_eq!(
+        dist.to_string(),
+        "mean 5.5  min 1.0  p10 1.0  median 5.0  p90 9.0  max 10.0"
+    );
+
+    let single = Distribution::from_samples(&[2.5]).unwrap();
+    assert_eq!(
+        (
+            single.min,
+            single.p10,
+            single.median,
+            single.p90,
+            single.max
+        ),
+        (2.5, 2.5, 2.5, 2.5, 2.5)
+    );
+}
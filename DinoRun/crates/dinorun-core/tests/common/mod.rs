@@ -0,0 +1,96 @@
+//! 集成测试共用的夹具：平地上的玩家、逐步推进与常用的输入帧。
+//!
+//! 每个测试文件只用到其中一部分，其余会被当作未使用。
+#![allow(dead_code)]
+
+use dinorun_core::constants::{PLAYER_SIZE, SIM_DT};
+use dinorun_core::input::{InputFrame, Takeoff, control_player};
+use dinorun_core::player::{Player, update_player};
+use dinorun_core::tuning::Tuning;
+use dinorun_core::types::GameState;
+use dinorun_core::update::step_world;
+use dinorun_core::world::World;
+
+/// 平地的高度。
+pub const GROUND: f32 = 500.0;
+
+/// 只在本帧按下跳跃。
+pub fn jump() -> InputFrame {
+    InputFrame {
+        jump_pressed: true,
+        ..InputFrame::default()
+    }
+}
+
+/// 按下跳跃并按住。
+pub fn jump_and_hold() -> InputFrame {
+    InputFrame {
+        jump_held: true,
+        ..jump()
+    }
+}
+
+/// 继续按住跳跃。
+pub fn held() -> InputFrame {
+    InputFrame {
+        jump_held: true,
+        ..InputFrame::default()
+    }
+}
+
+pub fn slide() -> InputFrame {
+    InputFrame {
+        slide_held: true,
+        ..InputFrame::default()
+    }
+}
+
+pub fn confirm() -> InputFrame {
+    InputFrame {
+        menu_confirm: true,
+        ..InputFrame::default()
+    }
+}
+
+pub fn back() -> InputFrame {
+    InputFrame {
+        pause_pressed: true,
+        ..InputFrame::default()
+    }
+}
+
+/// 站在 `GROUND` 上、没有空中跳跃的玩家。
+pub fn grounded_player(tuning: &Tuning) -> Player {
+    let mut player = Player::new();
+    player.air_jumps = 0;
+    player.air_jumps_left = 0;
+    player.pos.y = GROUND - PLAYER_SIZE.y;
+    // 先站一步，刷新土狼时间。
+    update_player(&mut player, SIM_DT, GROUND, &tuning.player);
+    player
+}
+
+/// 按 `step_world` 的顺序推进一个模拟步，返回本步的起跳。
+pub fn step(
+    player: &mut Player,
+    input: &InputFrame,
+    ground: f32,
+    tuning: &Tuning,
+) -> Option<Takeoff> {
+    let takeoff = control_player(player, input, SIM_DT, ground, tuning);
+    update_player(player, SIM_DT, ground, &tuning.player);
+    takeoff
+}
+
+/// 以 `seed` 开局、已进入奔跑状态的世界。
+pub fn running_world(seed: u64) -> World {
+    let mut world = World::new(seed);
+    world.reset_run_seeded(seed);
+    world.state = GameState::Running;
+    world
+}
+
+/// 以一帧输入推进世界一个模拟步。
+pub fn press(world: &mut World, frame: InputFrame) {
+    step_world(world, &frame, SIM_DT);
+}
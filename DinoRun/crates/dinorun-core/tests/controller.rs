@@ -0,0 +1,184 @@
+//! 玩家控制器测试：逐步驱动 `control_player` 与 `update_player`，锁定土狼时间、跳跃缓冲与可变跳跃高度的手感。
+
+mod common;
+
+use common::{GROUND, grounded_player, held, jump, jump_and_hold, step};
+use dinorun_core::constants::{PLAYER_SIZE, SIM_DT};
+use dinorun_core::input::InputFrame;
+use dinorun_core::player::Player;
+use dinorun_core::tuning::Tuning;
+
+/// 模拟地面突然下沉后，玩家悬空下落 `steps` 步。
+fn walk_off(player: &mut Player, steps: usize, tuning: &Tuning) -> f32 {
+    let lower = GROUND + 120.0;
+    for _ in 0..steps {
+        assert!(step(player, &InputFrame::default(), lower, tuning).is_none());
+    }
+    lower
+}
+
+/// 土狼时间对应的模拟步数（不含边界）。
+fn steps_within(seconds: f32) -> usize {
+    (seconds / SIM_DT) as usize - 1
+}
+
+#[test]
+fn coyote_time_allows_late_ground_jump() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    let ground = walk_off(
+        &mut player,
+        steps_within(tuning.player.coyote_time),
+        &tuning,
+    );
+    assert!(!player.on_ground(ground));
+
+    let takeoff = step(&mut player, &jump(), ground, &tuning).expect("coyote jump");
+    assert!(!takeoff.air);
+    assert!(player.vel.y < 0.0);
+
+    // 土狼时间只能用一次：空中再按不会第二次地面起跳。
+    for _ in 0..4 {
+        step(&mut player, &InputFrame::default(), ground, &tuning);
+    }
+    assert!(step(&mut player, &jump(), ground, &tuning).is_none());
+}
+
+#[test]
+fn coyote_time_expires() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    let steps = (tuning.player.coyote_time / SIM_DT) as usize + 2;
+    let ground = walk_off(&mut player, steps, &tuning);
+    assert!(step(&mut player, &jump(), ground, &tuning).is_none());
+}
+
+#[test]
+fn zero_coyote_time_restores_strict_jumps() {
+    let mut tuning = Tuning::default();
+    tuning.player.coyote_time = 0.0;
+    let mut player = grounded_player(&tuning);
+    let ground = walk_off(&mut player, 1, &tuning);
+    assert!(step(&mut player, &jump(), ground, &tuning).is_none());
+}
+
+#[test]
+fn coyote_time_does_not_replace_air_jumps() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    player.air_jumps = 1;
+    player.air_jumps_left = 1;
+    assert!(!step(&mut player, &jump(), GROUND, &tuning).unwrap().air);
+    // 刚起跳的下一步按跳跃，应当消耗空中跳跃而不是再次地面起跳。
+    let takeoff = step(&mut player, &jump(), GROUND, &tuning).expect("air jump");
+    assert!(takeoff.air);
+    assert_eq!(player.air_jumps_left, 0);
+}
+
+/// 从空中落下，在落地前 `lead` 秒按下跳跃，返回落地后多少步起跳（没有起跳为 `None`）。
+fn buffered_jump_after_landing(lead: f32, tuning: &Tuning) -> Option<usize> {
+    let mut player = grounded_player(tuning);
+    player.pos.y = GROUND - PLAYER_SIZE.y - 150.0;
+    player.coyote_timer = 0.0;
+
+    // 先预演一遍，算出落地所在的步数。
+    let mut probe = player.clone();
+    let mut landing_step = 0;
+    while !probe.on_ground(GROUND) {
+        step(&mut probe, &InputFrame::default(), GROUND, tuning);
+        landing_step += 1;
+    }
+    let press_step = landing_step - (lead / SIM_DT).round() as usize;
+
+    for idx in 0..landing_step + 30 {
+        let input = if idx == press_step {
+            jump()
+        } else {
+            InputFrame::default()
+        };
+        if let Some(takeoff) = step(&mut player, &input, GROUND, tuning) {
+            assert!(!takeoff.air);
+            return Some(idx - landing_step);
+        }
+    }
+    None
+}
+
+#[test]
+fn jump_buffer_fires_on_landing() {
+    let tuning = Tuning::default();
+    let after = buffered_jump_after_landing(tuning.player.jump_buffer * 0.5, &tuning);
+    assert_eq!(after, Some(0));
+}
+
+#[test]
+fn early_press_outside_buffer_is_dropped() {
+    let tuning = Tuning::default();
+    assert_eq!(
+        buffered_jump_after_landing(tuning.player.jump_buffer * 2.0, &tuning),
+        None
+    );
+
+    let mut strict = Tuning::default();
+    strict.player.jump_buffer = 0.0;
+    assert_eq!(buffered_jump_after_landing(0.02, &strict), None);
+}
+
+/// 起跳后按住跳跃键 `hold` 步再松开，返回相对起跳点的最高高度。
+fn apex_after_holding(player: &mut Player, hold: usize, tuning: &Tuning) -> f32 {
+    let start = player.pos.y;
+    let mut apex = start;
+    let first = if hold > 0 { jump_and_hold() } else { jump() };
+    step(player, &first, GROUND, tuning).expect("takeoff");
+    for idx in 1.. {
+        let input = if idx < hold {
+            held()
+        } else {
+            InputFrame::default()
+        };
+        step(player, &input, GROUND, tuning);
+        apex = apex.min(player.pos.y);
+        if player.vel.y >= 0.0 {
+            break;
+        }
+    }
+    start - apex
+}
+
+#[test]
+fn tap_and_hold_bound_jump_height() {
+    let tuning = Tuning::default();
+    let tap = apex_after_holding(&mut grounded_player(&tuning), 0, &tuning);
+    let full = apex_after_holding(&mut grounded_player(&tuning), 200, &tuning);
+    let min = tuning.player.min_jump_height;
+    let max = tuning.player.max_jump_height();
+    // 离散积分会带来几像素的误差。
+    assert!((tap - min).abs() < 8.0, "tap {tap} vs {min}");
+    assert!((full - max).abs() < 8.0, "full {full} vs {max}");
+
+    // 中途松开落在两者之间。
+    let mid = apex_after_holding(&mut grounded_player(&tuning), 8, &tuning);
+    assert!(tap < mid && mid < full, "{tap} < {mid} < {full}");
+}
+
+#[test]
+fn early_release_cuts_air_jump() {
+    let tuning = Tuning::default();
+    let air_jump = |hold| {
+        let mut player = grounded_player(&tuning);
+        player.air_jumps = 1;
+        player.air_jumps_left = 1;
+        // 先满高度起跳，升到最高点附近再空中跳跃。
+        step(&mut player, &jump_and_hold(), GROUND, &tuning);
+        while player.vel.y < -60.0 {
+            step(&mut player, &held(), GROUND, &tuning);
+        }
+        step(&mut player, &InputFrame::default(), GROUND, &tuning);
+        let input = if hold { jump_and_hold() } else { jump() };
+        let takeoff = step(&mut player, &input, GROUND, &tuning).expect("air jump");
+        assert!(takeoff.air);
+        player.vel.y
+    };
+    assert!(air_jump(false) >= -tuning.player.jump_cut_velocity());
+    assert!(air_jump(true) < -tuning.player.jump_cut_velocity());
+}
@@ -0,0 +1,168 @@
+//! 事件测试：逐步检查护盾碎裂、擦身而过与死亡事件各只发出一次，并且落在正确的模拟步。
+
+mod common;
+
+use common::{press, running_world};
+use dinorun_core::constants::PLAYER_SIZE;
+use dinorun_core::events::GameEvent;
+use dinorun_core::input::InputFrame;
+use dinorun_core::obstacles::Obstacle;
+use dinorun_core::types::{GameState, ObstacleKind, PowerUpKind};
+use dinorun_core::world::World;
+
+/// 只有手动放置的障碍的奔跑世界，玩家站在地面上。
+fn quiet_world() -> World {
+    let mut world = running_world(11);
+    world.obstacles.clear();
+    world.power_up.deactivate();
+    for _ in 0..30 {
+        step(&mut world);
+    }
+    world
+}
+
+/// 推进一步，并推迟常规刷新，让场上只剩测试放置的障碍。
+fn step(world: &mut World) -> Vec<GameEvent> {
+    world.difficulty.rng_obstacle_timer = f32::MAX;
+    world.power_ups.clear();
+    press(world, InputFrame::default());
+    world.events.clone()
+}
+
+/// 在玩家前方 `distance` 处放置贴地木箱。
+fn crate_ahead(world: &World, distance: f32) -> Obstacle {
+    let player = world.player.rect();
+    Obstacle::crate_box(
+        player.x + player.w + distance,
+        60.0,
+        70.0,
+        player.y + player.h,
+    )
+}
+
+/// 逐步推进直到 `done`，返回每一步的事件。
+fn run_until(world: &mut World, done: impl Fn(&World) -> bool) -> Vec<Vec<GameEvent>> {
+    let mut steps = Vec::new();
+    while !done(world) {
+        assert!(steps.len() < 2000, "scenario never finished");
+        steps.push(step(world));
+    }
+    // 多推进几步，确认事件不会重复发出。
+    for _ in 0..60 {
+        steps.push(step(world));
+    }
+    steps
+}
+
+/// 满足 `is_event` 的事件所在的唯一一步。
+fn only_step(steps: &[Vec<GameEvent>], is_event: impl Fn(&GameEvent) -> bool) -> usize {
+    let hits: Vec<usize> = steps
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, events)| events.iter().filter(|e| is_event(e)).map(move |_| idx))
+        .collect();
+    assert_eq!(hits.len(), 1, "event emitted at steps {hits:?}");
+    hits[0]
+}
+
+#[test]
+fn shield_breaks_once_on_the_contact_step() {
+    let mut world = quiet_world();
+    world.power_up.activate(PowerUpKind::Shield);
+    world.power_up.timer = 60.0;
+    world.obstacles.push(crate_ahead(&world, 120.0));
+
+    let mut steps = Vec::new();
+    while !world.obstacles.is_empty() {
+        assert!(steps.len() < 2000, "crate never reached the player");
+        let player = world.player.rect();
+        assert!(!world.obstacles[0].hurt_box.overlaps(&player));
+        steps.push(step(&mut world));
+    }
+    // 木箱在撞上玩家的那一步被护盾击碎并移除，事件中的外形与玩家重叠。
+    let broke_at = steps.len() - 1;
+    let player = world.player.rect();
+    assert!(steps[broke_at].iter().any(|e| matches!(
+        e,
+        GameEvent::ShieldBroke { rect } if rect.overlaps(&player)
+    )));
+    for _ in 0..60 {
+        steps.push(step(&mut world));
+    }
+
+    let broke = only_step(&steps, |e| matches!(e, GameEvent::ShieldBroke { .. }));
+    assert_eq!(broke, broke_at);
+    assert_eq!(
+        only_step(&steps, |e| *e
+            == GameEvent::PowerUpEnded(PowerUpKind::Shield)),
+        broke
+    );
+    assert!(world.state == GameState::Running);
+    assert!(
+        !steps
+            .iter()
+            .flatten()
+            .any(|e| matches!(e, GameEvent::Died { .. }))
+    );
+}
+
+#[test]
+fn near_miss_fires_once_when_the_obstacle_is_cleared() {
+    let mut world = quiet_world();
+    // 圆锯的伤害判定框底部在玩家头顶上方 6 像素处掠过。
+    let player = world.player.rect();
+    let mut saw = Obstacle::saw(
+        player.x + player.w + 160.0,
+        78.0,
+        0.0,
+        player.y + player.h,
+        0.0,
+    );
+    let dy = player.y - 6.0 - (saw.hurt_box.y + saw.hurt_box.h);
+    saw.rect.y += dy;
+    saw.hurt_box.y += dy;
+    saw.prev_rect = saw.rect;
+    world.obstacles.push(saw);
+
+    let steps = run_until(&mut world, |w| {
+        w.obstacles.first().is_none_or(|o| o.cleared)
+    });
+    let near = only_step(&steps, |e| *e == GameEvent::NearMiss(ObstacleKind::Saw));
+    // 擦身而过与越过障碍在同一步发出，也就是圆锯刚被标记为越过的那一步。
+    assert_eq!(
+        only_step(&steps, |e| *e
+            == GameEvent::ObstacleCleared(ObstacleKind::Saw)),
+        near
+    );
+    assert_eq!(near, steps.len() - 61);
+    assert!(world.state == GameState::Running);
+}
+
+#[test]
+fn death_fires_once_on_the_contact_step() {
+    let mut world = quiet_world();
+    world.obstacles.push(crate_ahead(&world, 120.0));
+
+    let mut steps = Vec::new();
+    while world.state == GameState::Running {
+        assert!(steps.len() < 2000, "crate never reached the player");
+        let player = world.player.rect();
+        assert!(!world.obstacles[0].hurt_box.overlaps(&player));
+        steps.push(step(&mut world));
+    }
+    // 撞上的那一步结束时木箱与玩家重叠，死亡事件记下此刻的玩家中心。
+    assert!(world.obstacles[0].hurt_box.overlaps(&world.player.rect()));
+    let died_at = steps.len() - 1;
+    let center = world.player.pos + PLAYER_SIZE * 0.5;
+    for _ in 0..60 {
+        steps.push(step(&mut world));
+    }
+
+    let died = only_step(&steps, |e| matches!(e, GameEvent::Died { .. }));
+    assert_eq!(died, died_at);
+    assert!(steps[died].contains(&GameEvent::Died {
+        killed_by: ObstacleKind::Crate,
+        at: center,
+    }));
+    assert!(matches!(world.state, GameState::GameOver { .. }));
+}
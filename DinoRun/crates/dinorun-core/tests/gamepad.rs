@@ -0,0 +1,93 @@
+//! 手柄输入测试：用虚拟手柄检查按钮映射、摇杆死区、热插拔与改绑。
+
+mod common;
+
+use common::running_world;
+use dinorun_core::bindings::Action;
+use dinorun_core::gamepad::{GamepadInput, PadButton, STICK_DEAD_ZONE, VirtualGamepad};
+use dinorun_core::input::{InputFrame, InputSource};
+use dinorun_core::vec2;
+use dinorun_core::world::World;
+
+fn connected_pad() -> GamepadInput<VirtualGamepad> {
+    let mut input = GamepadInput::new(VirtualGamepad::default());
+    input.backend_mut().connect(0, "Virtual Pad");
+    input
+}
+
+#[test]
+fn buttons_map_through_bindings() {
+    let world = running_world(3);
+    let mut input = connected_pad();
+    assert_eq!(input.poll(&world), InputFrame::default());
+
+    input.backend_mut().press(0, PadButton::South);
+    input.backend_mut().press(0, PadButton::RightTrigger);
+    let frame = input.poll(&world);
+    assert!(frame.jump_pressed && frame.menu_confirm && frame.dash_held);
+
+    // 按住不放只在第一帧算作按下，冲刺持续生效。
+    let frame = input.poll(&world);
+    assert!(!frame.jump_pressed && frame.dash_held);
+
+    input.backend_mut().press(0, PadButton::Start);
+    assert!(input.poll(&world).pause_pressed);
+}
+
+#[test]
+fn stick_respects_dead_zone() {
+    let world = running_world(3);
+    let mut input = connected_pad();
+
+    // 静止漂移不触发滑铲。
+    input
+        .backend_mut()
+        .set_stick(0, vec2(0.1, STICK_DEAD_ZONE * 0.8));
+    assert!(!input.poll(&world).slide_held);
+
+    input.backend_mut().set_stick(0, vec2(0.1, 0.9));
+    let frame = input.poll(&world);
+    assert!(frame.slide_held && frame.menu_down);
+    let frame = input.poll(&world);
+    assert!(frame.slide_held && !frame.menu_down);
+
+    input.backend_mut().set_stick(0, vec2(0.0, -0.9));
+    let frame = input.poll(&world);
+    assert!(!frame.slide_held && frame.menu_up);
+}
+
+#[test]
+fn hot_plugging_pauses_running_game() {
+    let world = running_world(3);
+    let mut input = GamepadInput::new(VirtualGamepad::default());
+    assert_eq!(input.poll(&world), InputFrame::default());
+    assert_eq!(input.connected(), 0);
+
+    input.backend_mut().connect(4, "Late Pad");
+    input.backend_mut().press(4, PadButton::DPadDown);
+    assert!(input.poll(&world).slide_held);
+    assert_eq!(input.connected(), 1);
+
+    input.backend_mut().disconnect(4);
+    let frame = input.poll(&world);
+    assert!(frame.pause_pressed && !frame.slide_held);
+    assert_eq!(input.connected(), 0);
+
+    // 菜单中断开不会产生输入。
+    let menu = World::new(3);
+    input.backend_mut().connect(5, "Menu Pad");
+    input.poll(&menu);
+    input.backend_mut().disconnect(5);
+    assert_eq!(input.poll(&menu), InputFrame::default());
+}
+
+#[test]
+fn rebound_buttons_follow_settings() {
+    let mut world = running_world(3);
+    let mut input = connected_pad();
+    world.settings.controls.bind(Action::Jump, 0, "PadNorth");
+
+    input.backend_mut().press(0, PadButton::North);
+    assert!(input.poll(&world).jump_pressed);
+    assert_eq!(input.last_pressed(), Some("PadNorth"));
+}
@@ -0,0 +1,115 @@
+//! 障碍图案测试：图案文件的解析、各项校验与按时间抽取。
+
+use dinorun_core::patterns::{PatternError, PatternLibrary};
+use dinorun_core::rng::Rng;
+
+/// 在一个合法的开局图案之后追加 `chunk`，只让被测图案出错。
+fn parse_with(chunk: &str) -> Result<PatternLibrary, PatternError> {
+    PatternLibrary::parse(&format!(
+        "[[chunk]]\nname = \"start\"\nobstacles = [{{ kind = \"crate\", x = 0.0 }}]\n\n{chunk}"
+    ))
+}
+
+/// 断言图案 `name` 因 `reason` 中的原因被拒绝。
+fn assert_invalid(result: Result<PatternLibrary, PatternError>, name: &str, reason: &str) {
+    match result {
+        Err(PatternError::Invalid { chunk, reason: got }) => {
+            assert_eq!(chunk, name);
+            assert!(got.contains(reason), "unexpected reason: {got}");
+        }
+        other => panic!("expected chunk `{name}` to be invalid, got {other:?}"),
+    }
+}
+
+#[test]
+fn builtin_library_round_trips() {
+    let library = PatternLibrary::default();
+    assert!(library.chunks.iter().any(|c| c.min_time == 0.0));
+    assert_eq!(PatternLibrary::parse(&library.to_toml()).unwrap(), library);
+}
+
+#[test]
+fn overlapping_obstacles_are_rejected() {
+    let result = parse_with(
+        "[[chunk]]\nname = \"pile\"\nobstacles = [\n    { kind = \"crate\", x = 0.0, size = 68.0 },\n    { kind = \"saw\", x = 40.0 },\n]\n",
+    );
+    assert_invalid(result, "pile", "obstacles 0 and 1 overlap");
+
+    // 同样的两个障碍拉开距离后即可通过。
+    parse_with(
+        "[[chunk]]\nname = \"pile\"\nobstacles = [\n    { kind = \"crate\", x = 0.0, size = 68.0 },\n    { kind = \"saw\", x = 300.0 },\n]\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn non_finite_values_are_rejected() {
+    for dims in ["x = inf", "x = 0.0, size = nan", "x = 0.0, height = -inf"] {
+        let result = parse_with(&format!(
+            "[[chunk]]\nname = \"odd\"\nobstacles = [{{ kind = \"crate\", {dims} }}]\n"
+        ));
+        assert_invalid(result, "odd", "negative or non-finite dimension");
+    }
+    assert_invalid(
+        parse_with(
+            "[[chunk]]\nname = \"odd\"\nmin_time = nan\nobstacles = [{ kind = \"saw\", x = 0.0 }]\n",
+        ),
+        "odd",
+        "min_time",
+    );
+    assert_invalid(
+        parse_with(
+            "[[chunk]]\nname = \"odd\"\nweight = inf\nobstacles = [{ kind = \"saw\", x = 0.0 }]\n",
+        ),
+        "odd",
+        "weight",
+    );
+}
+
+#[test]
+fn zero_weight_and_empty_chunks_are_rejected() {
+    assert_invalid(
+        parse_with(
+            "[[chunk]]\nname = \"never\"\nweight = 0.0\nobstacles = [{ kind = \"saw\", x = 0.0 }]\n",
+        ),
+        "never",
+        "weight must be greater than 0",
+    );
+    assert_invalid(
+        parse_with("[[chunk]]\nname = \"empty\"\nobstacles = []\n"),
+        "empty",
+        "has no obstacles",
+    );
+}
+
+#[test]
+fn library_needs_a_starting_chunk() {
+    let result = PatternLibrary::parse(
+        "[[chunk]]\nname = \"late\"\nmin_time = 5.0\nobstacles = [{ kind = \"saw\", x = 0.0 }]\n",
+    );
+    assert!(matches!(result, Err(PatternError::NoStartingChunk)));
+}
+
+#[test]
+fn unknown_fields_are_parse_errors() {
+    let result = parse_with(
+        "[[chunk]]\nname = \"typo\"\nwieght = 2.0\nobstacles = [{ kind = \"saw\", x = 0.0 }]\n",
+    );
+    assert!(matches!(result, Err(PatternError::Parse(_))));
+}
+
+#[test]
+fn choose_only_returns_unlocked_chunks() {
+    let library = parse_with(
+        "[[chunk]]\nname = \"late\"\nmin_time = 30.0\nweight = 100.0\nobstacles = [{ kind = \"saw\", x = 0.0 }]\n",
+    )
+    .unwrap();
+    let mut rng = Rng::new(4);
+    for _ in 0..200 {
+        assert_eq!(library.choose(0.0, &mut rng).name, "start");
+    }
+    let late = (0..200)
+        .filter(|_| library.choose(30.0, &mut rng).name == "late")
+        .count();
+    assert!(late > 150, "late chunk chosen {late} times");
+}
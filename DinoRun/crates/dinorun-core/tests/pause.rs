@@ -0,0 +1,66 @@
+//! 暂停菜单测试：继续本局与中途退出，退出的一局照常计入最佳成绩。
+
+mod common;
+
+use common::{back, confirm, press, running_world};
+use dinorun_core::input::InputFrame;
+use dinorun_core::types::GameState;
+use dinorun_core::world::World;
+
+/// 没有障碍、已经跑了几秒的对局。
+fn run_for_a_while() -> World {
+    let mut world = running_world(11);
+    world.difficulty.rng_obstacle_timer = f32::INFINITY;
+    for _ in 0..360 {
+        press(&mut world, InputFrame::default());
+    }
+    assert!(world.score > 0.0);
+    world
+}
+
+fn down() -> InputFrame {
+    InputFrame {
+        menu_down: true,
+        ..InputFrame::default()
+    }
+}
+
+#[test]
+fn resume_continues_the_run() {
+    let mut world = run_for_a_while();
+    press(&mut world, back());
+    assert!(world.state == GameState::Paused);
+    press(&mut world, confirm());
+    assert!(world.state == GameState::Running);
+
+    // 暂停键同样继续，再次暂停时光标回到“继续”。
+    press(&mut world, back());
+    press(&mut world, down());
+    press(&mut world, back());
+    assert!(world.state == GameState::Running);
+    press(&mut world, back());
+    assert_eq!(world.pause_menu.selected, 0);
+}
+
+#[test]
+fn quitting_to_menu_records_the_run() {
+    let mut world = run_for_a_while();
+    let score = world.score;
+    press(&mut world, back());
+    press(&mut world, down());
+    press(&mut world, confirm());
+    assert!(matches!(world.state, GameState::Menu { .. }));
+    assert_eq!(world.best_score, score);
+    assert_eq!(world.best_run.as_ref().map(|run| run.distance), Some(score));
+    assert_eq!(world.stats.distance, score);
+
+    // 更短的一局不会覆盖最佳成绩。
+    world.reset_run_seeded(12);
+    world.state = GameState::Running;
+    world.difficulty.rng_obstacle_timer = f32::INFINITY;
+    press(&mut world, InputFrame::default());
+    press(&mut world, back());
+    press(&mut world, down());
+    press(&mut world, confirm());
+    assert_eq!(world.best_score, score);
+}
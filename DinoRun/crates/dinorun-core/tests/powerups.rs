@@ -0,0 +1,113 @@
+//! 强化道具测试：逐步对比同一种子的两局，ScoreBoost 只在生效期间放大距离得分、拾取得分与货币。
+
+mod common;
+
+use common::{press, running_world};
+use dinorun_core::collectibles::Collectible;
+use dinorun_core::constants::SIM_DT;
+use dinorun_core::input::InputFrame;
+use dinorun_core::types::{CollectibleKind, PowerUpKind};
+use dinorun_core::world::World;
+
+/// 场上没有障碍、收集物与道具的奔跑世界；`boost` 时 ScoreBoost 还剩 `boost` 秒。
+fn quiet_world(boost: Option<f32>) -> World {
+    let mut world = running_world(5);
+    world.obstacles.clear();
+    world.collectibles.clear();
+    world.power_ups.clear();
+    world.power_up.deactivate();
+    if let Some(seconds) = boost {
+        world.power_up.activate(PowerUpKind::ScoreBoost);
+        world.power_up.timer = seconds;
+    }
+    world
+}
+
+/// 推进一步并推迟所有刷新，返回本步增加的分数与货币。
+fn step(world: &mut World) -> (f32, u32) {
+    world.difficulty.rng_obstacle_timer = f32::MAX;
+    world.difficulty.rng_collectible_timer = f32::MAX;
+    world.difficulty.rng_powerup_timer = f32::MAX;
+    let (score, currency) = (world.score, world.currency);
+    press(world, InputFrame::default());
+    (world.score - score, world.currency - currency)
+}
+
+/// 分数是累计值之差，两局总分不同会带来舍入误差。
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 1e-3, "{a} != {b}");
+}
+
+/// 在玩家身上放一颗宝石，下一步即被拾取。
+fn gem_on_player(world: &mut World) {
+    let rect = world.player.rect();
+    world.collectibles.push(Collectible {
+        kind: CollectibleKind::Gem,
+        rect,
+        prev_rect: rect,
+        value: world.tuning.scoring.gem_value,
+        float_phase: 0.0,
+    });
+}
+
+#[test]
+fn score_boost_multiplies_distance_score() {
+    let mut plain = quiet_world(None);
+    let mut boosted = quiet_world(Some(60.0));
+    let mult = boosted.tuning.scoring.score_boost_mult;
+    assert!(mult > 1.0);
+
+    for _ in 0..120 {
+        let (base, _) = step(&mut plain);
+        let (gained, _) = step(&mut boosted);
+        assert!(base > 0.0);
+        assert_close(gained, base * mult);
+    }
+    assert_close(boosted.boost_score, plain.score * (mult - 1.0));
+}
+
+#[test]
+fn score_boost_multiplies_pickup_score_and_currency() {
+    let mut plain = quiet_world(None);
+    let mut boosted = quiet_world(Some(60.0));
+    let mult = boosted.tuning.scoring.score_boost_mult;
+    gem_on_player(&mut plain);
+    gem_on_player(&mut boosted);
+
+    let (base, coins) = step(&mut plain);
+    let (gained, boosted_coins) = step(&mut boosted);
+    assert!(plain.collectibles.is_empty() && boosted.collectibles.is_empty());
+    assert_eq!(coins, plain.tuning.scoring.gem_value / 4);
+    assert_close(gained, base * mult);
+    assert_eq!(boosted_coins, (coins as f32 * mult) as u32);
+    assert_eq!(boosted.boost_currency, boosted_coins - coins);
+    assert_eq!(plain.boost_currency, 0);
+}
+
+#[test]
+fn score_boost_stops_when_it_expires() {
+    let mut plain = quiet_world(None);
+    let mut boosted = quiet_world(Some(SIM_DT * 10.5));
+
+    let mut steps = 0;
+    while boosted.score_boost_active() {
+        step(&mut plain);
+        step(&mut boosted);
+        steps += 1;
+    }
+    assert_eq!(steps, 11);
+    let boost_score = boosted.boost_score;
+
+    // 失效后距离得分与拾取都按原值计算。
+    for _ in 0..30 {
+        assert_close(step(&mut boosted).0, step(&mut plain).0);
+    }
+    gem_on_player(&mut plain);
+    gem_on_player(&mut boosted);
+    let (gained, boosted_coins) = step(&mut boosted);
+    let (base, coins) = step(&mut plain);
+    assert_close(gained, base);
+    assert_eq!(boosted_coins, coins);
+    assert_eq!(boosted.boost_score, boost_score);
+    assert_eq!(boosted.boost_currency, 0);
+}
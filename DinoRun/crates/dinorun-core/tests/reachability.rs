@@ -0,0 +1,63 @@
+//! 可达性模糊测试：自动驾驶按求解器给出的输入游玩，任何种子都不应死亡。
+
+use dinorun_core::constants::SIM_DT;
+use dinorun_core::solver::Scenario;
+use dinorun_core::types::GameState;
+use dinorun_core::update::step_world;
+use dinorun_core::world::World;
+use std::collections::VecDeque;
+
+/// 每个种子模拟的对局时长（秒）。
+const RUN_SECONDS: f32 = 90.0;
+
+/// 以求解器作为自动驾驶游玩一局，死亡时返回当时的对局时间。
+fn autopilot(seed: u64) -> Result<(), f32> {
+    let mut world = World::new(seed);
+    world.reset_run_seeded(seed);
+    world.state = GameState::Running;
+    let mut plan = VecDeque::new();
+    let mut replan = true;
+    while world.difficulty.time < RUN_SECONDS {
+        // 搜索并不完备，重新规划失败时沿用原计划。
+        if (replan || plan.is_empty())
+            && let Some(frames) = Scenario::from_world(&world).solve()
+        {
+            plan = VecDeque::from(frames);
+        }
+        let timers = (
+            world.difficulty.rng_obstacle_timer,
+            world.difficulty.rng_powerup_timer,
+        );
+        let power_up = (world.power_up.active, world.power_up.kind);
+        let input = plan.pop_front().unwrap_or_default();
+        step_world(&mut world, &input, SIM_DT);
+        // 求解结论只对求解时的局面成立：生成计时器被重置（新图案）或强化状态变化后重新规划，
+        // 障碍离场则不影响已有计划。
+        replan = world.difficulty.rng_obstacle_timer > timers.0
+            || world.difficulty.rng_powerup_timer > timers.1
+            || power_up != (world.power_up.active, world.power_up.kind);
+        if matches!(world.state, GameState::GameOver { .. }) {
+            return Err(world.difficulty.time);
+        }
+    }
+    Ok(())
+}
+
+fn fuzz(seeds: std::ops::Range<u64>) {
+    let deaths: Vec<(u64, f32)> = seeds
+        .filter_map(|seed| autopilot(seed).err().map(|time| (seed, time)))
+        .collect();
+    assert!(deaths.is_empty(), "autopilot died (seed, time): {deaths:?}");
+}
+
+#[test]
+fn every_spawned_sequence_is_survivable() {
+    fuzz(0..8);
+}
+
+/// 大规模模糊测试，耗时较长：`cargo test --release -- --ignored`。
+#[test]
+#[ignore]
+fn every_spawned_sequence_is_survivable_many_seeds() {
+    fuzz(0..2000);
+}
@@ -0,0 +1,146 @@
+//! 录像测试：编码与解码互逆、不兼容的文件明确报错，以及录下的对局能原样回放。
+
+use dinorun_core::bot::Bot;
+use dinorun_core::constants::SIM_DT;
+use dinorun_core::input::{InputFrame, InputSource};
+use dinorun_core::patterns::PatternLibrary;
+use dinorun_core::replay::{REPLAY_VERSION, Replay, ReplayError, ReplayRecorder};
+use dinorun_core::shop::{UpgradeKind, Upgrades};
+use dinorun_core::tuning::Tuning;
+use dinorun_core::types::GameState;
+use dinorun_core::update::step_world;
+use dinorun_core::world::World;
+
+/// 录像记录的 8 个输入位，与编码中的位序一致。
+fn frame_from_byte(bits: u8) -> InputFrame {
+    InputFrame {
+        jump_pressed: bits & 1 != 0,
+        dash_held: bits & (1 << 1) != 0,
+        slide_held: bits & (1 << 2) != 0,
+        pause_pressed: bits & (1 << 3) != 0,
+        menu_up: bits & (1 << 4) != 0,
+        menu_down: bits & (1 << 5) != 0,
+        menu_confirm: bits & (1 << 6) != 0,
+        jump_held: bits & (1 << 7) != 0,
+        ..InputFrame::default()
+    }
+}
+
+fn sample_replay(frames: Vec<InputFrame>) -> Replay {
+    let mut upgrades = Upgrades::default();
+    for (level, kind) in UpgradeKind::ALL.into_iter().enumerate() {
+        upgrades.set_level(kind, level as u32);
+    }
+    Replay {
+        seed: 0x0123_4567_89ab_cdef,
+        upgrades,
+        tuning: Tuning::default(),
+        patterns: PatternLibrary::default(),
+        frames,
+    }
+}
+
+#[test]
+fn encoding_round_trips_every_input_bit() {
+    // 每种输入位组合各出现一次，再接一段超过 u16 上限、需要拆成多块的相同输入。
+    let mut frames: Vec<InputFrame> = (0..=u8::MAX).map(frame_from_byte).collect();
+    frames.extend(std::iter::repeat_n(frame_from_byte(0b1000_0001), 70_000));
+    frames.push(InputFrame::default());
+    let replay = sample_replay(frames);
+    assert_eq!(Replay::decode(&replay.encode()).unwrap(), replay);
+
+    // 每一位单独置位，确认不会与其他字段混淆。
+    let single: Vec<InputFrame> = (0..8).map(|bit| frame_from_byte(1 << bit)).collect();
+    let decoded = Replay::decode(&sample_replay(single.clone()).encode()).unwrap();
+    assert_eq!(decoded.frames, single);
+}
+
+#[test]
+fn other_versions_are_rejected() {
+    let bytes = sample_replay(vec![InputFrame::default()]).encode();
+    for version in [REPLAY_VERSION - 1, REPLAY_VERSION + 1] {
+        let mut other = bytes.clone();
+        other[4..6].copy_from_slice(&version.to_le_bytes());
+        match Replay::decode(&other) {
+            Err(ReplayError::UnsupportedVersion { found, expected }) => {
+                assert_eq!(found, version);
+                assert_eq!(expected, REPLAY_VERSION);
+            }
+            other => panic!("version {version} was not rejected: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn damaged_files_are_rejected() {
+    let bytes = sample_replay(vec![InputFrame::default(); 3]).encode();
+    let mut magic = bytes.clone();
+    magic[0] = b'X';
+    assert!(matches!(Replay::decode(&magic), Err(ReplayError::BadMagic)));
+
+    let mut rate = bytes.clone();
+    rate[6..8].copy_from_slice(&1u16.to_le_bytes());
+    assert!(matches!(
+        Replay::decode(&rate),
+        Err(ReplayError::StepRateMismatch { found: 1, .. })
+    ));
+
+    assert!(matches!(
+        Replay::decode(&bytes[..bytes.len() - 1]),
+        Err(ReplayError::Truncated)
+    ));
+    assert!(matches!(
+        Replay::decode(&bytes[..20]),
+        Err(ReplayError::Truncated)
+    ));
+}
+
+#[test]
+fn oversized_frame_count_is_rejected() {
+    // 把头部声明的总步数改成 u32::MAX，后面只剩一个游程块。
+    let mut bytes = sample_replay(vec![InputFrame::default()]).encode();
+    let count_at = bytes.len() - 3 - 4;
+    assert_eq!(bytes[count_at..count_at + 4], 1u32.to_le_bytes());
+    bytes[count_at..count_at + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(matches!(
+        Replay::decode(&bytes),
+        Err(ReplayError::Truncated)
+    ));
+
+    // 只有头部、没有任何游程块时同样报错。
+    bytes.truncate(count_at + 4);
+    assert!(matches!(
+        Replay::decode(&bytes),
+        Err(ReplayError::Truncated)
+    ));
+}
+
+#[test]
+fn recorded_run_plays_back_identically() {
+    // 与游戏主循环一样，从主菜单开局，机器人代替玩家操作。
+    let mut world = World::new(5);
+    world.state = GameState::Menu { fade: 1.0 };
+    let mut recorder = ReplayRecorder::default();
+    let mut bot = Bot;
+    let mut replay = None;
+    while replay.is_none() {
+        assert!(world.survival_time < 600.0, "bot never died");
+        let was_in_run = world.in_run();
+        let input = if was_in_run {
+            bot.poll(&world)
+        } else {
+            InputFrame {
+                menu_confirm: true,
+                ..InputFrame::default()
+            }
+        };
+        step_world(&mut world, &input, SIM_DT);
+        replay = recorder.observe(was_in_run, &input, &world);
+    }
+    let replay = Replay::decode(&replay.unwrap().encode()).unwrap();
+    assert_eq!(replay.seed, world.run_seed);
+
+    let mut playback = World::new(99);
+    replay.play_to_end(&mut playback);
+    assert_eq!(playback.run_stats(), world.run_stats());
+}
@@ -0,0 +1,125 @@
+//! 存档测试：解析与版本检查、旧存档的升级、读写往返与坏档备份。
+
+use dinorun_core::save::{SAVE_VERSION, SaveData, SaveError, SaveStore};
+use dinorun_core::stats::RunStats;
+use dinorun_core::world::World;
+use std::path::PathBuf;
+
+/// 每个测试独占的临时目录。
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dinorun-save-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn parse_checks_the_version() {
+    assert!(matches!(
+        SaveData::parse("best_score = 10.0\n"),
+        Err(SaveError::MissingVersion)
+    ));
+    assert!(matches!(
+        SaveData::parse("version = \"1\"\n"),
+        Err(SaveError::MissingVersion)
+    ));
+    for version in [0, -1, i64::from(u32::MAX) + 1] {
+        let result = SaveData::parse(&format!("version = {version}\n"));
+        assert!(
+            matches!(result, Err(SaveError::InvalidVersion(v)) if v == version),
+            "version {version}: {result:?}"
+        );
+    }
+    let newer = SAVE_VERSION + 1;
+    assert!(matches!(
+        SaveData::parse(&format!("version = {newer}\n")),
+        Err(SaveError::NewerVersion(v)) if v == newer
+    ));
+    assert!(matches!(
+        SaveData::parse("version = 1\ncurrency = \"lots\"\n"),
+        Err(SaveError::Parse(_))
+    ));
+}
+
+#[test]
+fn old_saves_upgrade_to_the_current_version() {
+    // 第一版存档只有最佳距离与货币，其余字段取默认值。
+    let data = SaveData::parse("version = 1\nbest_score = 420.5\ncurrency = 37\n").unwrap();
+    assert_eq!(
+        data,
+        SaveData {
+            best_score: 420.5,
+            currency: 37,
+            ..SaveData::default()
+        }
+    );
+    assert_eq!(data.version, SAVE_VERSION);
+
+    // 没有整局统计时，由最佳距离补出结算界面对比用的一局。
+    let mut world = World::new(1);
+    data.apply(&mut world);
+    assert_eq!(world.best_score, 420.5);
+    assert_eq!(world.currency, 37);
+    assert_eq!(world.best_run, Some(RunStats::from_distance(420.5)));
+}
+
+#[test]
+fn progress_round_trips_through_the_store() {
+    let dir = temp_dir("round-trip");
+    let store = SaveStore::new(dir.join("save.toml"));
+    // 文件不存在时是空存档，没有提示。
+    let empty = store.load();
+    assert_eq!(empty.data, SaveData::default());
+    assert!(empty.warning.is_none());
+
+    let mut world = World::new(1);
+    world.best_score = 1234.0;
+    world.currency = 99;
+    world.upgrades.extra_jump = 2;
+    world.best_run = Some(RunStats {
+        jumps: 40,
+        ..RunStats::from_distance(1234.0)
+    });
+    world.achievements.unlocked.insert("pit_hopper".to_string());
+    let data = SaveData::capture(&world);
+    store.save(&data).unwrap();
+
+    let loaded = store.load();
+    assert!(loaded.warning.is_none());
+    assert_eq!(loaded.data, data);
+    let mut restored = World::new(2);
+    loaded.data.apply(&mut restored);
+    assert_eq!(SaveData::capture(&restored), data);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unreadable_saves_are_backed_up() {
+    let dir = temp_dir("corrupt");
+    let store = SaveStore::new(dir.join("save.toml"));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(store.path(), "version = 99\n").unwrap();
+
+    let outcome = store.load();
+    assert_eq!(outcome.data, SaveData::default());
+    let warning = outcome.warning.unwrap();
+    assert!(warning.contains("backed up"), "{warning}");
+    // 原文件被改名保留，内容不变，不会被之后的写入覆盖。
+    assert!(!store.path().exists());
+    let backups: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(backups.len(), 1);
+    assert!(
+        backups[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("save.toml.bak-")
+    );
+    assert_eq!(
+        std::fs::read_to_string(&backups[0]).unwrap(),
+        "version = 99\n"
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
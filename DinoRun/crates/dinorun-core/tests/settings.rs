@@ -0,0 +1,113 @@
+//! 设置页与设置文件测试：菜单操作改动设置，写出的文件能原样读回。
+
+mod common;
+
+use common::{back, confirm, press};
+use dinorun_core::bindings::Bindings;
+use dinorun_core::input::InputFrame;
+use dinorun_core::locale::Language;
+use dinorun_core::menu::SettingsItem;
+use dinorun_core::settings::{ColorblindMode, SETTINGS_VERSION, Settings, SettingsStore};
+use dinorun_core::types::GameState;
+use dinorun_core::world::World;
+
+/// 打开设置页后，把光标移到 `item` 上。
+fn settings_page(item: SettingsItem) -> World {
+    let mut world = World::new(1);
+    world.state = GameState::Settings;
+    world.settings_menu.selected = SettingsItem::ALL
+        .iter()
+        .position(|candidate| *candidate == item)
+        .unwrap();
+    world
+}
+
+#[test]
+fn sliders_step_and_clamp() {
+    let mut world = settings_page(SettingsItem::MusicVolume);
+    let left = InputFrame {
+        menu_left: true,
+        ..InputFrame::default()
+    };
+    for _ in 0..3 {
+        press(&mut world, left);
+    }
+    assert_eq!(world.settings.music_volume, 0.3);
+    for _ in 0..10 {
+        press(&mut world, left);
+    }
+    assert_eq!(world.settings.music_volume, 0.0);
+}
+
+#[test]
+fn choices_cycle_both_ways() {
+    let mut world = settings_page(SettingsItem::Language);
+    let left = InputFrame {
+        menu_left: true,
+        ..InputFrame::default()
+    };
+    press(&mut world, left);
+    assert_eq!(world.settings.language, Language::German);
+    press(&mut world, confirm());
+    assert_eq!(world.settings.language, Language::English);
+}
+
+#[test]
+fn escape_returns_to_menu() {
+    let mut world = settings_page(SettingsItem::ShowFps);
+    press(&mut world, back());
+    assert!(matches!(world.state, GameState::Menu { .. }));
+}
+
+#[test]
+fn settings_file_round_trips() {
+    let dir = std::env::temp_dir().join(format!("dinorun-settings-{}", std::process::id()));
+    let store = SettingsStore::new(dir.join("settings.toml"));
+    assert!(store.load().unwrap().is_none());
+
+    let settings = Settings {
+        sfx_volume: 0.3,
+        fullscreen: true,
+        language: Language::Spanish,
+        colorblind: ColorblindMode::Tritanopia,
+        ..Settings::default()
+    };
+    store.save(&settings).unwrap();
+    assert_eq!(store.load().unwrap(), Some(settings));
+
+    // 越界的数值在读取时被修正，缺失的字段取默认值。
+    std::fs::write(store.path(), "master_volume = 4.0\n").unwrap();
+    let loaded = store.load().unwrap().unwrap();
+    assert_eq!(loaded.master_volume, 1.0);
+    assert_eq!(loaded.music_volume, Settings::default().music_volume);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn old_controls_gain_default_pad_bindings() {
+    // 加入手柄支持之前的设置文件没有版本号，`[controls]` 里只有键盘按键。
+    let text = "\
+master_volume = 0.5
+
+[controls]
+jump = [\"Space\", \"Up\", \"W\"]
+dash = [\"J\", \"K\", \"L\", \"Semicolon\"]
+slide = [\"Down\", \"S\"]
+";
+    let settings = Settings::parse(text).unwrap();
+    assert_eq!(settings.version, SETTINGS_VERSION);
+    assert_eq!(settings.master_volume, 0.5);
+    let controls = &settings.controls;
+    assert_eq!(controls.jump, ["Space", "Up", "W", "PadSouth"]);
+    assert_eq!(controls.slide, ["Down", "S", "PadDown", "StickDown"]);
+    // 已满四个键的动作保留玩家的绑定，不挤进手柄按键。
+    assert_eq!(controls.dash, ["J", "K", "L", "Semicolon"]);
+    assert_eq!(controls.pause, Bindings::default().pause);
+    assert!(controls.conflicts().is_empty());
+
+    // 当前版本的文件不再迁移，玩家移除的手柄按键不会被补回。
+    let mut current = Settings::default();
+    current.controls.jump = vec!["Space".into()];
+    let text = toml::to_string_pretty(&current).unwrap();
+    assert_eq!(Settings::parse(&text).unwrap(), current);
+}
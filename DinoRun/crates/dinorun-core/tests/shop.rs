@@ -0,0 +1,95 @@
+//! 商店测试：逐级递增的价格、货币不足与满级时拒绝购买、升级在开局时作用到玩家。
+
+use dinorun_core::shop::{UpgradeKind, Upgrades};
+use dinorun_core::tuning::Tuning;
+use dinorun_core::types::PowerUpKind;
+use dinorun_core::world::World;
+
+#[test]
+fn costs_escalate_per_level() {
+    for kind in UpgradeKind::ALL {
+        let mut upgrades = Upgrades::default();
+        let mut previous = 0;
+        for level in 0..kind.max_level() {
+            upgrades.set_level(kind, level);
+            let cost = upgrades.next_cost(kind).unwrap();
+            assert!(
+                cost > previous,
+                "{kind:?} level {level}: {cost} <= {previous}"
+            );
+            previous = cost;
+        }
+    }
+
+    // 首级 150，之后每级乘 1.8。
+    let mut upgrades = Upgrades::default();
+    assert_eq!(upgrades.next_cost(UpgradeKind::MaxStamina), Some(150));
+    upgrades.set_level(UpgradeKind::MaxStamina, 1);
+    assert_eq!(upgrades.next_cost(UpgradeKind::MaxStamina), Some(270));
+}
+
+#[test]
+fn purchase_needs_enough_currency() {
+    let mut upgrades = Upgrades::default();
+    let cost = upgrades.next_cost(UpgradeKind::ExtraJump).unwrap();
+
+    let mut currency = cost - 1;
+    assert!(!upgrades.purchase(UpgradeKind::ExtraJump, &mut currency));
+    assert_eq!(currency, cost - 1);
+    assert_eq!(upgrades.level(UpgradeKind::ExtraJump), 0);
+
+    let mut currency = cost + 5;
+    assert!(upgrades.purchase(UpgradeKind::ExtraJump, &mut currency));
+    assert_eq!(currency, 5);
+    assert_eq!(upgrades.level(UpgradeKind::ExtraJump), 1);
+}
+
+#[test]
+fn levels_stop_at_the_cap() {
+    for kind in UpgradeKind::ALL {
+        let mut upgrades = Upgrades::default();
+        let mut currency = u32::MAX;
+        while upgrades.purchase(kind, &mut currency) {}
+        assert_eq!(upgrades.level(kind), kind.max_level(), "{kind:?}");
+        assert_eq!(upgrades.next_cost(kind), None);
+
+        // 满级后不再扣款；外部数据给出的过高等级也会被截断。
+        let before = currency;
+        assert!(!upgrades.purchase(kind, &mut currency));
+        assert_eq!(currency, before);
+        upgrades.set_level(kind, kind.max_level() + 3);
+        assert_eq!(upgrades.level(kind), kind.max_level());
+    }
+}
+
+#[test]
+fn upgrades_reach_the_new_player() {
+    let tuning = Tuning::default();
+    let mut world = World::new(1);
+    world.reset_run_seeded(1);
+    assert_eq!(world.player.max_stamina, tuning.stamina.max);
+    assert_eq!(world.player.air_jumps, 1);
+    assert_eq!(world.power_up.duration, tuning.powerups.duration);
+    assert!(!world.power_up.active);
+
+    world.upgrades = Upgrades {
+        max_stamina: 2,
+        stamina_recovery: 1,
+        powerup_duration: 2,
+        extra_jump: 1,
+        starting_shield: 1,
+    };
+    world.reset_run_seeded(1);
+    assert_eq!(world.player.max_stamina, tuning.stamina.max + 40.0);
+    assert_eq!(world.player.stamina, world.player.max_stamina);
+    assert_eq!(
+        world.player.stamina_recover_rate,
+        tuning.stamina.recover_rate * 1.25
+    );
+    assert_eq!(world.player.air_jumps, 2);
+    assert_eq!(world.player.air_jumps_left, 2);
+    assert_eq!(world.power_up.duration, tuning.powerups.duration + 3.0);
+    assert!(world.power_up.active);
+    assert_eq!(world.power_up.kind, PowerUpKind::Shield);
+    assert_eq!(world.power_up.timer, 4.0);
+}
@@ -0,0 +1,156 @@
+//! 滑铲测试：最短时长、起跳取消、体力消耗，以及激光与起滑无敌窗口的碰撞判定。
+
+mod common;
+
+use common::{GROUND, grounded_player, held, jump_and_hold, running_world, slide, step};
+use dinorun_core::constants::{PLAYER_SIZE, SIM_DT};
+use dinorun_core::input::{InputFrame, control_player};
+use dinorun_core::obstacles::Obstacle;
+use dinorun_core::tuning::Tuning;
+use dinorun_core::types::{GameState, ObstacleKind, PlayerAction};
+use dinorun_core::update::step_world;
+use dinorun_core::world::World;
+
+/// 没有新障碍与道具刷新、只有给定障碍的对局。
+fn world_with(obstacle: impl FnOnce(f32, f32) -> Obstacle) -> World {
+    let mut world = running_world(5);
+    world.difficulty.rng_obstacle_timer = f32::INFINITY;
+    world.difficulty.rng_powerup_timer = f32::INFINITY;
+    let ground = world.difficulty.ground_y(&world.tuning);
+    world.obstacles = vec![obstacle(world.player.pos.x, ground)];
+    world
+}
+
+/// 以同一输入推进 `seconds` 秒，返回撞上的障碍种类。
+fn run(world: &mut World, input: InputFrame, seconds: f32) -> Option<ObstacleKind> {
+    for _ in 0..(seconds / SIM_DT) as usize {
+        step_world(world, &input, SIM_DT);
+        if matches!(world.state, GameState::GameOver { .. }) {
+            return world.stats.killed_by;
+        }
+    }
+    None
+}
+
+#[test]
+fn tapped_slide_lasts_minimum_duration() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    let stamina = player.stamina;
+    step(&mut player, &slide(), GROUND, &tuning);
+    assert!(player.sliding && player.action == PlayerAction::Slide);
+    assert_eq!(stamina - player.stamina, tuning.slide.stamina_cost);
+    assert!(player.rect().h < PLAYER_SIZE.y);
+    assert!(player.vel.x > 0.0);
+
+    // 松开后仍滑满最短时长，冲量随之衰减。
+    let steps = (tuning.slide.min_duration / SIM_DT) as usize;
+    for _ in 1..steps {
+        step(&mut player, &InputFrame::default(), GROUND, &tuning);
+        assert!(player.sliding);
+    }
+    step(&mut player, &InputFrame::default(), GROUND, &tuning);
+    step(&mut player, &InputFrame::default(), GROUND, &tuning);
+    assert!(!player.sliding && player.action == PlayerAction::Running);
+    assert_eq!(player.vel.x, 0.0);
+    assert_eq!(player.rect().h, PLAYER_SIZE.y);
+}
+
+#[test]
+fn slide_needs_enough_stamina() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    player.stamina = 0.0;
+    step(&mut player, &slide(), GROUND, &tuning);
+    assert!(!player.sliding && !player.is_invulnerable());
+    assert!(player.action != PlayerAction::Slide);
+    assert_eq!(player.rect().h, PLAYER_SIZE.y);
+
+    // 没有体力就没有起滑无敌窗口，压在身上的木箱立即致命。
+    let mut world = world_with(|x, ground| Obstacle::crate_box(x, 68.0, 72.0, ground));
+    world.player.stamina = 0.0;
+    assert_eq!(run(&mut world, slide(), SIM_DT), Some(ObstacleKind::Crate));
+}
+
+#[test]
+fn jump_cancels_slide() {
+    let tuning = Tuning::default();
+    let mut player = grounded_player(&tuning);
+    step(&mut player, &slide(), GROUND, &tuning);
+    step(&mut player, &slide(), GROUND, &tuning);
+    assert!(player.is_invulnerable());
+
+    let takeoff = control_player(&mut player, &jump_and_hold(), SIM_DT, GROUND, &tuning);
+    assert!(takeoff.is_some_and(|t| !t.air));
+    assert!(!player.sliding && !player.is_invulnerable());
+    assert!(player.action == PlayerAction::Jump);
+    assert_eq!(player.rect().h, PLAYER_SIZE.y);
+}
+
+#[test]
+fn laser_must_be_slid_under() {
+    let laser = |x: f32, ground: f32| Obstacle::laser(x + 200.0, 24.0, 56.0, ground);
+
+    let mut world = world_with(laser);
+    assert_eq!(
+        run(&mut world, InputFrame::default(), 2.0),
+        Some(ObstacleKind::Laser)
+    );
+
+    // 跳跃同样撞上延伸到画面之外的光束。
+    let mut world = world_with(laser);
+    step_world(&mut world, &jump_and_hold(), SIM_DT);
+    assert_eq!(
+        run(&mut world, InputFrame::default(), 2.0),
+        Some(ObstacleKind::Laser)
+    );
+
+    let mut world = world_with(laser);
+    assert_eq!(run(&mut world, slide(), 2.0), None);
+    assert!(world.obstacles.iter().all(|o| o.cleared));
+}
+
+#[test]
+fn low_drone_must_be_slid_under() {
+    let drone = |x: f32, ground: f32| Obstacle::drone(x + 200.0, 44.0, ground);
+
+    let mut world = world_with(drone);
+    assert_eq!(
+        run(&mut world, InputFrame::default(), 2.0),
+        Some(ObstacleKind::Drone)
+    );
+
+    // 无论何时起跳，满高度的跳跃都会撞上缆绳。
+    for delay in 0..60 {
+        let mut world = world_with(drone);
+        run(&mut world, InputFrame::default(), delay as f32 * SIM_DT);
+        step_world(&mut world, &jump_and_hold(), SIM_DT);
+        assert_eq!(run(&mut world, held(), 2.0), Some(ObstacleKind::Drone));
+    }
+
+    let mut world = world_with(drone);
+    assert_eq!(run(&mut world, slide(), 2.0), None);
+    assert!(world.obstacles.iter().all(|o| o.cleared));
+
+    // 高过玩家的无人机不挂缆绳，站着就能从下方通过。
+    let mut world = world_with(|x, ground| Obstacle::drone(x + 200.0, 110.0, ground));
+    assert!(!world.obstacles[0].is_tethered());
+    assert_eq!(run(&mut world, InputFrame::default(), 2.0), None);
+}
+
+#[test]
+fn slide_start_is_briefly_invulnerable() {
+    // 木箱正压在玩家身上：站着立即撞上，起滑后只在无敌窗口内安然无恙。
+    let crate_box = |x: f32, ground: f32| Obstacle::crate_box(x, 68.0, 72.0, ground);
+
+    let mut world = world_with(crate_box);
+    assert_eq!(
+        run(&mut world, InputFrame::default(), SIM_DT),
+        Some(ObstacleKind::Crate)
+    );
+
+    let mut world = world_with(crate_box);
+    let window = world.tuning.slide.invulnerability;
+    assert_eq!(run(&mut world, slide(), window - SIM_DT), None);
+    assert_eq!(run(&mut world, slide(), 1.0), Some(ObstacleKind::Crate));
+}
@@ -0,0 +1,100 @@
+//! 求解器测试：起始局面本身的碰撞、单步求解预算的上限，以及预算耗尽时推迟生成。
+
+mod common;
+
+use dinorun_core::constants::SIM_DT;
+use dinorun_core::input::InputFrame;
+use dinorun_core::obstacles::Obstacle;
+use dinorun_core::patterns::PatternLibrary;
+use dinorun_core::powerups::PowerUpPickup;
+use dinorun_core::rng::Rng;
+use dinorun_core::solver::Scenario;
+use dinorun_core::types::{GameState, PowerUpKind};
+use dinorun_core::update::{SOLVER_BUDGET, step_world};
+use dinorun_core::utils::Rect;
+use dinorun_core::world::World;
+
+/// 不再刷新强化道具的对局。
+fn running_world() -> World {
+    let mut world = common::running_world(9);
+    world.difficulty.rng_powerup_timer = f32::INFINITY;
+    world
+}
+
+/// 远处一堵跳不过去的高墙：搜索要把每条分支推进到墙前才能确认无解。
+fn wall_scenario() -> Scenario {
+    let world = running_world();
+    let ground = world.difficulty.ground_y(&world.tuning);
+    let mut scenario = Scenario::from_world(&world);
+    scenario.obstacles = vec![Obstacle::crate_box(
+        world.player.pos.x + 1400.0,
+        68.0,
+        400.0,
+        ground,
+    )];
+    scenario
+}
+
+#[test]
+fn pickup_overlapping_at_the_start_invalidates_the_search() {
+    // 生成检查在本步碰撞处理之前进行：玩家此刻压着的 TimeSlow 会在这一步被拾取，流速随之改变。
+    let world = running_world();
+    let ground = world.difficulty.ground_y(&world.tuning);
+    let mut scenario = Scenario::from_world(&world);
+    scenario.obstacles = vec![Obstacle::crate_box(
+        world.player.pos.x + 400.0,
+        68.0,
+        76.0,
+        ground,
+    )];
+    assert!(scenario.is_survivable());
+
+    let mut pickup = PowerUpPickup::new(PowerUpKind::TimeSlow, ground, &mut Rng::new(1));
+    // 只压住玩家左缘，滚动一步后就已离开，之后的搜索看不到它。
+    let player = world.player.rect();
+    pickup.rect = Rect::new(player.x - 34.0, player.y, 36.0, 36.0);
+    scenario.power_ups = vec![pickup];
+    assert!(!scenario.is_survivable());
+}
+
+#[test]
+fn exhausted_budget_stops_the_search() {
+    let scenario = wall_scenario();
+    let mut unlimited = usize::MAX;
+    assert!(scenario.solve_within(&mut unlimited).is_none());
+    assert!(usize::MAX - unlimited > SOLVER_BUDGET);
+
+    let mut budget = SOLVER_BUDGET;
+    assert!(!scenario.is_survivable_within(&mut budget));
+    assert_eq!(budget, 0);
+
+    // 预算充足时与不限预算的结论一致，并扣除实际用量。
+    let mut world = running_world();
+    let ground = world.difficulty.ground_y(&world.tuning);
+    world.obstacles = vec![Obstacle::crate_box(
+        world.player.pos.x + 400.0,
+        68.0,
+        76.0,
+        ground,
+    )];
+    let scenario = Scenario::from_world(&world);
+    let mut budget = SOLVER_BUDGET;
+    let frames = scenario.solve_within(&mut budget).unwrap();
+    assert!(budget > 0);
+    assert_eq!(Some(frames), scenario.solve());
+}
+
+#[test]
+fn spawn_is_deferred_when_budget_runs_out() {
+    // 唯一的图案无解，每次生成检查都会耗尽本步预算。
+    let mut world = running_world();
+    world.patterns = PatternLibrary::parse(
+        "[[chunk]]\nname = \"wall\"\nobstacles = [{ kind = \"crate\", x = 0.0, height = 400.0 }]\n",
+    )
+    .unwrap();
+    world.difficulty.rng_obstacle_timer = 0.0;
+    step_world(&mut world, &InputFrame::default(), SIM_DT);
+    assert!(world.obstacles.is_empty());
+    assert!(world.difficulty.rng_obstacle_timer > 0.0);
+    assert!(world.state == GameState::Running);
+}
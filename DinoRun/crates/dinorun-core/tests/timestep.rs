@@ -0,0 +1,49 @@
+//! 固定步长测试：过长的帧被截断到 `MAX_FRAME_TIME`、不足一步的时间留到下一帧、插值比例不越界。
+
+use dinorun_core::constants::{MAX_FRAME_TIME, SIM_DT};
+use dinorun_core::timestep::FixedTimestep;
+
+#[test]
+fn long_frames_are_clamped() {
+    // 8 Hz 的步长 0.125 s 可以精确表示，便于逐步核对。
+    let mut clock = FixedTimestep::new(8.0);
+    assert_eq!(clock.advance(10.0), (MAX_FRAME_TIME / 0.125) as u32);
+    assert_eq!(clock.alpha(), 0.0);
+    assert_eq!(clock.advance(-1.0), 0);
+    assert_eq!(clock.alpha(), 0.0);
+
+    // 默认频率下一帧卡顿最多追赶 MAX_FRAME_TIME 对应的步数。
+    let mut clock = FixedTimestep::default();
+    assert_eq!(clock.step_dt(), SIM_DT);
+    let steps = clock.advance(5.0);
+    assert!(steps.abs_diff((MAX_FRAME_TIME / SIM_DT).round() as u32) <= 1);
+}
+
+#[test]
+fn leftover_time_carries_to_the_next_frame() {
+    let mut clock = FixedTimestep::new(8.0);
+    assert_eq!(clock.advance(0.0625), 0);
+    assert_eq!(clock.alpha(), 0.5);
+    assert_eq!(clock.advance(0.125), 1);
+    assert_eq!(clock.alpha(), 0.5);
+    assert_eq!(clock.advance(0.0625), 1);
+    assert_eq!(clock.alpha(), 0.0);
+}
+
+#[test]
+fn alpha_stays_within_one_step() {
+    let mut clock = FixedTimestep::default();
+    let frames: [f32; 8] = [0.0, 0.001, 1.0 / 144.0, 1.0 / 60.0, 0.033, 0.1, 0.3, 2.0];
+    let mut total = 0.0;
+    let mut steps = 0;
+    for idx in 0..1000 {
+        let frame = frames[idx * 7 % frames.len()];
+        total += frame.min(MAX_FRAME_TIME);
+        steps += clock.advance(frame);
+        let alpha = clock.alpha();
+        assert!((0.0..=1.0).contains(&alpha), "frame {idx}: alpha {alpha}");
+    }
+    // 执行的步数加上剩余比例与累计的真实时间一致。
+    let simulated = (steps as f32 + clock.alpha()) * SIM_DT;
+    assert!((simulated - total).abs() < 1e-2, "{simulated} vs {total}");
+}
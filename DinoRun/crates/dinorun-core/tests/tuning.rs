@@ -0,0 +1,133 @@
+//! 调参测试：随仓库发布的调参文件、编码往返与各项取值校验。
+
+use dinorun_core::tuning::{Tuning, TuningError};
+use std::path::Path;
+
+/// 断言 `text` 因 `field` 的取值被拒绝。
+fn assert_invalid(text: &str, field: &str) {
+    match Tuning::parse(text) {
+        Err(TuningError::Invalid { field: got, .. }) => assert_eq!(got, field, "{text}"),
+        other => panic!("expected {field} to be rejected for {text:?}, got {other:?}"),
+    }
+}
+
+#[test]
+fn shipped_file_matches_the_defaults() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/tuning.toml");
+    assert_eq!(Tuning::load(&path).unwrap(), Tuning::default());
+    let tuning = Tuning::default();
+    assert_eq!(Tuning::parse(&tuning.to_toml()).unwrap(), tuning);
+}
+
+#[test]
+fn missing_fields_use_defaults_and_typos_are_errors() {
+    let tuning = Tuning::parse("[player]\ngravity = 2500.0\n").unwrap();
+    assert_eq!(tuning.player.gravity, 2500.0);
+    assert_eq!(tuning.slide, Tuning::default().slide);
+    assert!(matches!(
+        Tuning::parse("[player]\ngravty = 2500.0\n"),
+        Err(TuningError::Parse(_))
+    ));
+    assert!(matches!(
+        Tuning::parse("[playr]\ngravity = 2500.0\n"),
+        Err(TuningError::Parse(_))
+    ));
+}
+
+#[test]
+fn out_of_range_values_are_rejected() {
+    let cases = [
+        ("[player]\ngravity = 0.0\n", "player.gravity"),
+        ("[player]\njump_velocity = -820.0\n", "player.jump_velocity"),
+        ("[player]\ndash_speed = nan\n", "player.dash_speed"),
+        // 最低跳跃高度不能超过按住跳跃时的最高高度。
+        (
+            "[player]\nmin_jump_height = 500.0\n",
+            "player.min_jump_height",
+        ),
+        ("[player]\ncoyote_time = -0.1\n", "player.coyote_time"),
+        ("[stamina]\nmax = inf\n", "stamina.max"),
+        ("[slide]\nspeed = 2000.0\n", "slide.speed"),
+        ("[slide]\ninvulnerability = 1.0\n", "slide.invulnerability"),
+        ("[scroll]\nmax_speed = 100.0\n", "scroll.max_speed"),
+        (
+            "[difficulty]\npowerup_max_interval = 1.0\n",
+            "difficulty.powerup_max_interval",
+        ),
+        ("[scoring]\nscroll_score = -0.05\n", "scoring.scroll_score"),
+        ("[scoring]\nspeed_score = inf\n", "scoring.speed_score"),
+        ("[scoring]\ngem_chance = 1.5\n", "scoring.gem_chance"),
+        ("[scoring]\nstreak_max = 0.5\n", "scoring.streak_max"),
+        (
+            "[powerups]\ntime_slow_scale = 0.0\n",
+            "powerups.time_slow_scale",
+        ),
+    ];
+    for (text, field) in cases {
+        assert_invalid(text, field);
+    }
+}
+
+#[test]
+fn spawn_jitter_is_validated() {
+    let cases = [
+        (
+            "[difficulty]\nobstacle_jitter_min = -inf\n",
+            "difficulty.obstacle_jitter_min",
+        ),
+        // 抖动范围不能倒置。
+        (
+            "[difficulty]\nobstacle_jitter_max = -0.5\n",
+            "difficulty.obstacle_jitter_max",
+        ),
+        (
+            "[difficulty]\nobstacle_min_interval = 0.0\n",
+            "difficulty.obstacle_min_interval",
+        ),
+        (
+            "[difficulty]\npowerup_jitter_min = nan\n",
+            "difficulty.powerup_jitter_min",
+        ),
+        (
+            "[difficulty]\npowerup_jitter_max = -2.0\n",
+            "difficulty.powerup_jitter_max",
+        ),
+        (
+            "[difficulty]\npowerup_min_interval = 0.0\n",
+            "difficulty.powerup_min_interval",
+        ),
+        (
+            "[difficulty]\ncollectible_interval = -1.0\n",
+            "difficulty.collectible_interval",
+        ),
+        (
+            "[difficulty]\ncollectible_wave = nan\n",
+            "difficulty.collectible_wave",
+        ),
+        (
+            "[difficulty]\ncollectible_jitter_min = 1.0\n",
+            "difficulty.collectible_jitter_max",
+        ),
+        (
+            "[difficulty]\ncollectible_min_interval = 0.0\n",
+            "difficulty.collectible_min_interval",
+        ),
+    ];
+    for (text, field) in cases {
+        assert_invalid(text, field);
+    }
+    // 抖动可以关掉。
+    let fixed =
+        Tuning::parse("[difficulty]\nobstacle_jitter_min = 0.0\nobstacle_jitter_max = 0.0\n")
+            .unwrap();
+    assert_eq!(fixed.difficulty.obstacle_jitter_max, 0.0);
+}
+
+#[test]
+fn invalid_error_names_the_field() {
+    let err = Tuning::parse("[player]\ngravity = -1.0\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "player.gravity must be greater than 0 (got -1)"
+    );
+}
@@ -0,0 +1,206 @@
+//! 无窗口的平衡模拟：让内置机器人以最快速度游玩 N 个种子，逐局输出 CSV 或 JSON，
+//! 并汇总存活时间、得分与死因，供调整难度曲线时参考。
+//!
+//! 参数解析、数据加载与输出格式都在这里，`main` 只负责串起来与报告错误。
+
+use dinorun_core::bot::{BotRun, Distribution, play_run};
+use dinorun_core::patterns::{PATTERNS_PATH, PatternLibrary};
+use dinorun_core::tuning::{TUNING_PATH, Tuning};
+use dinorun_core::types::ObstacleKind;
+use dinorun_core::world::World;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+/// 用法说明，参数有误时随错误一起打印。
+pub const USAGE: &str = "\
+usage: dinorun-sim [options]
+
+  --runs <n>          number of runs (default 100)
+  --seed <n>          first seed; runs use seed..seed+n (default 0)
+  --max-time <secs>   stop a run that survives this long (default 300)
+  --format <csv|json> output format (default csv)
+  --out <file>        write results to a file instead of stdout
+  --tuning <file>     tuning file (default assets/tuning.toml)
+  --patterns <file>   pattern library (default assets/patterns.toml)";
+
+/// 逐局结果的输出格式。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+/// 命令行选项。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {
+    pub runs: u64,
+    pub seed: u64,
+    pub max_time: f32,
+    pub format: Format,
+    pub out: Option<PathBuf>,
+    pub tuning: PathBuf,
+    pub patterns: PathBuf,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            runs: 100,
+            seed: 0,
+            max_time: 300.0,
+            format: Format::Csv,
+            out: None,
+            tuning: PathBuf::from(TUNING_PATH),
+            patterns: PathBuf::from(PATTERNS_PATH),
+        }
+    }
+}
+
+impl Options {
+    /// 解析命令行参数；出错时返回可直接打印的说明。
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{flag} needs a value"));
+            match flag.as_str() {
+                "--runs" => options.runs = parse_number(&flag, &value()?)?,
+                "--seed" => options.seed = parse_number(&flag, &value()?)?,
+                "--max-time" => options.max_time = parse_number(&flag, &value()?)?,
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "csv" => Format::Csv,
+                        "json" => Format::Json,
+                        other => return Err(format!("unknown format `{other}`")),
+                    }
+                }
+                "--out" => options.out = Some(PathBuf::from(value()?)),
+                "--tuning" => options.tuning = PathBuf::from(value()?),
+                "--patterns" => options.patterns = PathBuf::from(value()?),
+                other => return Err(format!("unknown option `{other}`")),
+            }
+        }
+        if !options.max_time.is_finite() || options.max_time <= 0.0 {
+            return Err(format!(
+                "--max-time must be a positive number of seconds (got {})",
+                options.max_time
+            ));
+        }
+        if options.seed.checked_add(options.runs).is_none() {
+            return Err(format!(
+                "--seed {} with --runs {} runs past the last seed",
+                options.seed, options.runs
+            ));
+        }
+        Ok(options)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String>
+where
+    T::Err: std::fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|err| format!("{flag} {value}: {err}"))
+}
+
+/// 按选项读取调参与图案，建立模拟用的世界。
+/// 任一文件无效时返回带路径的错误说明：用错误的数据跑出来的统计没有意义。
+pub fn load_world(options: &Options) -> Result<World, String> {
+    let mut world = World::new(0);
+    let tuning = Tuning::load(&options.tuning)
+        .map_err(|err| format!("{}: {err}", options.tuning.display()))?;
+    world.set_tuning(tuning);
+    world.patterns = PatternLibrary::load(&options.patterns)
+        .map_err(|err| format!("{}: {err}", options.patterns.display()))?;
+    Ok(world)
+}
+
+/// 依次游玩 `seed..seed + runs` 的每个种子。
+pub fn play_runs(world: &mut World, options: &Options) -> Vec<BotRun> {
+    (options.seed..options.seed + options.runs)
+        .map(|seed| play_run(world, seed, options.max_time))
+        .collect()
+}
+
+/// 按格式写出逐局结果。
+pub fn write_runs(out: &mut impl Write, runs: &[BotRun], format: Format) -> io::Result<()> {
+    match format {
+        Format::Csv => {
+            writeln!(
+                out,
+                "seed,survival_time,killed_by,distance,coins,gems,max_combo,max_streak,\
+                 dash_time,jumps,air_jumps,power_ups,near_misses"
+            )?;
+            for run in runs {
+                let stats = &run.stats;
+                writeln!(
+                    out,
+                    "{},{:.3},{},{:.1},{},{},{},{:.2},{:.3},{},{},{},{}",
+                    run.seed,
+                    stats.survival_time,
+                    stats.killed_by.map(kind_name).unwrap_or(""),
+                    stats.distance,
+                    stats.coins,
+                    stats.gems,
+                    stats.max_combo,
+                    stats.max_streak,
+                    stats.dash_time,
+                    stats.jumps,
+                    stats.air_jumps,
+                    stats.power_ups,
+                    stats.near_misses
+                )?;
+            }
+            Ok(())
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, runs)?;
+            writeln!(out)
+        }
+    }
+}
+
+/// 存活时间、得分与死因的汇总文本，由 `main` 打印到标准错误，不干扰重定向到文件的逐局结果。
+pub fn summary(runs: &[BotRun], max_time: f32) -> String {
+    let times: Vec<f32> = runs.iter().map(|run| run.stats.survival_time).collect();
+    let scores: Vec<f32> = runs.iter().map(|run| run.stats.distance).collect();
+    let (Some(times), Some(scores)) = (
+        Distribution::from_samples(&times),
+        Distribution::from_samples(&scores),
+    ) else {
+        return "no runs\n".to_string();
+    };
+    let mut text = String::new();
+    // 写入 String 不会失败。
+    let _ = writeln!(text, "{} runs, capped at {max_time}s", times.count);
+    let _ = writeln!(text, "survival (s): {times}");
+    let _ = writeln!(text, "score (m):    {scores}");
+    let survived = runs
+        .iter()
+        .filter(|run| run.stats.killed_by.is_none())
+        .count();
+    text.push_str("deaths:      ");
+    for kind in ObstacleKind::ALL {
+        let count = runs
+            .iter()
+            .filter(|run| run.stats.killed_by == Some(kind))
+            .count();
+        let _ = write!(text, " {} {count}", kind_name(kind));
+    }
+    let _ = writeln!(text, "  survived {survived}");
+    text
+}
+
+/// 与图案文件和 JSON 输出一致的小写名称。
+pub fn kind_name(kind: ObstacleKind) -> &'static str {
+    match kind {
+        ObstacleKind::Crate => "crate",
+        ObstacleKind::Saw => "saw",
+        ObstacleKind::Pit => "pit",
+        ObstacleKind::Drone => "drone",
+        ObstacleKind::Laser => "laser",
+    }
+}
@@ -0,0 +1,39 @@
+//! `dinorun-sim` 命令行入口，选项说明见 [`dinorun_sim::USAGE`]。
+
+use dinorun_sim::{Options, USAGE, load_world, play_runs, summary, write_runs};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut world = match load_world(&options) {
+        Ok(world) => world,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let runs = play_runs(&mut world, &options);
+    let written = match &options.out {
+        Some(path) => File::create(path).and_then(|file| {
+            let mut out = BufWriter::new(file);
+            write_runs(&mut out, &runs, options.format)?;
+            out.flush()
+        }),
+        None => write_runs(&mut io::stdout().lock(), &runs, options.format),
+    };
+    if let Err(err) = written {
+        eprintln!("failed to write results: {err}");
+        return ExitCode::FAILURE;
+    }
+    eprint!("{}", summary(&runs, options.max_time));
+    ExitCode::SUCCESS
+}
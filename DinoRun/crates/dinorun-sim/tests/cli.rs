@@ -0,0 +1,220 @@
+//! 命令行测试：参数解析、调参与图案文件的校验，以及 CSV / JSON 输出格式。
+
+use dinorun_core::bot::BotRun;
+use dinorun_core::stats::RunStats;
+use dinorun_core::types::ObstacleKind;
+use dinorun_sim::{Format, Options, load_world, play_runs, summary, write_runs};
+use std::path::{Path, PathBuf};
+
+fn parse(args: &[&str]) -> Result<Options, String> {
+    Options::parse(args.iter().map(|arg| arg.to_string()))
+}
+
+/// 仓库自带的数据文件；测试的工作目录是本 crate 的目录。
+fn asset(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../assets")
+        .join(name)
+}
+
+/// 指向仓库数据文件的默认选项。
+fn asset_options() -> Options {
+    Options {
+        tuning: asset("tuning.toml"),
+        patterns: asset("patterns.toml"),
+        ..Options::default()
+    }
+}
+
+/// 写入临时文件并返回路径，文件名带上进程号避免并行测试互相覆盖。
+fn temp_file(name: &str, text: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("dinorun-sim-{}-{name}", std::process::id()));
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+fn sample_runs() -> Vec<BotRun> {
+    vec![
+        BotRun {
+            seed: 3,
+            stats: RunStats {
+                killed_by: Some(ObstacleKind::Saw),
+                distance: 812.25,
+                survival_time: 41.5,
+                coins: 12,
+                gems: 1,
+                max_combo: 4,
+                max_streak: 1.75,
+                dash_time: 2.0,
+                jumps: 30,
+                air_jumps: 5,
+                power_ups: 2,
+                near_misses: 3,
+                ..RunStats::default()
+            },
+        },
+        BotRun {
+            seed: 4,
+            stats: RunStats {
+                distance: 5000.0,
+                survival_time: 120.0,
+                ..RunStats::default()
+            },
+        },
+    ]
+}
+
+#[test]
+fn parses_every_option() {
+    assert_eq!(parse(&[]).unwrap(), Options::default());
+    let options = parse(&[
+        "--runs",
+        "5",
+        "--seed",
+        "10",
+        "--max-time",
+        "60",
+        "--format",
+        "json",
+        "--out",
+        "out.json",
+        "--tuning",
+        "t.toml",
+        "--patterns",
+        "p.toml",
+    ])
+    .unwrap();
+    assert_eq!(
+        options,
+        Options {
+            runs: 5,
+            seed: 10,
+            max_time: 60.0,
+            format: Format::Json,
+            out: Some(PathBuf::from("out.json")),
+            tuning: PathBuf::from("t.toml"),
+            patterns: PathBuf::from("p.toml"),
+        }
+    );
+}
+
+#[test]
+fn rejects_bad_arguments() {
+    let cases: [(&[&str], &str); 7] = [
+        (&["--runs"], "--runs needs a value"),
+        (&["--runs", "many"], "--runs many"),
+        (&["--seed", "-1"], "--seed -1"),
+        (&["--format", "xml"], "unknown format `xml`"),
+        (&["--verbose"], "unknown option `--verbose`"),
+        (&["--max-time", "0"], "--max-time must be a positive"),
+        (
+            &["--seed", "18446744073709551615", "--runs", "2"],
+            "runs past the last seed",
+        ),
+    ];
+    for (args, expected) in cases {
+        let err = parse(args).unwrap_err();
+        assert!(err.contains(expected), "{args:?}: {err}");
+    }
+    assert!(parse(&["--max-time", "nan"]).is_err());
+}
+
+#[test]
+fn loads_the_shipped_data() {
+    let options = Options {
+        runs: 2,
+        max_time: 5.0,
+        ..asset_options()
+    };
+    let mut world = load_world(&options).unwrap();
+    let runs = play_runs(&mut world, &options);
+    assert_eq!(runs.iter().map(|run| run.seed).collect::<Vec<_>>(), [0, 1]);
+    assert!(runs.iter().all(|run| run.stats.survival_time <= 5.0));
+}
+
+#[test]
+fn rejects_invalid_tuning_and_pattern_files() {
+    let tuning = temp_file("tuning.toml", "[player]\ngravity = -10.0\n");
+    let err = load_world(&Options {
+        tuning: tuning.clone(),
+        ..asset_options()
+    })
+    .err()
+    .unwrap();
+    assert!(err.starts_with(&tuning.display().to_string()), "{err}");
+    assert!(err.contains("player.gravity"), "{err}");
+
+    let patterns = temp_file(
+        "patterns.toml",
+        "[[chunk]]\nname = \"late\"\nmin_time = 5.0\nobstacles = [{ kind = \"saw\", x = 0.0 }]\n",
+    );
+    let err = load_world(&Options {
+        patterns: patterns.clone(),
+        ..asset_options()
+    })
+    .err()
+    .unwrap();
+    assert!(err.starts_with(&patterns.display().to_string()), "{err}");
+    assert!(err.contains("min_time = 0"), "{err}");
+
+    // 调参文件缺失时使用内置默认值，图案文件则必须存在。
+    assert!(
+        load_world(&Options {
+            tuning: asset("missing.toml"),
+            ..asset_options()
+        })
+        .is_ok()
+    );
+    assert!(
+        load_world(&Options {
+            patterns: asset("missing.toml"),
+            ..asset_options()
+        })
+        .is_err()
+    );
+
+    std::fs::remove_file(tuning).unwrap();
+    std::fs::remove_file(patterns).unwrap();
+}
+
+#[test]
+fn writes_csv_rows() {
+    let mut out = Vec::new();
+    write_runs(&mut out, &sample_runs(), Format::Csv).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "seed,survival_time,killed_by,distance,coins,gems,max_combo,max_streak,\
+             dash_time,jumps,air_jumps,power_ups,near_misses",
+            "3,41.500,saw,812.2,12,1,4,1.75,2.000,30,5,2,3",
+            "4,120.000,,5000.0,0,0,0,1.00,0.000,0,0,0,0",
+        ]
+    );
+}
+
+#[test]
+fn writes_json_array() {
+    let mut out = Vec::new();
+    write_runs(&mut out, &sample_runs(), Format::Json).unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    let runs = value.as_array().unwrap();
+    assert_eq!(runs.len(), 2);
+    // 统计字段与种子平铺在同一层，死因使用小写名称。
+    assert_eq!(runs[0]["seed"], 3);
+    assert_eq!(runs[0]["killed_by"], "saw");
+    assert_eq!(runs[0]["coins"], 12);
+    assert!(runs[1]["killed_by"].is_null());
+}
+
+#[test]
+fn summary_counts_deaths() {
+    assert_eq!(summary(&[], 60.0), "no runs\n");
+    let text = summary(&sample_runs(), 120.0);
+    assert!(text.starts_with("2 runs, capped at 120s\n"), "{text}");
+    assert!(
+        text.contains("deaths:       crate 0 saw 1 pit 0 drone 0 laser 0  survived 1"),
+        "{text}"
+    );
+}
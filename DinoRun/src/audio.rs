@@ -0,0 +1,411 @@
+//! 基于 macroquad 的音频后端：启动时合成全部音效与背景音乐，不依赖额外的音频资源文件。
+//!
+//! `audio` 特性默认启用；以 `--no-default-features` 构建时 macroquad 不会真正发声，此时直接使用静音后端。
+
+use dinorun_core::audio::{AudioBackend, MusicTrack, NullAudio, Sfx};
+use dinorun_core::rng::Rng;
+use macroquad::audio::{
+    PlaySoundParams, Sound, load_sound_from_bytes, play_sound, set_sound_volume,
+};
+use std::f32::consts::TAU;
+
+/// 合成采样率，与 macroquad 的混音器一致，避免加载时重采样。
+const SAMPLE_RATE: u32 = 44_100;
+/// 时间减缓期间对局音乐的变速比例，同时降低音高。
+const SLOW_PITCH: f32 = 0.75;
+/// 起音与收尾的最短淡变时间（秒），消除咔哒声。
+const CLICK_GUARD: f32 = 0.004;
+
+/// 创建音频后端；关闭了 `audio` 特性时返回静音后端。
+pub async fn load_backend() -> Box<dyn AudioBackend> {
+    if !cfg!(feature = "audio") {
+        return Box::new(NullAudio);
+    }
+    Box::new(MacroquadAudio::load().await)
+}
+
+/// 通过 macroquad 播放的后端，音效与音乐均按各自 `ALL` 的顺序存放。
+struct MacroquadAudio {
+    sfx: Vec<Sound>,
+    music: Vec<Sound>,
+}
+
+impl MacroquadAudio {
+    async fn load() -> Self {
+        let mut sfx = Vec::with_capacity(Sfx::ALL.len());
+        for kind in Sfx::ALL {
+            sfx.push(load_samples(&synth_sfx(kind)).await);
+        }
+        let run = synth_run_music();
+        let mut music = Vec::with_capacity(MusicTrack::ALL.len());
+        for track in MusicTrack::ALL {
+            let samples = match track {
+                MusicTrack::Menu => synth_menu_music(),
+                MusicTrack::Run => run.clone(),
+                MusicTrack::RunSlow => resample_loop(&run, SLOW_PITCH),
+            };
+            let sound = load_samples(&samples).await;
+            // 音乐从启动起一直循环，由调度器通过音量切换。
+            play_sound(
+                &sound,
+                PlaySoundParams {
+                    looped: true,
+                    volume: 0.0,
+                },
+            );
+            music.push(sound);
+        }
+        Self { sfx, music }
+    }
+}
+
+impl AudioBackend for MacroquadAudio {
+    fn play(&mut self, sfx: Sfx, volume: f32) {
+        // 枚举的声明顺序与 `ALL` 一致，可直接作为下标。
+        play_sound(
+            &self.sfx[sfx as usize],
+            PlaySoundParams {
+                looped: false,
+                volume,
+            },
+        );
+    }
+
+    fn set_music_volume(&mut self, track: MusicTrack, volume: f32) {
+        set_sound_volume(&self.music[track as usize], volume);
+    }
+}
+
+/// 把采样编码为 WAV 后交给 macroquad 加载。
+async fn load_samples(samples: &[f32]) -> Sound {
+    load_sound_from_bytes(&encode_wav(samples))
+        .await
+        .expect("synthesized WAV data is always valid")
+}
+
+/// 编码为 16 位单声道 PCM WAV；超出范围的采样经软削波压回。
+fn encode_wav(samples: &[f32]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut out = Vec::with_capacity(44 + data_len as usize);
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_len).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());
+    out.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    out.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    out.extend_from_slice(&2u16.to_le_bytes());
+    out.extend_from_slice(&16u16.to_le_bytes());
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        let value = (sample.tanh() * i16::MAX as f32) as i16;
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+    out
+}
+
+/// 振荡器波形。
+#[derive(Clone, Copy)]
+enum Wave {
+    Sine,
+    Square,
+    Triangle,
+    Saw,
+}
+
+impl Wave {
+    /// `phase` 为 `[0, 1)` 内的相位。
+    fn sample(self, phase: f32) -> f32 {
+        match self {
+            Wave::Sine => (phase * TAU).sin(),
+            Wave::Square => {
+                if phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Wave::Triangle => 1.0 - 4.0 * (phase - 0.5).abs(),
+            Wave::Saw => 2.0 * phase - 1.0,
+        }
+    }
+}
+
+/// 一个音符：频率从 `from` 指数滑到 `to`；包络先用 `attack` 秒淡入，再按 `decay` 次幂衰减到结尾。
+#[derive(Clone, Copy)]
+struct Note {
+    wave: Wave,
+    from: f32,
+    to: f32,
+    start: f32,
+    length: f32,
+    gain: f32,
+    attack: f32,
+    decay: f32,
+}
+
+impl Note {
+    /// 音高不变、打击感衰减的短音。
+    fn pluck(wave: Wave, freq: f32, start: f32, length: f32, gain: f32) -> Self {
+        Self {
+            wave,
+            from: freq,
+            to: freq,
+            start,
+            length,
+            gain,
+            attack: CLICK_GUARD,
+            decay: 2.0,
+        }
+    }
+
+    /// 频率滑动的短音。
+    fn sweep(wave: Wave, from: f32, to: f32, start: f32, length: f32, gain: f32) -> Self {
+        Self {
+            from,
+            to,
+            ..Self::pluck(wave, from, start, length, gain)
+        }
+    }
+}
+
+fn seconds_to_samples(seconds: f32) -> usize {
+    (seconds * SAMPLE_RATE as f32).round() as usize
+}
+
+/// 包络：起音淡入，按 `decay` 次幂衰减，并在最后几毫秒淡出。
+fn envelope(t: f32, length: f32, attack: f32, decay: f32) -> f32 {
+    let fade_in = (t / attack.max(CLICK_GUARD)).min(1.0);
+    let fade_out = ((length - t) / CLICK_GUARD).min(1.0);
+    fade_in * (1.0 - t / length).powf(decay) * fade_out
+}
+
+/// 把音符叠加进缓冲区；超出末尾的部分绕回开头，循环音乐的接缝处不会断音。
+fn add_note(buf: &mut [f32], note: Note) {
+    let offset = seconds_to_samples(note.start);
+    let count = seconds_to_samples(note.length);
+    let ratio = note.to / note.from;
+    let mut phase = 0.0;
+    for i in 0..count {
+        let t = i as f32 / SAMPLE_RATE as f32;
+        let freq = note.from * ratio.powf(t / note.length);
+        let value = note.wave.sample(phase) * envelope(t, note.length, note.attack, note.decay);
+        buf[(offset + i) % buf.len()] += value * note.gain;
+        phase = (phase + freq / SAMPLE_RATE as f32).fract();
+    }
+}
+
+/// 叠加一段衰减的噪声；`bright` 为真时取相邻采样之差，得到更尖的高频噪声。
+fn add_noise(buf: &mut [f32], rng: &mut Rng, start: f32, length: f32, gain: f32, bright: bool) {
+    let offset = seconds_to_samples(start);
+    let count = seconds_to_samples(length);
+    let mut last = 0.0;
+    for i in 0..count {
+        let t = i as f32 / SAMPLE_RATE as f32;
+        let white = rng.gen_range(-1.0, 1.0);
+        let value = if bright { (white - last) * 0.5 } else { white };
+        last = white;
+        buf[(offset + i) % buf.len()] += value * envelope(t, length, CLICK_GUARD, 2.0) * gain;
+    }
+}
+
+/// MIDI 音符编号对应的频率。
+fn midi(note: i32) -> f32 {
+    440.0 * 2f32.powf((note - 69) as f32 / 12.0)
+}
+
+/// 合成单个音效。
+fn synth_sfx(kind: Sfx) -> Vec<f32> {
+    let length = match kind {
+        Sfx::Jump | Sfx::DoubleJump | Sfx::Land => 0.16,
+        Sfx::Dash | Sfx::Slide => 0.22,
+        Sfx::Coin => 0.26,
+        Sfx::Gem | Sfx::PowerUp => 0.36,
+        Sfx::ShieldBreak => 0.4,
+        Sfx::Death => 0.75,
+    };
+    let mut buf = vec![0.0; seconds_to_samples(length)];
+    let mut rng = Rng::new(kind as u64 + 1);
+    match kind {
+        Sfx::Jump => add_note(
+            &mut buf,
+            Note::sweep(Wave::Square, 280.0, 620.0, 0.0, 0.13, 0.22),
+        ),
+        Sfx::DoubleJump => {
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Square, 460.0, 940.0, 0.0, 0.11, 0.2),
+            );
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Triangle, 920.0, 1400.0, 0.03, 0.12, 0.2),
+            );
+        }
+        Sfx::Dash => {
+            add_noise(&mut buf, &mut rng, 0.0, 0.2, 0.35, false);
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Saw, 220.0, 70.0, 0.0, 0.2, 0.25),
+            );
+        }
+        Sfx::Slide => {
+            add_noise(&mut buf, &mut rng, 0.0, 0.2, 0.3, true);
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Triangle, 320.0, 150.0, 0.0, 0.18, 0.2),
+            );
+        }
+        Sfx::Land => {
+            add_noise(&mut buf, &mut rng, 0.0, 0.07, 0.3, false);
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Sine, 140.0, 55.0, 0.0, 0.14, 0.6),
+            );
+        }
+        Sfx::Coin => {
+            add_note(
+                &mut buf,
+                Note::pluck(Wave::Square, midi(83), 0.0, 0.07, 0.18),
+            );
+            add_note(
+                &mut buf,
+                Note::pluck(Wave::Square, midi(88), 0.06, 0.2, 0.18),
+            );
+        }
+        Sfx::Gem => {
+            for (i, note) in [84, 88, 91, 96].into_iter().enumerate() {
+                let start = i as f32 * 0.05;
+                add_note(
+                    &mut buf,
+                    Note::pluck(Wave::Triangle, midi(note), start, 0.2, 0.35),
+                );
+            }
+        }
+        Sfx::PowerUp => {
+            for (i, note) in [72, 76, 79, 84, 88].into_iter().enumerate() {
+                let start = i as f32 * 0.045;
+                add_note(
+                    &mut buf,
+                    Note::pluck(Wave::Square, midi(note), start, 0.16, 0.12),
+                );
+                add_note(
+                    &mut buf,
+                    Note::pluck(Wave::Triangle, midi(note - 12), start, 0.16, 0.25),
+                );
+            }
+        }
+        Sfx::ShieldBreak => {
+            add_noise(&mut buf, &mut rng, 0.0, 0.38, 0.4, true);
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Square, 1200.0, 180.0, 0.0, 0.3, 0.16),
+            );
+        }
+        Sfx::Death => {
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Square, 440.0, 70.0, 0.0, 0.7, 0.2),
+            );
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Triangle, 220.0, 40.0, 0.05, 0.7, 0.35),
+            );
+            add_noise(&mut buf, &mut rng, 0.0, 0.25, 0.25, false);
+        }
+    }
+    buf
+}
+
+/// 两首曲子共用的和弦进行 Am - F - C - G：每小节的低音与三和弦（MIDI 编号）。
+const PROGRESSION: [(i32, [i32; 3]); 4] = [
+    (45, [57, 60, 64]),
+    (41, [53, 57, 60]),
+    (48, [55, 60, 64]),
+    (43, [55, 59, 62]),
+];
+
+/// 菜单音乐：80 BPM 的柔和铺底与缓慢琶音，四小节循环。
+fn synth_menu_music() -> Vec<f32> {
+    let beat = 60.0 / 80.0;
+    let bar = beat * 4.0;
+    let mut buf = vec![0.0; seconds_to_samples(bar * PROGRESSION.len() as f32)];
+    for (i, (bass, chord)) in PROGRESSION.into_iter().enumerate() {
+        let start = i as f32 * bar;
+        for note in chord {
+            add_note(
+                &mut buf,
+                Note {
+                    attack: bar * 0.35,
+                    decay: 0.8,
+                    ..Note::pluck(Wave::Sine, midi(note), start, bar * 1.2, 0.12)
+                },
+            );
+        }
+        add_note(
+            &mut buf,
+            Note::pluck(Wave::Triangle, midi(bass), start, bar, 0.3),
+        );
+        for step in 0..8 {
+            let note = chord[[0, 1, 2, 1][step % 4]] + 12;
+            let at = start + step as f32 * beat * 0.5;
+            add_note(
+                &mut buf,
+                Note::pluck(Wave::Triangle, midi(note), at, beat, 0.07),
+            );
+        }
+    }
+    buf
+}
+
+/// 对局音乐：140 BPM 的八分音符低音、十六分音符琶音与鼓点，四小节循环。
+fn synth_run_music() -> Vec<f32> {
+    let beat = 60.0 / 140.0;
+    let bar = beat * 4.0;
+    let mut buf = vec![0.0; seconds_to_samples(bar * PROGRESSION.len() as f32)];
+    let mut rng = Rng::new(0x5EED);
+    for (i, (bass, chord)) in PROGRESSION.into_iter().enumerate() {
+        let start = i as f32 * bar;
+        for step in 0..8 {
+            let at = start + step as f32 * beat * 0.5;
+            let octave = if step % 2 == 1 { 12 } else { 0 };
+            add_note(
+                &mut buf,
+                Note::pluck(Wave::Triangle, midi(bass + octave), at, beat * 0.45, 0.35),
+            );
+        }
+        for step in 0..16 {
+            let note = chord[[0, 1, 2, 1][step % 4]] + 12;
+            let at = start + step as f32 * beat * 0.25;
+            add_note(
+                &mut buf,
+                Note::pluck(Wave::Square, midi(note), at, beat * 0.22, 0.06),
+            );
+        }
+        for step in 0..4 {
+            let at = start + step as f32 * beat;
+            add_note(
+                &mut buf,
+                Note::sweep(Wave::Sine, 150.0, 45.0, at, 0.16, 0.6),
+            );
+            add_noise(&mut buf, &mut rng, at + beat * 0.5, 0.04, 0.18, true);
+        }
+    }
+    buf
+}
+
+/// 按比例变速循环音乐（线性插值），`rate` 小于 1 时变慢变低。
+fn resample_loop(samples: &[f32], rate: f32) -> Vec<f32> {
+    let len = (samples.len() as f32 / rate) as usize;
+    (0..len)
+        .map(|i| {
+            let pos = i as f32 * rate;
+            let index = pos as usize;
+            let frac = pos - index as f32;
+            let a = samples[index % samples.len()];
+            let b = samples[(index + 1) % samples.len()];
+            a + (b - a) * frac
+        })
+        .collect()
+}
@@ -0,0 +1,74 @@
+//! 画面映射与屏幕震动：把固定的逻辑分辨率等比缩放到窗口中央（多余部分留边），
+//! 并在撞击、护盾破碎与重落地时让场景短暂晃动。
+
+use dinorun_core::constants::*;
+use dinorun_core::events::GameEvent;
+use dinorun_core::world::World;
+use macroquad::miniquad::window::dpi_scale;
+use macroquad::prelude::*;
+
+/// 震动强度为 1 时场景的最大偏移（逻辑像素）。
+const MAX_SHAKE_OFFSET: f32 = 14.0;
+/// 震动强度每秒衰减的量。
+const SHAKE_DECAY: f32 = 1.6;
+
+/// 把逻辑画面 `SCREEN_WIDTH x SCREEN_HEIGHT` 映射到当前窗口的相机，`offset` 为场景的平移。
+pub fn screen_camera(offset: Vec2) -> Camera2D {
+    let scale = (screen_width() / SCREEN_WIDTH).min(screen_height() / SCREEN_HEIGHT);
+    let width = SCREEN_WIDTH * scale;
+    let height = SCREEN_HEIGHT * scale;
+    // 视口以物理像素计，高 DPI 屏幕上需要乘以缩放系数。
+    let dpi = dpi_scale();
+    let viewport = (
+        ((screen_width() - width) * 0.5 * dpi) as i32,
+        ((screen_height() - height) * 0.5 * dpi) as i32,
+        (width * dpi) as i32,
+        (height * dpi) as i32,
+    );
+    Camera2D {
+        viewport: Some(viewport),
+        ..Camera2D::from_display_rect(Rect::new(-offset.x, -offset.y, SCREEN_WIDTH, SCREEN_HEIGHT))
+    }
+}
+
+/// 屏幕震动：玩法事件累积强度，随时间衰减；偏移随强度平方增长，小震动更柔和。
+#[derive(Default)]
+pub struct ScreenShake {
+    trauma: f32,
+    time: f32,
+}
+
+impl ScreenShake {
+    /// 在每个模拟步之后调用，按本步事件增加震动。
+    pub fn observe(&mut self, world: &World) {
+        for event in &world.events {
+            let amount = match *event {
+                GameEvent::Died { .. } => 0.8,
+                GameEvent::ShieldBroke { .. } => 0.6,
+                // 只有接近终端速度的重落地才会轻微震动。
+                GameEvent::Landed { speed } => {
+                    (speed / world.tuning.player.terminal_velocity - 0.6).max(0.0)
+                }
+                _ => continue,
+            };
+            self.trauma = (self.trauma + amount).min(1.0);
+        }
+    }
+
+    /// 每帧调用一次，让震动衰减。
+    pub fn update(&mut self, dt: f32) {
+        self.time += dt;
+        self.trauma = (self.trauma - SHAKE_DECAY * dt).max(0.0);
+    }
+
+    /// 当前的场景偏移，`intensity` 为设置中的震动强度。
+    pub fn offset(&self, intensity: f32) -> Vec2 {
+        let amount = self.trauma * self.trauma * intensity * MAX_SHAKE_OFFSET;
+        // 几个不成倍数的频率叠加，得到不规则但平滑的晃动。
+        let t = self.time;
+        vec2(
+            (t * 47.0).sin() * 0.6 + (t * 73.0).sin() * 0.4,
+            (t * 53.0).cos() * 0.6 + (t * 89.0).sin() * 0.4,
+        ) * amount
+    }
+}
@@ -0,0 +1,381 @@
+//! 实体绘制：玩家、障碍、收集物与粒子特效的外观表现。
+
+use crate::types::Palette;
+use dinorun_core::animation::{AnimationSet, Animator};
+use dinorun_core::collectibles::Collectible;
+use dinorun_core::obstacles::Obstacle;
+use dinorun_core::particles::Particle;
+use dinorun_core::player::Player;
+use dinorun_core::powerups::PowerUpPickup;
+use dinorun_core::settings::ParticleDensity;
+use dinorun_core::types::{CollectibleKind, FloatingText, ObstacleKind, PowerUpKind};
+use macroquad::prelude::*;
+
+/// 精灵图集与动画数据，供所有带 [`Animator`] 的实体共用。
+pub struct SpriteSheet {
+    pub texture: Texture2D,
+    pub animations: AnimationSet,
+}
+
+impl SpriteSheet {
+    /// 按动画状态绘制一帧到目标矩形；片段缺失时返回 `false`，由调用方退回到占位图形。
+    pub fn draw(&self, animator: &Animator, dest: Rect, rotation: f32) -> bool {
+        let Some(frame) = self.animations.frame(animator) else {
+            return false;
+        };
+        draw_texture_ex(
+            &self.texture,
+            dest.x,
+            dest.y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(dest.w, dest.h)),
+                source: Some(Rect::new(
+                    frame.rect.x,
+                    frame.rect.y,
+                    frame.rect.w,
+                    frame.rect.h,
+                )),
+                rotation,
+                ..Default::default()
+            },
+        );
+        true
+    }
+}
+
+/// 按玩家当前动画绘制，挤压形变以脚底中点为锚点。
+/// 滑铲时身形压到碰撞盒的高度，并描出碰撞盒；起滑的无敌窗口内碰撞盒闪烁。
+pub fn draw_player(player: &Player, sprites: &SpriteSheet, alpha: f32) {
+    let pos = player.interpolated_pos(alpha);
+    let body = player.rect();
+    let body = Rect::new(
+        body.x + pos.x - player.pos.x,
+        body.y + pos.y - player.pos.y,
+        body.w,
+        body.h,
+    );
+    let size = vec2(body.w, body.h) * player.animator.scale();
+    let dest = Rect::new(
+        body.x + (body.w - size.x) * 0.5,
+        body.y + body.h - size.y,
+        size.x,
+        size.y,
+    );
+    if !sprites.draw(&player.animator, dest, 0.0) {
+        let color = if player.sliding {
+            Color::from_rgba(220, 110, 40, 255)
+        } else {
+            Color::from_rgba(240, 140, 50, 255)
+        };
+        draw_rectangle(dest.x, dest.y, dest.w, dest.h, color);
+    }
+    if player.sliding {
+        let color = if player.is_invulnerable() {
+            let pulse = (player.invulnerable_timer * 60.0).sin() * 0.5 + 0.5;
+            Color::new(1.0, 1.0, 1.0, 0.5 + pulse * 0.5)
+        } else {
+            Color::from_rgba(90, 220, 255, 160)
+        };
+        draw_rectangle_lines(body.x, body.y, body.w, body.h, 2.0, color);
+    }
+}
+
+/// 绘制场景内所有障碍；圆锯与无人机使用精灵动画，其余类型采用几何图形。
+pub fn draw_obstacles(obstacles: &[Obstacle], sprites: &SpriteSheet, alpha: f32) {
+    for obstacle in obstacles {
+        let rect = obstacle.interpolated_rect(alpha);
+        if obstacle.is_tethered() {
+            // 低空无人机的缆绳从画面顶端垂到机身，提示只能从下方滑过。
+            let center = rect.x + rect.w * 0.5;
+            draw_rectangle(
+                rect.x + 8.0,
+                0.0,
+                rect.w - 20.0,
+                rect.y,
+                Color::from_rgba(90, 90, 110, 50),
+            );
+            draw_rectangle(
+                center - 3.0,
+                0.0,
+                6.0,
+                rect.y,
+                Color::from_rgba(60, 60, 75, 255),
+            );
+        }
+        if let Some(animator) = &obstacle.animator {
+            let dest = Rect::new(rect.x, rect.y, rect.w, rect.h);
+            let rotation = if obstacle.kind == ObstacleKind::Saw {
+                obstacle.saw_angle
+            } else {
+                0.0
+            };
+            if sprites.draw(animator, dest, rotation) {
+                continue;
+            }
+        }
+        match obstacle.kind {
+            ObstacleKind::Crate => {
+                draw_rectangle(
+                    rect.x,
+                    rect.y,
+                    rect.w,
+                    rect.h,
+                    Color::from_rgba(120, 83, 58, 255),
+                );
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 3.0, BLACK);
+            }
+            ObstacleKind::Saw => {
+                let center = vec2(rect.x + rect.w * 0.5, rect.y + rect.h * 0.5);
+                let radius = rect.w * 0.5;
+                draw_circle(
+                    center.x,
+                    center.y,
+                    radius,
+                    Color::from_rgba(210, 210, 210, 255),
+                );
+                for i in 0..8 {
+                    let angle = obstacle.saw_angle + i as f32 * std::f32::consts::PI / 4.0;
+                    let dir = vec2(angle.cos(), angle.sin());
+                    draw_triangle(
+                        center,
+                        center + dir * radius,
+                        center + vec2(-dir.y, dir.x) * (radius * 0.7),
+                        Color::from_rgba(180, 180, 180, 255),
+                    );
+                }
+            }
+            ObstacleKind::Pit => {
+                draw_rectangle(
+                    rect.x,
+                    rect.y,
+                    rect.w,
+                    12.0,
+                    Color::from_rgba(10, 20, 30, 255),
+                );
+                draw_rectangle(
+                    rect.x,
+                    rect.y + 12.0,
+                    rect.w,
+                    16.0,
+                    Color::from_rgba(12, 16, 18, 255),
+                );
+            }
+            ObstacleKind::Laser => {
+                // 光束从画面顶端的发射器射下，末端带一团光晕提示离地高度。
+                let bottom = rect.y + rect.h;
+                let center = rect.x + rect.w * 0.5;
+                draw_rectangle(
+                    rect.x - 4.0,
+                    0.0,
+                    rect.w + 8.0,
+                    bottom,
+                    Color::from_rgba(255, 60, 90, 70),
+                );
+                draw_rectangle(
+                    rect.x,
+                    0.0,
+                    rect.w,
+                    bottom,
+                    Color::from_rgba(255, 50, 80, 200),
+                );
+                draw_rectangle(
+                    center - rect.w * 0.15,
+                    0.0,
+                    rect.w * 0.3,
+                    bottom,
+                    Color::from_rgba(255, 220, 230, 255),
+                );
+                draw_rectangle(
+                    rect.x - 10.0,
+                    0.0,
+                    rect.w + 20.0,
+                    18.0,
+                    Color::from_rgba(60, 60, 75, 255),
+                );
+                draw_circle(
+                    center,
+                    bottom,
+                    rect.w * 0.6,
+                    Color::from_rgba(255, 120, 140, 160),
+                );
+            }
+            ObstacleKind::Drone => {
+                draw_rectangle(
+                    rect.x,
+                    rect.y,
+                    rect.w,
+                    rect.h,
+                    Color::from_rgba(90, 90, 110, 255),
+                );
+                draw_rectangle(
+                    rect.x + rect.w * 0.1,
+                    rect.y + rect.h * 0.2,
+                    rect.w * 0.8,
+                    rect.h * 0.6,
+                    Color::from_rgba(40, 200, 200, 200),
+                );
+            }
+        }
+    }
+}
+
+/// 根据类型绘制收集物，并加入轻微浮动效果。
+pub fn draw_collectibles(collectibles: &[Collectible], alpha: f32, palette: &Palette) {
+    for item in collectibles {
+        let rect = item.interpolated_rect(alpha);
+        let mut y = rect.y;
+        y += item.float_phase.sin() * 10.0;
+        let color = match item.kind {
+            CollectibleKind::Coin => palette.coin,
+            CollectibleKind::Gem => palette.gem,
+        };
+        draw_rectangle(rect.x, y, rect.w, rect.h, color);
+        draw_rectangle_lines(
+            rect.x,
+            y,
+            rect.w,
+            rect.h,
+            2.0,
+            Color::from_rgba(0, 0, 0, 160),
+        );
+    }
+}
+
+/// 绘制强化道具：每种类型拥有独立的图形，外圈光环提示可拾取。
+pub fn draw_power_ups(power_ups: &[PowerUpPickup], alpha: f32, palette: &Palette) {
+    for pickup in power_ups {
+        let rect = pickup.interpolated_rect(alpha);
+        let center = vec2(
+            rect.x + rect.w * 0.5,
+            rect.y + rect.h * 0.5 + pickup.float_phase.sin() * 8.0,
+        );
+        let radius = rect.w * 0.5;
+        let glow = 0.35 + (pickup.float_phase * 2.0).sin().abs() * 0.25;
+        match pickup.kind {
+            PowerUpKind::Shield => {
+                draw_circle(
+                    center.x,
+                    center.y,
+                    radius + 6.0,
+                    with_alpha(palette.shield, glow),
+                );
+                draw_poly(center.x, center.y, 6, radius, 30.0, palette.shield);
+                draw_poly_lines(
+                    center.x,
+                    center.y,
+                    6,
+                    radius,
+                    30.0,
+                    3.0,
+                    Color::from_rgba(200, 235, 255, 255),
+                );
+                draw_circle(
+                    center.x,
+                    center.y,
+                    radius * 0.35,
+                    Color::from_rgba(200, 235, 255, 255),
+                );
+            }
+            PowerUpKind::ScoreBoost => {
+                draw_circle(
+                    center.x,
+                    center.y,
+                    radius + 6.0,
+                    with_alpha(palette.score_boost, glow),
+                );
+                // 两个错开的三角形拼成六角星。
+                for offset in [0.0, std::f32::consts::PI] {
+                    let points: Vec<Vec2> = (0..3)
+                        .map(|i| {
+                            let angle = offset - std::f32::consts::FRAC_PI_2
+                                + i as f32 * std::f32::consts::TAU / 3.0;
+                            center + vec2(angle.cos(), angle.sin()) * radius
+                        })
+                        .collect();
+                    draw_triangle(points[0], points[1], points[2], palette.score_boost);
+                }
+                draw_text(
+                    "x2",
+                    center.x - 11.0,
+                    center.y + 7.0,
+                    22.0,
+                    Color::from_rgba(120, 70, 0, 255),
+                );
+            }
+            PowerUpKind::TimeSlow => {
+                draw_circle(
+                    center.x,
+                    center.y,
+                    radius + 6.0,
+                    with_alpha(palette.time_slow, glow),
+                );
+                draw_circle(center.x, center.y, radius, palette.time_slow);
+                draw_circle_lines(
+                    center.x,
+                    center.y,
+                    radius,
+                    3.0,
+                    Color::from_rgba(230, 210, 255, 255),
+                );
+                // 沙漏轮廓。
+                let top = center - vec2(0.0, radius * 0.6);
+                let bottom = center + vec2(0.0, radius * 0.6);
+                let half = radius * 0.45;
+                draw_triangle(
+                    top - vec2(half, 0.0),
+                    top + vec2(half, 0.0),
+                    center,
+                    Color::from_rgba(230, 210, 255, 255),
+                );
+                draw_triangle(
+                    bottom - vec2(half, 0.0),
+                    bottom + vec2(half, 0.0),
+                    center,
+                    Color::from_rgba(230, 210, 255, 255),
+                );
+            }
+        }
+    }
+}
+
+/// 按透明度绘制粒子圆形；密度较低时按粒子槽位固定跳过一部分，模拟本身不受影响。
+pub fn draw_particles(particles: &[Particle], density: ParticleDensity) {
+    for (idx, particle) in particles.iter().enumerate() {
+        let shown = match density {
+            ParticleDensity::Low => idx % 3 == 0,
+            ParticleDensity::Medium => idx % 3 != 2,
+            ParticleDensity::High => true,
+        };
+        if !particle.active || !shown {
+            continue;
+        }
+        // 使用剩余寿命作为透明度，增强消散感。
+        let alpha = (particle.lifetime / particle.max_lifetime).clamp(0.0, 1.0);
+        draw_circle(
+            particle.pos.x,
+            particle.pos.y,
+            6.0,
+            Color::new(particle.color.r, particle.color.g, particle.color.b, alpha),
+        );
+    }
+}
+
+/// 替换颜色的透明度。
+fn with_alpha(color: Color, alpha: f32) -> Color {
+    Color::new(color.r, color.g, color.b, alpha)
+}
+
+/// 渲染浮动文本，随时间淡出。
+pub fn draw_floating_texts(floating_texts: &[FloatingText]) {
+    for text in floating_texts {
+        let ratio = (text.timer / text.max_timer).clamp(0.0, 1.0);
+        draw_text(
+            &text.text,
+            text.pos.x,
+            text.pos.y,
+            28.0,
+            Color::new(text.color.r, text.color.g, text.color.b, ratio),
+        );
+    }
+}
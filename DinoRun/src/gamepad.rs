@@ -0,0 +1,67 @@
+//! 基于 gilrs 的手柄后端，支持运行中插拔。
+//!
+//! `gamepad` 特性默认启用；关闭时不链接 gilrs，直接使用没有手柄的空后端。
+
+use dinorun_core::gamepad::{GamepadBackend, NullGamepad};
+#[cfg(feature = "gamepad")]
+use dinorun_core::gamepad::{Pad, PadButton, PadState};
+#[cfg(feature = "gamepad")]
+use gilrs::{Axis, Button, Gilrs};
+
+/// 创建手柄后端；关闭了 `gamepad` 特性或初始化失败时返回空后端。
+pub fn load_backend() -> Box<dyn GamepadBackend> {
+    #[cfg(feature = "gamepad")]
+    match Gilrs::new() {
+        Ok(gilrs) => return Box::new(GilrsBackend { gilrs }),
+        Err(err) => eprintln!("gamepad support unavailable: {err}"),
+    }
+    Box::new(NullGamepad)
+}
+
+/// 通过 gilrs 读取的手柄，设备编号沿用 gilrs 的 `GamepadId`。
+#[cfg(feature = "gamepad")]
+struct GilrsBackend {
+    gilrs: Gilrs,
+}
+
+#[cfg(feature = "gamepad")]
+impl GamepadBackend for GilrsBackend {
+    fn pads(&mut self) -> Vec<Pad> {
+        // 取空事件队列后 gilrs 才会更新按钮状态与连接列表。
+        while self.gilrs.next_event().is_some() {}
+        let button = |button: PadButton| match button {
+            PadButton::South => Button::South,
+            PadButton::East => Button::East,
+            PadButton::West => Button::West,
+            PadButton::North => Button::North,
+            PadButton::LeftBumper => Button::LeftTrigger,
+            PadButton::RightBumper => Button::RightTrigger,
+            PadButton::LeftTrigger => Button::LeftTrigger2,
+            PadButton::RightTrigger => Button::RightTrigger2,
+            PadButton::Select => Button::Select,
+            PadButton::Start => Button::Start,
+            PadButton::DPadUp => Button::DPadUp,
+            PadButton::DPadDown => Button::DPadDown,
+            PadButton::DPadLeft => Button::DPadLeft,
+            PadButton::DPadRight => Button::DPadRight,
+        };
+        self.gilrs
+            .gamepads()
+            .map(|(id, pad)| Pad {
+                id: id.into(),
+                name: pad.name().to_string(),
+                state: PadState {
+                    buttons: PadButton::ALL
+                        .into_iter()
+                        .filter(|b| pad.is_pressed(button(*b)))
+                        .collect(),
+                    // gilrs 的摇杆 y 轴向上为正。
+                    stick: dinorun_core::vec2(
+                        pad.value(Axis::LeftStickX),
+                        -pad.value(Axis::LeftStickY),
+                    ),
+                },
+            })
+            .collect()
+    }
+}
@@ -1,113 +1,41 @@
-use crate::constants::*;
-use crate::particles::spawn_dust;
-use crate::types::{GameState, PlayerAction, PowerUpKind};
-use crate::world::World;
+use dinorun_core::bindings::{Action, Bindings};
+use dinorun_core::input::{InputFrame, InputSource};
+use dinorun_core::world::World;
 use macroquad::prelude::*;
 
-/// 统一处理玩家输入，根据当前状态机执行操作或切换状态。
-pub fn handle_input(world: &mut World, dt: f32) {
-    if is_key_pressed(KeyCode::Escape) {
-        match world.state {
-            GameState::Running => {
-                world.state = GameState::Paused;
-                world.pause_flash = 1.0;
-            }
-            GameState::Paused => {
-                world.state = GameState::Running;
-            }
-            GameState::Menu { .. } | GameState::Splash { .. } => {}
-            GameState::GameOver { .. } => {}
-        }
-    }
+/// 键盘输入来源，按设置中的按键绑定把 macroquad 的按键状态翻译成 [`InputFrame`]。
+pub struct KeyboardInput;
 
-    match world.state {
-        GameState::Splash { .. } => {}
-        GameState::Menu { .. } => {
-            if is_key_pressed(KeyCode::Up) {
-                world.menu_selected = world.menu_selected.saturating_sub(1);
-            }
-            if is_key_pressed(KeyCode::Down) {
-                world.menu_selected = (world.menu_selected + 1).min(2);
-            }
-            if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::Space) {
-                if world.menu_selected == 0 {
-                    world.reset_run();
-                    world.state = GameState::Running;
-                } else if world.menu_selected == 1 {
-                    world.power_up.activate(PowerUpKind::Shield);
-                    world.power_up.timer = 0.1;
-                } else {
-                    world.reset_run();
-                    world.best_score = 0.0;
-                    world.currency = 0;
-                }
-            }
-        }
-        GameState::Running => {
-            let ground = world.difficulty.ground_y();
-            let mut dust_events: Vec<(Vec2, usize)> = Vec::new();
-            {
-                let p = &mut world.player;
-                // 支持多键触发跳跃，方便玩家使用偏好键位。
-                let jump_pressed = is_key_pressed(KeyCode::Space)
-                    || is_key_pressed(KeyCode::Up)
-                    || is_key_pressed(KeyCode::W);
+impl InputSource for KeyboardInput {
+    fn poll(&mut self, world: &World) -> InputFrame {
+        let pressed_keys: Vec<String> = get_keys_pressed().into_iter().map(key_name).collect();
+        let held_keys: Vec<String> = get_keys_down().into_iter().map(key_name).collect();
+        let pressed: Vec<&str> = pressed_keys.iter().map(String::as_str).collect();
+        let held: Vec<&str> = held_keys.iter().map(String::as_str).collect();
+        world.settings.controls.frame(&pressed, &held)
+    }
+}
 
-                if jump_pressed {
-                    if p.on_ground(ground) {
-                        p.vel.y = -820.0;
-                        p.action = PlayerAction::Jump;
-                        p.action_timer = 0.32;
-                        p.can_double_jump = true;
-                        let origin = p.pos + vec2(PLAYER_SIZE.x * 0.5, PLAYER_SIZE.y);
-                        dust_events.push((origin, 8));
-                    } else if p.can_double_jump {
-                        p.vel.y = -780.0;
-                        p.action = PlayerAction::DoubleJump;
-                        p.action_timer = 0.28;
-                        p.can_double_jump = false;
-                        let origin = p.pos + vec2(PLAYER_SIZE.x * 0.5, PLAYER_SIZE.y * 0.4);
-                        dust_events.push((origin, 6));
-                    }
-                }
+/// 按键在设置文件与按键设置页中使用的名称，即 macroquad 按键码的变体名。
+pub fn key_name(code: KeyCode) -> String {
+    format!("{code:?}")
+}
 
-                if is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift) {
-                    if p.stamina > 0.0 {
-                        p.vel.x = DASH_SPEED;
-                        p.stamina = (p.stamina - STAMINA_CONSUME_RATE * dt).max(0.0);
-                        p.action = PlayerAction::Dash;
-                        p.action_timer = 0.2;
-                    }
-                } else {
-                    p.stamina = (p.stamina + STAMINA_RECOVER_RATE * dt).min(MAX_STAMINA);
-                    p.vel.x = 0.0;
-                }
+/// 本帧新按下的键盘按键与手柄输入名，用于不进入 [`InputFrame`] 的界面动作：
+/// 清除按键、观看录像与回放控制。
+pub struct PressedKeys(Vec<String>);
 
-                if (is_key_down(KeyCode::Down) || is_key_down(KeyCode::S)) && p.on_ground(ground) {
-                    p.action = PlayerAction::Slide;
-                    p.action_timer = 0.4;
-                }
-            }
+impl PressedKeys {
+    /// 读取本帧的键盘按键，并合并手柄新按下的输入名 `pad`。
+    pub fn poll(pad: &[&str]) -> Self {
+        let mut names: Vec<String> = get_keys_pressed().into_iter().map(key_name).collect();
+        names.extend(pad.iter().map(|name| name.to_string()));
+        Self(names)
+    }
 
-            for (origin, count) in dust_events {
-                spawn_dust(&mut world.particles, origin, count);
-            }
-        }
-        GameState::Paused => {
-            if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
-                world.state = GameState::Running;
-            }
-        }
-        GameState::GameOver { cooldown } => {
-            if cooldown <= 0.0 {
-                if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
-                    world.reset_run();
-                    world.state = GameState::Running;
-                }
-                if is_key_pressed(KeyCode::Escape) {
-                    world.state = GameState::Menu { fade: 0.0 };
-                }
-            }
-        }
+    /// 本帧是否按下了 `bindings` 中绑定到 `action` 的键。
+    pub fn hit(&self, bindings: &Bindings, action: Action) -> bool {
+        let names: Vec<&str> = self.0.iter().map(String::as_str).collect();
+        bindings.hit(action, &names)
     }
 }
@@ -1,44 +1,403 @@
 //! 游戏程序入口，负责初始化资源、驱动主循环并协调输入、逻辑与渲染模块。
 
-mod collectibles;
-mod constants;
+mod audio;
+mod camera;
+mod draw;
+mod gamepad;
 mod input;
-mod obstacles;
-mod particles;
-mod player;
 mod render;
+mod replay;
 mod types;
-mod update;
 mod utils;
-mod world;
 
-use constants::*;
-use input::handle_input;
+use dinorun_core::animation::AnimationSet;
+use dinorun_core::audio::AudioDirector;
+use dinorun_core::bindings::Action;
+use dinorun_core::bot::{Bot, Distribution, play_run};
+use dinorun_core::constants::*;
+use dinorun_core::gamepad::GamepadInput;
+use dinorun_core::input::{InputFrame, InputSource};
+use dinorun_core::patterns::{PATTERNS_PATH, PatternLibrary};
+use dinorun_core::replay::{REPLAY_EXTENSION, Replay, ReplayRecorder};
+use dinorun_core::save::{SaveData, SaveStore};
+use dinorun_core::settings::{Settings, SettingsStore};
+use dinorun_core::timestep::FixedTimestep;
+use dinorun_core::tuning::{TUNING_PATH, Tuning, TuningError, TuningWatcher};
+use dinorun_core::types::GameState;
+use dinorun_core::update::step_world;
+use dinorun_core::world::World;
+use draw::SpriteSheet;
+use input::{KeyboardInput, PressedKeys, key_name};
+use macroquad::miniquad::conf::Platform;
 use macroquad::prelude::*;
-use render::draw_world;
-use update::update_world;
-use world::World;
+use render::{RenderContext, draw_world};
+use replay::ReplayViewer;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 录像的保存目录（相对于工作目录）。
+const REPLAY_DIR: &str = "replays";
+/// `--bot-report` 中每局的存活时间上限（秒）。
+const BOT_REPORT_MAX_TIME: f32 = 300.0;
 
 #[macroquad::main(window_conf)]
 async fn main() {
-    // 加载玩家贴图并使用最近邻采样保持像素风格。
-    let player_texture: Texture2D = load_texture("assets/player.png").await.unwrap();
-    player_texture.set_filter(FilterMode::Nearest);
+    // 命令行 `--bot-report <runs>` 只跑机器人统计，不进入游戏。
+    if let Some(runs) = arg_value("--bot-report") {
+        match runs.parse() {
+            Ok(runs) => bot_report(runs),
+            Err(err) => eprintln!("--bot-report {runs}: {err}"),
+        }
+        return;
+    }
+
+    // 加载精灵图集并使用最近邻采样保持像素风格；动画数据缺失时退回占位图形。
+    let texture: Texture2D = load_texture("assets/sprites.png").await.unwrap();
+    texture.set_filter(FilterMode::Nearest);
+    let animations =
+        AnimationSet::load(Path::new("assets/animations.toml")).unwrap_or_else(|err| {
+            eprintln!("assets/animations.toml: {err}");
+            AnimationSet::default()
+        });
+    let mut ctx = RenderContext::new(SpriteSheet {
+        texture,
+        animations,
+    });
+
+    // World 结构体承载游戏状态，贯穿整个生命周期；以启动时间作为初始种子。
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let mut world = World::new(seed);
 
-    // World 结构体承载游戏状态，贯穿整个生命周期。
-    let mut world = World::new(player_texture);
+    // 读取持久化进度；坏档会被备份，并在菜单中提示玩家。
+    let store = SaveStore::open_default();
+    let loaded = store.load();
+    loaded.data.apply(&mut world);
+    if let Some(warning) = loaded.warning {
+        eprintln!("{warning}");
+        ctx.notice = Some(warning);
+    }
+    let mut saved = SaveData::capture(&world);
+
+    // 读取障碍图案；无效时沿用内置图案库并在菜单中提示。
+    match PatternLibrary::load(Path::new(PATTERNS_PATH)) {
+        Ok(patterns) => world.patterns = patterns,
+        Err(err) => {
+            let warning = format!("{PATTERNS_PATH}: {err}; using built-in patterns");
+            eprintln!("{warning}");
+            ctx.notice.get_or_insert(warning);
+        }
+    }
+    prevent_quit();
+
+    // 读取玩家设置；首次启动时写出默认设置，方便玩家手动修改。
+    let settings_store = SettingsStore::open_default();
+    world.settings = load_settings(&settings_store, &mut ctx);
+    let mut saved_settings = world.settings.clone();
+    let mut fullscreen = world.settings.fullscreen;
+    let mut audio_director = AudioDirector::default();
+    let mut audio_backend = audio::load_backend().await;
+
+    // 读取调参文件，之后每帧检查修改时间以便热重载。
+    let mut tuning_watcher = TuningWatcher::new(PathBuf::from(TUNING_PATH));
+    apply_tuning(
+        Tuning::load(tuning_watcher.path()),
+        &mut world,
+        &mut ctx,
+        tuning_watcher.path(),
+    );
+
+    let mut input_source = KeyboardInput;
+    let mut gamepad_input = GamepadInput::new(gamepad::load_backend());
+    let mut bot = Bot;
+    let mut clock = FixedTimestep::default();
+    let mut pending_input = InputFrame::default();
+    let mut recorder = ReplayRecorder::default();
+    let mut last_replay: Option<Replay> = None;
+
+    // 命令行 `--replay <file>` 直接进入回放模式。
+    let mut viewer = replay_arg().and_then(|path| match Replay::load(&path) {
+        Ok(replay) => Some(ReplayViewer::new(replay, world.settings.clone())),
+        Err(err) => {
+            eprintln!("{}: {err}", path.display());
+            None
+        }
+    });
+
+    // 命令行 `--attract` 启动后直接进入演示模式。
+    if std::env::args().any(|arg| arg == "--attract") {
+        world.start_autoplay();
+    }
 
-    // 主循环：处理输入、更新逻辑与渲染输出，随后等待下一帧。
+    // 主循环：采集输入后以固定步长推进模拟，渲染时在两步之间插值，随后等待下一帧。
     loop {
-        let dt = get_frame_time();
-        handle_input(&mut world, dt);
-        update_world(&mut world, dt);
-        draw_world(&world);
+        if is_quit_requested() {
+            // 对局中途关闭窗口同样算作放弃本局，距离照常计入最佳成绩。
+            if world.in_run() {
+                world.record_run();
+            }
+            persist_progress(&store, &world, &mut saved);
+            persist_settings(&settings_store, &world, &mut saved_settings);
+            break;
+        }
+
+        if let Some(result) = tuning_watcher.poll() {
+            apply_tuning(result, &mut world, &mut ctx, tuning_watcher.path());
+        }
+
+        // 手柄每帧都要读取以跟踪插拔与按下沿，回放期间也不例外。
+        let pad = gamepad_input.poll(&world);
+        let pressed = PressedKeys::poll(gamepad_input.pressed());
+
+        if let Some(active) = viewer.as_mut() {
+            if active.update(get_frame_time(), &pressed) {
+                active.draw(&ctx);
+            } else {
+                viewer = None;
+                pending_input = InputFrame::default();
+            }
+            next_frame().await;
+            continue;
+        }
+
+        // 键盘与手柄经同一份按键绑定合并为一帧输入。
+        // 按键设置页等待改绑时，按下的键或按钮交给设置页而不作为输入。
+        let keys = if world.controls_page.capturing {
+            capture_binding(&mut world, gamepad_input.last_pressed());
+            InputFrame::default()
+        } else {
+            if world.state == GameState::Controls
+                && pressed.hit(&world.settings.controls, Action::ClearKey)
+            {
+                world.controls_page.clear(&mut world.settings.controls);
+            }
+            input_source.poll(&world).merge(&pad)
+        };
+
+        // 演示模式下任意按键都会退出到主菜单，这一帧的按键不再传给菜单。
+        if world.autoplay && keys != InputFrame::default() {
+            world.stop_autoplay();
+        } else {
+            pending_input.accumulate(&keys);
+        }
+        for _ in 0..clock.advance(get_frame_time()) {
+            let was_in_run = world.in_run();
+            // 机器人每个模拟步都重新观察局面，与 `play_run` 的统计口径一致。
+            let input = if world.autoplay {
+                bot.poll(&world)
+            } else {
+                pending_input
+            };
+            step_world(&mut world, &input, clock.step_dt());
+            audio_director.on_step(&world, audio_backend.as_mut());
+            ctx.shake.observe(&world);
+            // 演示对局的录像不保存，也不覆盖可回看的上一局。
+            if let Some(replay) = recorder.observe(was_in_run, &input, &world)
+                && !world.autoplay
+            {
+                save_replay(&replay, "last");
+                if world.is_new_best() {
+                    save_replay(&replay, "best");
+                }
+                last_replay = Some(replay);
+            }
+            pending_input.clear_pressed();
+        }
+
+        // 对局之外（结算、菜单重置等）进度一旦变化立即落盘，对局中途不写文件。
+        if !world.in_run() {
+            persist_progress(&store, &world, &mut saved);
+        }
+
+        // 设置修改后立即生效，离开设置页时写入文件。
+        if world.settings.fullscreen != fullscreen {
+            fullscreen = world.settings.fullscreen;
+            set_fullscreen(fullscreen);
+        }
+        if !matches!(world.state, GameState::Settings | GameState::Controls) {
+            persist_settings(&settings_store, &world, &mut saved_settings);
+        }
+
+        if let GameState::GameOver { cooldown } = world.state
+            && cooldown <= 0.0
+            && pressed.hit(&world.settings.controls, Action::WatchReplay)
+        {
+            viewer = last_replay
+                .clone()
+                .map(|replay| ReplayViewer::new(replay, world.settings.clone()));
+        }
+
+        audio_director.update(&world, get_frame_time(), audio_backend.as_mut());
+        ctx.shake.update(get_frame_time());
+        draw_world(&world, &ctx, clock.alpha());
         next_frame().await;
     }
 }
 
+/// 设置与上次写入不同时保存，失败时只打印警告。
+fn persist_settings(store: &SettingsStore, world: &World, saved: &mut Settings) {
+    if world.settings == *saved {
+        return;
+    }
+    match store.save(&world.settings) {
+        Ok(()) => *saved = world.settings.clone(),
+        Err(err) => eprintln!("failed to write {}: {err}", store.path().display()),
+    }
+}
+
+/// 等待改绑时读取本帧按下的键或手柄按钮 `pad`：Esc 取消，其他输入绑定到选中的位置。
+fn capture_binding(world: &mut World, pad: Option<&str>) {
+    let name = match get_last_key_pressed() {
+        Some(KeyCode::Escape) => {
+            world.controls_page.cancel();
+            return;
+        }
+        Some(KeyCode::Unknown) | None => match pad {
+            Some(pad) => pad.to_string(),
+            None => return,
+        },
+        Some(code) => key_name(code),
+    };
+    world
+        .controls_page
+        .capture(&mut world.settings.controls, &name);
+}
+
+/// 读取设置；文件缺失时写出默认值，损坏时使用默认值并在菜单中提示。
+/// 手动编辑造成的按键冲突只提示，不自动修正。
+fn load_settings(store: &SettingsStore, ctx: &mut RenderContext) -> Settings {
+    match store.load() {
+        Ok(Some(settings)) => {
+            if let Some(conflict) = settings.controls.conflicts().first() {
+                let warning = format!(
+                    "{}: {} is bound to both {:?} and {:?}",
+                    store.path().display(),
+                    conflict.key,
+                    conflict.first,
+                    conflict.second
+                );
+                eprintln!("{warning}");
+                ctx.notice.get_or_insert(warning);
+            }
+            settings
+        }
+        Ok(None) => {
+            let settings = Settings::default();
+            if let Err(err) = store.save(&settings) {
+                eprintln!("failed to write {}: {err}", store.path().display());
+            }
+            settings
+        }
+        Err(err) => {
+            let warning = format!("{}: {err}; using default settings", store.path().display());
+            eprintln!("{warning}");
+            ctx.notice.get_or_insert(warning);
+            Settings::default()
+        }
+    }
+}
+
+/// 进度与上次写入不同时保存存档，失败时只打印警告。
+fn persist_progress(store: &SaveStore, world: &World, saved: &mut SaveData) {
+    let current = SaveData::capture(world);
+    if current == *saved {
+        return;
+    }
+    match store.save(&current) {
+        Ok(()) => *saved = current,
+        Err(err) => eprintln!("failed to write {}: {err}", store.path().display()),
+    }
+}
+
+/// 应用新读取的调参；失败时保留当前调参，并在画面上显示错误。
+fn apply_tuning(
+    result: Result<Tuning, TuningError>,
+    world: &mut World,
+    ctx: &mut RenderContext,
+    path: &Path,
+) {
+    match result {
+        Ok(tuning) => {
+            world.set_tuning(tuning);
+            ctx.tuning_error = None;
+        }
+        Err(err) => {
+            eprintln!("{}: {err}", path.display());
+            ctx.tuning_error = Some(format!("{}: {err}", path.display()));
+        }
+    }
+}
+
+/// 读取 `--replay <file>` 命令行参数。
+fn replay_arg() -> Option<PathBuf> {
+    arg_value("--replay").map(PathBuf::from)
+}
+
+/// 读取形如 `<name> <value>` 的命令行参数。
+fn arg_value(name: &str) -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == name {
+            return args.next();
+        }
+    }
+    None
+}
+
+/// 以当前调参与图案、无升级的玩家让机器人依次游玩种子 `0..runs`，打印存活时间与得分的分布。
+fn bot_report(runs: u64) {
+    let mut world = World::new(0);
+    match PatternLibrary::load(Path::new(PATTERNS_PATH)) {
+        Ok(patterns) => world.patterns = patterns,
+        Err(err) => eprintln!("{PATTERNS_PATH}: {err}; using built-in patterns"),
+    }
+    match Tuning::load(Path::new(TUNING_PATH)) {
+        Ok(tuning) => world.set_tuning(tuning),
+        Err(err) => eprintln!("{TUNING_PATH}: {err}; using default tuning"),
+    }
+
+    let results: Vec<_> = (0..runs)
+        .map(|seed| play_run(&mut world, seed, BOT_REPORT_MAX_TIME))
+        .collect();
+    let times: Vec<f32> = results.iter().map(|run| run.stats.survival_time).collect();
+    let scores: Vec<f32> = results.iter().map(|run| run.stats.distance).collect();
+    let (Some(times), Some(scores)) = (
+        Distribution::from_samples(&times),
+        Distribution::from_samples(&scores),
+    ) else {
+        println!("no runs");
+        return;
+    };
+    let capped = results
+        .iter()
+        .filter(|run| run.stats.survival_time >= BOT_REPORT_MAX_TIME)
+        .count();
+    println!(
+        "bot report: {} runs, capped at {BOT_REPORT_MAX_TIME}s",
+        times.count
+    );
+    println!("survival (s): {times}");
+    println!("score (m):    {scores}");
+    println!("reached cap:  {capped}");
+}
+
+/// 把录像写入录像目录，失败时只打印警告，不影响游戏继续。
+fn save_replay(replay: &Replay, name: &str) {
+    let path = Path::new(REPLAY_DIR).join(format!("{name}.{REPLAY_EXTENSION}"));
+    if let Err(err) = replay.save(&path) {
+        eprintln!("failed to save replay {}: {err}", path.display());
+    }
+}
+
 fn window_conf() -> Conf {
+    // 全屏与垂直同步在创建窗口时就要确定，这里先读一次设置文件；出错时由主循环提示。
+    let settings = SettingsStore::open_default()
+        .load()
+        .ok()
+        .flatten()
+        .unwrap_or_default();
     Conf {
         // 配置窗口标题与尺寸，确保与常量定义保持一致。
         window_title: "Neon Run".to_string(),
@@ -46,6 +405,12 @@ fn window_conf() -> Conf {
         window_height: SCREEN_HEIGHT as i32,
         high_dpi: true,
         sample_count: 4,
+        fullscreen: settings.fullscreen,
+        // 交换间隔只在创建窗口时读取，菜单中的垂直同步因此标注为需重启。
+        platform: Platform {
+            swap_interval: Some(settings.vsync as i32),
+            ..Default::default()
+        },
         ..Default::default()
     }
 }
@@ -1,23 +1,134 @@
-use crate::collectibles::draw_collectibles;
-use crate::constants::*;
-use crate::obstacles::draw_obstacles;
-use crate::particles::{draw_floating_texts, draw_particles};
-use crate::player::draw_player;
-use crate::types::{ColorLerp, GameState, PowerUpKind};
+use crate::camera::{ScreenShake, screen_camera};
+use crate::draw::{
+    SpriteSheet, draw_collectibles, draw_floating_texts, draw_obstacles, draw_particles,
+    draw_player, draw_power_ups,
+};
+use crate::types::{ColorLerp, Palette, ParallaxLayer};
 use crate::utils::{draw_text_centered, ease_out_quad};
-use crate::world::World;
+use dinorun_core::achievements::ACHIEVEMENTS;
+use dinorun_core::bindings::{Action, ControlsItem, MAX_KEYS_PER_ACTION, Rebind};
+use dinorun_core::constants::*;
+use dinorun_core::locale::{Language, Text};
+use dinorun_core::menu::{MainMenuItem, PauseMenuItem, SettingsItem, Widget};
+use dinorun_core::shop::UpgradeKind;
+use dinorun_core::stats::RunStats;
+use dinorun_core::types::{GameState, PowerUpKind};
+use dinorun_core::world::World;
 use macroquad::prelude::*;
 
+/// 渲染层持有的资源与纯表现数据，不参与模拟。
+pub struct RenderContext {
+    pub sprites: SpriteSheet,
+    pub parallax: Vec<ParallaxLayer>,
+    /// 需要在菜单中提示玩家的信息（例如存档损坏已备份）。
+    pub notice: Option<String>,
+    /// 调参文件无效时的错误信息，修正前一直显示在画面顶部。
+    pub tuning_error: Option<String>,
+    pub shake: ScreenShake,
+}
+
+impl RenderContext {
+    /// 使用已加载的精灵图集构建渲染上下文，并初始化视差背景层。
+    pub fn new(sprites: SpriteSheet) -> Self {
+        Self {
+            sprites,
+            parallax: vec![
+                ParallaxLayer {
+                    height: BASE_GROUND_Y + 90.0,
+                    speed: 32.0,
+                    color: Color::from_rgba(44, 62, 105, 255),
+                    thickness: 120.0,
+                },
+                ParallaxLayer {
+                    height: BASE_GROUND_Y + 60.0,
+                    speed: 48.0,
+                    color: Color::from_rgba(63, 83, 141, 255),
+                    thickness: 76.0,
+                },
+                ParallaxLayer {
+                    height: BASE_GROUND_Y + 32.0,
+                    speed: 68.0,
+                    color: Color::from_rgba(84, 112, 174, 255),
+                    thickness: 48.0,
+                },
+            ],
+            notice: None,
+            tuning_error: None,
+            shake: ScreenShake::default(),
+        }
+    }
+}
+
 // 渲染完整世界：依次绘制背景、实体、特效与 UI。
-pub fn draw_world(world: &World) {
+// `alpha` 为固定步长之间的插值比例，实体位置在上一步与当前步之间平滑过渡。
+// 只有场景随屏幕震动偏移，UI 保持不动。
+pub fn draw_world(world: &World, ctx: &RenderContext, alpha: f32) {
+    set_camera(&screen_camera(
+        ctx.shake.offset(world.settings.screen_shake),
+    ));
+    draw_scene(world, ctx, alpha);
+    set_camera(&screen_camera(Vec2::ZERO));
+    draw_ui(world);
+    if let (Some(notice), GameState::Menu { .. }) = (&ctx.notice, world.state) {
+        draw_text_centered(
+            notice,
+            SCREEN_WIDTH * 0.5,
+            SCREEN_HEIGHT - 24.0,
+            20.0,
+            Color::from_rgba(255, 170, 120, 230),
+        );
+    }
+    draw_tuning_error(ctx);
+    if world.settings.show_fps {
+        draw_text(
+            &format!("FPS {}", get_fps()),
+            SCREEN_WIDTH - 96.0,
+            SCREEN_HEIGHT - 12.0,
+            22.0,
+            Color::from_rgba(200, 255, 200, 220),
+        );
+    }
+}
+
+/// 在画面顶部显示调参错误，逐行绘制以保留解析器给出的定位信息。
+pub fn draw_tuning_error(ctx: &RenderContext) {
+    let Some(error) = &ctx.tuning_error else {
+        return;
+    };
+    let lines: Vec<&str> = error.lines().collect();
+    let height = 36.0 + lines.len() as f32 * 22.0;
+    draw_rectangle(
+        16.0,
+        16.0,
+        SCREEN_WIDTH - 32.0,
+        height,
+        Color::from_rgba(120, 20, 30, 230),
+    );
+    draw_text("Tuning error", 28.0, 40.0, 24.0, WHITE);
+    for (idx, line) in lines.iter().enumerate() {
+        draw_text(
+            line,
+            28.0,
+            64.0 + idx as f32 * 22.0,
+            20.0,
+            Color::from_rgba(255, 220, 220, 255),
+        );
+    }
+}
+
+// 只绘制场景（背景、实体与粒子），不含状态相关的 UI，供录像回放复用。
+pub fn draw_scene(world: &World, ctx: &RenderContext, alpha: f32) {
     clear_background(day_night_color(world.day_phase));
-    draw_parallax(world);
+    draw_parallax(world, ctx);
     draw_ground(world);
-    draw_player(&world.player, &world.player_texture);
+    draw_player(&world.player, &ctx.sprites, alpha);
 
     // Draw shield effect around player if active
     if world.power_up.active && world.power_up.kind == PowerUpKind::Shield {
-        let rect = world.player.rect();
+        let mut rect = world.player.rect();
+        let render_pos = world.player.interpolated_pos(alpha);
+        rect.x += render_pos.x - world.player.pos.x;
+        rect.y += render_pos.y - world.player.pos.y;
         let pad = (get_time() as f32).sin() * 4.0 + 12.0;
         draw_rectangle_lines(
             rect.x - pad * 0.5,
@@ -29,11 +140,13 @@ pub fn draw_world(world: &World) {
         );
     }
 
-    draw_obstacles(&world.obstacles);
-    draw_collectibles(&world.collectibles);
-    draw_particles(&world.particles);
-    draw_ui(world);
+    draw_obstacles(&world.obstacles, &ctx.sprites, alpha);
+    let palette = Palette::for_mode(world.settings.colorblind);
+    draw_collectibles(&world.collectibles, alpha, palette);
+    draw_power_ups(&world.power_ups, alpha, palette);
+    draw_particles(&world.particles, world.settings.particle_density);
 }
+
 // 根据昼夜相位返回背景颜色。
 fn day_night_color(t: f32) -> Color {
     let palette = [
@@ -65,9 +178,10 @@ fn day_night_color(t: f32) -> Color {
 }
 
 // 绘制视差背景层，强化速度感。
-fn draw_parallax(world: &World) {
-    for layer in &world.parallax {
-        let speed = layer.speed * world.difficulty.scroll_speed() / BASE_SCROLL_SPEED;
+fn draw_parallax(world: &World, ctx: &RenderContext) {
+    for layer in &ctx.parallax {
+        let speed = layer.speed * world.difficulty.scroll_speed(&world.tuning)
+            / world.tuning.scroll.base_speed;
         let offset = (get_time() as f32 * speed) % SCREEN_WIDTH;
         for i in -1..=2 {
             draw_rectangle(
@@ -83,7 +197,7 @@ fn draw_parallax(world: &World) {
 
 // 绘制地面与高光。
 fn draw_ground(world: &World) {
-    let ground_y = world.difficulty.ground_y();
+    let ground_y = world.difficulty.ground_y(&world.tuning);
     draw_rectangle(
         0.0,
         ground_y,
@@ -111,24 +225,44 @@ fn draw_ground(world: &World) {
 
 // 根据 GameState 切换不同 UI 场景。
 fn draw_ui(world: &World) {
+    let language = world.settings.language;
     match world.state {
-        GameState::Splash { timer } => draw_splash(timer),
+        GameState::Splash { timer } => draw_splash(timer, language),
         GameState::Menu { fade } => draw_menu(world, fade),
+        GameState::Shop => draw_shop(world),
+        GameState::Achievements => draw_achievements(world),
+        GameState::Settings => draw_settings(world),
+        GameState::Controls => draw_controls(world),
         GameState::Running => draw_hud(world, 1.0),
         GameState::Paused => {
             draw_hud(world, 0.4);
-            draw_pause(world.pause_flash);
+            draw_pause(world);
         }
         GameState::GameOver { cooldown } => {
             draw_hud(world, 0.4);
             draw_game_over(world, cooldown);
         }
     }
+    if world.autoplay {
+        draw_attract_banner(language);
+    }
     draw_floating_texts(&world.floating_texts);
 }
 
+// 演示模式提示，闪烁提醒玩家按任意键接管。
+fn draw_attract_banner(language: Language) {
+    let pulse = ((get_time() as f32 * 3.0).sin() + 1.0) * 0.5;
+    draw_text_centered(
+        Text::AttractBanner.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT - 48.0,
+        32.0,
+        Color::new(1.0, 1.0, 1.0, 0.5 + pulse * 0.5),
+    );
+}
+
 // 闪屏界面的淡入文案。
-fn draw_splash(timer: f32) {
+fn draw_splash(timer: f32, language: Language) {
     let t = (timer / 2.0).min(1.0);
     let alpha = (ease_out_quad(t) * 255.0) as u8;
     draw_text_centered(
@@ -140,7 +274,7 @@ fn draw_splash(timer: f32) {
     );
     let sub_alpha = (ease_out_quad((timer - 0.8).max(0.0) / 1.2) * 255.0) as u8;
     draw_text_centered(
-        "Press Space",
+        Text::PressSpace.get(language),
         SCREEN_WIDTH * 0.5,
         SCREEN_HEIGHT * 0.6,
         36.0,
@@ -150,6 +284,7 @@ fn draw_splash(timer: f32) {
 
 // 主菜单选项与提示文字。
 fn draw_menu(world: &World, fade: f32) {
+    let language = world.settings.language;
     let alpha = (fade * 255.0) as u8;
     draw_text_centered(
         "DINO RUN",
@@ -158,83 +293,478 @@ fn draw_menu(world: &World, fade: f32) {
         72.0,
         Color::from_rgba(255, 255, 255, alpha),
     );
-    let options = ["Start Run", "Instant Shield", "Reset Progress"];
-    for (idx, option) in options.iter().enumerate() {
-        let y = SCREEN_HEIGHT * 0.45 + idx as f32 * 46.0;
-        let selected = idx == world.menu_selected;
-        let color = if selected {
-            Color::from_rgba(255, 200, 90, alpha)
+    for (idx, item) in MainMenuItem::ALL.iter().enumerate() {
+        let y = SCREEN_HEIGHT * 0.38 + idx as f32 * 36.0;
+        let color = menu_color(idx == world.main_menu.selected, alpha);
+        draw_text_centered(
+            item.label().get(language),
+            SCREEN_WIDTH * 0.5,
+            y,
+            32.0,
+            color,
+        );
+    }
+    draw_text_centered(
+        &format!(
+            "{}: {:0.0}m",
+            Text::BestDistance.get(language),
+            world.best_score
+        ),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.8,
+        28.0,
+        Color::from_rgba(200, 220, 255, alpha),
+    );
+}
+
+/// 提示文字中展示的按键：动作绑定的第一个键。
+pub fn first_key(world: &World, action: Action) -> &str {
+    world
+        .settings
+        .controls
+        .keys(action)
+        .first()
+        .map_or("?", String::as_str)
+}
+
+/// 菜单条目的文字颜色：选中项高亮。
+fn menu_color(selected: bool, alpha: u8) -> Color {
+    if selected {
+        Color::from_rgba(255, 200, 90, alpha)
+    } else {
+        Color::from_rgba(200, 200, 210, alpha)
+    }
+}
+
+/// 设置页的标签列、控件列位置与滑块宽度。
+const SETTINGS_LABEL_X: f32 = SCREEN_WIDTH * 0.2;
+const SETTINGS_VALUE_X: f32 = SCREEN_WIDTH * 0.58;
+const SLIDER_WIDTH: f32 = 220.0;
+
+/// 在 `x` 处绘制菜单条目的控件；按钮没有取值，不绘制。`y` 为文字基线。
+fn draw_widget(widget: Widget, x: f32, y: f32, color: Color, language: Language) {
+    match widget {
+        Widget::Button => {}
+        Widget::Toggle(on) => {
+            let text = if on { Text::On } else { Text::Off };
+            draw_text(text.get(language), x, y, 28.0, color);
+        }
+        Widget::Slider(value) => {
+            let top = y - 16.0;
+            draw_rectangle(x, top, SLIDER_WIDTH, 14.0, Color::from_rgba(0, 0, 0, 120));
+            draw_rectangle(x, top, SLIDER_WIDTH * value, 14.0, color);
+            draw_rectangle_lines(x, top, SLIDER_WIDTH, 14.0, 2.0, color);
+            draw_text(
+                &format!("{:0.0}%", value * 100.0),
+                x + SLIDER_WIDTH + 16.0,
+                y,
+                26.0,
+                color,
+            );
+        }
+        Widget::Choice(text) => {
+            draw_text(&format!("< {text} >"), x, y, 28.0, color);
+        }
+    }
+}
+
+// 设置页：每项左侧为名称，右侧为开关、滑块或选项。除垂直同步需重启外，修改即时生效。
+fn draw_settings(world: &World) {
+    let language = world.settings.language;
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        Color::from_rgba(10, 16, 30, 160),
+    );
+    draw_text_centered(
+        Text::SettingsTitle.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.14,
+        64.0,
+        WHITE,
+    );
+    for (idx, item) in SettingsItem::ALL.iter().enumerate() {
+        let y = SCREEN_HEIGHT * 0.25 + idx as f32 * 34.0;
+        let color = menu_color(idx == world.settings_menu.selected, 255);
+        draw_text(item.label().get(language), SETTINGS_LABEL_X, y, 30.0, color);
+        draw_widget(
+            item.widget(&world.settings),
+            SETTINGS_VALUE_X,
+            y,
+            color,
+            language,
+        );
+    }
+    draw_text_centered(
+        Text::SettingsHint.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.88,
+        24.0,
+        Color::from_rgba(180, 180, 195, 220),
+    );
+}
+
+/// 按键设置页的标签列与第一个按键位置，以及按键位置的间距。
+const CONTROLS_LABEL_X: f32 = SCREEN_WIDTH * 0.08;
+const CONTROLS_SLOT_X: f32 = SCREEN_WIDTH * 0.36;
+const CONTROLS_SLOT_WIDTH: f32 = 160.0;
+/// 按键设置页一屏显示的条目数，超出时随选中项滚动。
+const CONTROLS_VISIBLE_ROWS: usize = 10;
+
+// 按键设置页：每个动作一行，右侧为各按键位置；同一场景下冲突的动作标红。
+fn draw_controls(world: &World) {
+    let language = world.settings.language;
+    let page = &world.controls_page;
+    let bindings = &world.settings.controls;
+    let conflicts = bindings.conflicts();
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        Color::from_rgba(10, 16, 30, 160),
+    );
+    draw_text_centered(
+        Text::ControlsTitle.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.14,
+        64.0,
+        WHITE,
+    );
+    // 选中项尽量保持在可见范围中部。
+    let first = page
+        .cursor
+        .selected
+        .saturating_sub(CONTROLS_VISIBLE_ROWS / 2)
+        .min(ControlsItem::ALL.len() - CONTROLS_VISIBLE_ROWS);
+    let rows = first..first + CONTROLS_VISIBLE_ROWS;
+    for (idx, item) in ControlsItem::ALL.iter().enumerate() {
+        if !rows.contains(&idx) {
+            continue;
+        }
+        let y = SCREEN_HEIGHT * 0.24 + (idx - first) as f32 * 34.0;
+        let selected = idx == page.cursor.selected;
+        let ControlsItem::Action(action) = *item else {
+            draw_text(
+                item.label().get(language),
+                CONTROLS_LABEL_X,
+                y,
+                30.0,
+                menu_color(selected, 255),
+            );
+            continue;
+        };
+        let conflicted = conflicts
+            .iter()
+            .any(|conflict| conflict.first == action || conflict.second == action);
+        let label_color = if conflicted {
+            Color::from_rgba(255, 110, 110, 255)
+        } else {
+            menu_color(selected, 255)
+        };
+        draw_text(
+            item.label().get(language),
+            CONTROLS_LABEL_X,
+            y,
+            30.0,
+            label_color,
+        );
+        let keys = bindings.keys(action);
+        for slot in 0..MAX_KEYS_PER_ACTION {
+            let x = CONTROLS_SLOT_X + slot as f32 * CONTROLS_SLOT_WIDTH;
+            let current = selected && slot == page.slot;
+            let text = if current && page.capturing {
+                "..."
+            } else {
+                keys.get(slot).map_or("-", String::as_str)
+            };
+            if current {
+                draw_rectangle_lines(
+                    x - 8.0,
+                    y - 24.0,
+                    CONTROLS_SLOT_WIDTH - 12.0,
+                    32.0,
+                    2.0,
+                    menu_color(true, 255),
+                );
+            }
+            draw_text(text, x, y, 26.0, menu_color(current, 255));
+        }
+    }
+    // 上下还有未显示的条目时，在首末行左侧画出箭头。
+    let more = Color::from_rgba(180, 180, 195, 220);
+    if first > 0 {
+        draw_text(
+            "^",
+            CONTROLS_LABEL_X - 32.0,
+            SCREEN_HEIGHT * 0.24,
+            30.0,
+            more,
+        );
+    }
+    if rows.end < ControlsItem::ALL.len() {
+        let y = SCREEN_HEIGHT * 0.24 + (CONTROLS_VISIBLE_ROWS - 1) as f32 * 34.0;
+        draw_text("v", CONTROLS_LABEL_X - 32.0, y, 30.0, more);
+    }
+
+    let status = match (&page.last, page.selected()) {
+        (_, ControlsItem::Action(action)) if page.capturing => {
+            Some(Text::PressKey.format(language, &[action.label().get(language)]))
+        }
+        (Some((key, Rebind::Moved { from })), _) => {
+            Some(Text::KeyMoved.format(language, &[key, from.label().get(language)]))
+        }
+        (Some((key, Rebind::Refused { owner })), _) => {
+            Some(Text::KeyRefused.format(language, &[key, owner.label().get(language)]))
+        }
+        _ => None,
+    };
+    if let Some(status) = status {
+        draw_text_centered(
+            &status,
+            SCREEN_WIDTH * 0.5,
+            SCREEN_HEIGHT * 0.82,
+            26.0,
+            Color::from_rgba(255, 200, 120, 240),
+        );
+    }
+    draw_text_centered(
+        &Text::ControlsHint.format(
+            language,
+            &[
+                first_key(world, Action::Confirm),
+                first_key(world, Action::MenuLeft),
+                first_key(world, Action::MenuRight),
+                first_key(world, Action::ClearKey),
+                first_key(world, Action::Pause),
+            ],
+        ),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.9,
+        22.0,
+        Color::from_rgba(180, 180, 195, 220),
+    );
+}
+
+// 升级商店：列出各项等级与价格，底部显示选中项说明。
+fn draw_shop(world: &World) {
+    let language = world.settings.language;
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        Color::from_rgba(10, 16, 30, 160),
+    );
+    draw_text_centered(
+        Text::ShopTitle.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.18,
+        64.0,
+        WHITE,
+    );
+    draw_text_centered(
+        &format!("{}: {}", Text::Currency.get(language), world.currency),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.26,
+        30.0,
+        Color::from_rgba(140, 210, 255, 255),
+    );
+    for (idx, kind) in UpgradeKind::ALL.iter().enumerate() {
+        let y = SCREEN_HEIGHT * 0.36 + idx as f32 * 48.0;
+        let level = world.upgrades.level(*kind);
+        let cost = match world.upgrades.next_cost(*kind) {
+            Some(cost) => format!("{cost}"),
+            None => Text::Max.get(language).to_string(),
+        };
+        let affordable = world
+            .upgrades
+            .next_cost(*kind)
+            .is_some_and(|cost| cost <= world.currency);
+        let color = if idx == world.shop_menu.selected {
+            Color::from_rgba(255, 200, 90, 255)
+        } else if affordable {
+            Color::from_rgba(220, 220, 230, 255)
         } else {
-            Color::from_rgba(200, 200, 210, alpha)
+            Color::from_rgba(140, 140, 155, 255)
         };
-        draw_text_centered(option, SCREEN_WIDTH * 0.5, y, 36.0, color);
+        draw_text(kind.name(), SCREEN_WIDTH * 0.22, y, 34.0, color);
+        draw_text(
+            &format!("Lv {level}/{}", kind.max_level()),
+            SCREEN_WIDTH * 0.56,
+            y,
+            30.0,
+            color,
+        );
+        draw_text(&cost, SCREEN_WIDTH * 0.7, y, 30.0, color);
     }
+    let selected = UpgradeKind::ALL[world.shop_menu.selected];
     draw_text_centered(
-        &format!("Best Distance: {:0.0}m", world.best_score),
+        selected.description(),
         SCREEN_WIDTH * 0.5,
-        SCREEN_HEIGHT * 0.7,
+        SCREEN_HEIGHT * 0.8,
         28.0,
-        Color::from_rgba(200, 220, 255, alpha),
+        Color::from_rgba(200, 220, 255, 255),
+    );
+    draw_text_centered(
+        Text::ShopHint.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.88,
+        24.0,
+        Color::from_rgba(180, 180, 195, 220),
+    );
+}
+
+// 成就页面：列出全部成就，已解锁的高亮显示。
+fn draw_achievements(world: &World) {
+    let language = world.settings.language;
+    draw_rectangle(
+        0.0,
+        0.0,
+        SCREEN_WIDTH,
+        SCREEN_HEIGHT,
+        Color::from_rgba(10, 16, 30, 160),
+    );
+    draw_text_centered(
+        Text::AchievementsTitle.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.15,
+        64.0,
+        WHITE,
+    );
+    draw_text_centered(
+        &format!(
+            "{} / {} {}",
+            world.achievements.unlocked_count(),
+            ACHIEVEMENTS.len(),
+            Text::Unlocked.get(language)
+        ),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.22,
+        28.0,
+        Color::from_rgba(140, 210, 255, 255),
+    );
+    for (idx, achievement) in ACHIEVEMENTS.iter().enumerate() {
+        let y = SCREEN_HEIGHT * 0.31 + idx as f32 * 38.0;
+        let (mark, name_color, text_color) = if world.achievements.is_unlocked(achievement) {
+            (
+                "*",
+                Color::from_rgba(255, 214, 90, 255),
+                Color::from_rgba(220, 220, 230, 255),
+            )
+        } else {
+            (
+                "-",
+                Color::from_rgba(140, 140, 155, 255),
+                Color::from_rgba(120, 120, 135, 255),
+            )
+        };
+        draw_text(mark, SCREEN_WIDTH * 0.2, y, 30.0, name_color);
+        draw_text(
+            achievement.name.get(language),
+            SCREEN_WIDTH * 0.23,
+            y,
+            30.0,
+            name_color,
+        );
+        draw_text(
+            achievement.description.get(language),
+            SCREEN_WIDTH * 0.46,
+            y,
+            26.0,
+            text_color,
+        );
+    }
+    draw_text_centered(
+        Text::BackHint.get(language),
+        SCREEN_WIDTH * 0.5,
+        SCREEN_HEIGHT * 0.93,
+        24.0,
+        Color::from_rgba(180, 180, 195, 220),
     );
 }
 
 // 游戏 HUD，显示分数、连击与体力条。
-fn draw_hud(world: &World, alpha: f32) {
+pub fn draw_hud(world: &World, alpha: f32) {
+    let language = world.settings.language;
+    let palette = Palette::for_mode(world.settings.colorblind);
     let panel_color = Color::from_rgba(20, 36, 58, (alpha * 170.0) as u8);
     draw_rectangle(24.0, 24.0, 320.0, 148.0, panel_color);
+    // ScoreBoost 期间距离读数变为脉动的金色，并标出倍率。
+    let distance_color = if world.score_boost_active() {
+        let pulse = ((get_time() as f32 * 8.0).sin() + 1.0) * 0.5;
+        Color::new(1.0, 0.78 + pulse * 0.2, 0.25 + pulse * 0.35, 1.0)
+    } else {
+        WHITE
+    };
     draw_text(
-        &format!("Distance: {:0.0}m", world.score),
+        &format!("{}: {:0.0}m", Text::Distance.get(language), world.score),
         36.0,
         66.0,
         32.0,
-        WHITE,
+        distance_color,
     );
+    if world.score_boost_active() {
+        draw_text(
+            &format!("x{:0.0}", world.tuning.scoring.score_boost_mult),
+            292.0,
+            66.0,
+            32.0,
+            distance_color,
+        );
+    }
     draw_text(
-        &format!("Best: {:0.0}m", world.best_score),
+        &format!("{}: {:0.0}m", Text::Best.get(language), world.best_score),
         36.0,
         102.0,
         28.0,
         LIGHTGRAY,
     );
     draw_text(
-        &format!("Combo x{:0.2}", world.streak_mult),
+        &format!("{} x{:0.2}", Text::Combo.get(language), world.streak_mult),
         36.0,
         138.0,
         24.0,
         Color::from_rgba(255, 210, 110, 255),
     );
     draw_text(
-        &format!("Currency: {}", world.currency),
+        &format!("{}: {}", Text::Currency.get(language), world.currency),
         36.0,
         174.0,
         24.0,
         Color::from_rgba(140, 210, 255, 255),
     );
 
-    let stamina_ratio = world.player.stamina / MAX_STAMINA;
+    let stamina_ratio = world.player.stamina / world.player.max_stamina;
     draw_bar(
         Vec2::new(28.0, 196.0),
         Vec2::new(312.0, 12.0),
         stamina_ratio,
-        Color::from_rgba(80, 200, 255, 255),
+        palette.stamina,
         alpha,
     );
 
     if world.power_up.active {
         let text = match world.power_up.kind {
-            PowerUpKind::Shield => "Shield",
-            PowerUpKind::ScoreBoost => "Score Boost",
-            PowerUpKind::TimeSlow => "Time Slow",
+            PowerUpKind::Shield => Text::Shield,
+            PowerUpKind::ScoreBoost => Text::ScoreBoost,
+            PowerUpKind::TimeSlow => Text::TimeSlow,
+        };
+        let fill = match world.power_up.kind {
+            PowerUpKind::ScoreBoost => palette.score_boost,
+            _ => Color::from_rgba(255, 200, 120, 255),
         };
-        let ratio = (world.power_up.timer / POWERUP_DURATION).clamp(0.0, 1.0);
+        let ratio = (world.power_up.timer / world.power_up.duration).clamp(0.0, 1.0);
         draw_bar(
             Vec2::new(SCREEN_WIDTH - 360.0, 36.0),
             Vec2::new(320.0, 16.0),
             ratio,
-            Color::from_rgba(255, 200, 120, 255),
+            fill,
             alpha,
         );
-        draw_text(text, SCREEN_WIDTH - 348.0, 66.0, 28.0, WHITE);
+        draw_text(text.get(language), SCREEN_WIDTH - 348.0, 66.0, 28.0, WHITE);
     }
 }
 
@@ -264,61 +794,218 @@ fn draw_bar(pos: Vec2, size: Vec2, ratio: f32, fill: Color, alpha: f32) {
     );
 }
 
-// 暂停提示文本。
-fn draw_pause(flash: f32) {
-    let alpha = (flash * 220.0) as u8;
+// 暂停提示文本与暂停菜单。
+fn draw_pause(world: &World) {
+    let language = world.settings.language;
+    let alpha = (world.pause_flash * 220.0) as u8;
     draw_text_centered(
-        "PAUSED",
+        Text::Paused.get(language),
         SCREEN_WIDTH * 0.5,
         SCREEN_HEIGHT * 0.45,
         64.0,
         Color::from_rgba(255, 255, 255, alpha),
     );
-    draw_text_centered(
-        "Press Space to resume",
-        SCREEN_WIDTH * 0.5,
-        SCREEN_HEIGHT * 0.55,
-        32.0,
-        Color::from_rgba(220, 220, 220, alpha),
-    );
+    // 提示文字随暂停闪现后淡去，菜单条目始终可见。
+    for (idx, item) in PauseMenuItem::ALL.iter().enumerate() {
+        let color = menu_color(idx == world.pause_menu.selected, 255);
+        draw_text_centered(
+            item.label().get(language),
+            SCREEN_WIDTH * 0.5,
+            SCREEN_HEIGHT * 0.55 + idx as f32 * 36.0,
+            32.0,
+            color,
+        );
+    }
+}
+
+/// 结算统计表的列位置：标签右对齐处、本局与最佳两列的中心。
+const STAT_LABEL_X: f32 = SCREEN_WIDTH * 0.47;
+const STAT_RUN_X: f32 = SCREEN_WIDTH * 0.58;
+const STAT_BEST_X: f32 = SCREEN_WIDTH * 0.7;
+
+/// 结算统计表的一行：标签、取值方式与显示格式；所有数值都是越大越好。
+struct StatRow {
+    label: Text,
+    value: fn(&RunStats) -> f32,
+    format: fn(f32) -> String,
 }
 
-// 游戏结束界面展示成绩与提示。
+const STAT_ROWS: [StatRow; 10] = [
+    StatRow {
+        label: Text::Distance,
+        value: |s| s.distance,
+        format: |v| format!("{v:0.0}m"),
+    },
+    StatRow {
+        label: Text::Time,
+        value: |s| s.survival_time,
+        format: |v| format!("{v:0.1}s"),
+    },
+    StatRow {
+        label: Text::Coins,
+        value: |s| s.coins as f32,
+        format: |v| format!("{v}"),
+    },
+    StatRow {
+        label: Text::Gems,
+        value: |s| s.gems as f32,
+        format: |v| format!("{v}"),
+    },
+    StatRow {
+        label: Text::MaxCombo,
+        value: |s| s.max_combo as f32,
+        format: |v| format!("{v}"),
+    },
+    StatRow {
+        label: Text::BestStreak,
+        value: |s| s.max_streak,
+        format: |v| format!("x{v:0.2}"),
+    },
+    StatRow {
+        label: Text::Jumps,
+        value: |s| (s.jumps + s.air_jumps) as f32,
+        format: |v| format!("{v}"),
+    },
+    StatRow {
+        label: Text::DashTime,
+        value: |s| s.dash_time,
+        format: |v| format!("{v:0.1}s"),
+    },
+    StatRow {
+        label: Text::PowerUps,
+        value: |s| s.power_ups as f32,
+        format: |v| format!("{v}"),
+    },
+    StatRow {
+        label: Text::CurrencyEarned,
+        value: |s| s.currency_earned as f32,
+        format: |v| format!("{v}"),
+    },
+];
+
+// 游戏结束界面：本局统计与个人最佳的对比，以及操作提示。
 fn draw_game_over(world: &World, cooldown: f32) {
+    let language = world.settings.language;
+    let palette = Palette::for_mode(world.settings.colorblind);
     let alpha = ((1.0 - cooldown) * 255.0) as u8;
+    let fade = |r, g, b| Color::from_rgba(r, g, b, alpha);
+    draw_rectangle(
+        SCREEN_WIDTH * 0.2,
+        SCREEN_HEIGHT * 0.08,
+        SCREEN_WIDTH * 0.6,
+        SCREEN_HEIGHT * 0.86,
+        Color::from_rgba(10, 16, 30, (alpha as f32 * 0.63) as u8),
+    );
     draw_text_centered(
-        "RUN OVER",
+        Text::RunOver.get(language),
         SCREEN_WIDTH * 0.5,
-        SCREEN_HEIGHT * 0.4,
+        SCREEN_HEIGHT * 0.17,
         64.0,
-        Color::from_rgba(255, 160, 160, alpha),
+        fade(255, 160, 160),
     );
+    let stats = &world.stats;
+    let killed_by = stats
+        .killed_by
+        .map(|kind| format!("{}: {}", Text::KilledBy.get(language), kind.name()))
+        .unwrap_or_default();
+    let headline = if world.is_new_best() {
+        format!("{}   {killed_by}", Text::NewBest.get(language))
+    } else {
+        killed_by
+    };
     draw_text_centered(
-        &format!("Distance: {:0.0}m", world.score),
+        &headline,
         SCREEN_WIDTH * 0.5,
-        SCREEN_HEIGHT * 0.5,
-        32.0,
-        Color::from_rgba(255, 255, 255, alpha),
+        SCREEN_HEIGHT * 0.23,
+        28.0,
+        fade(255, 214, 90),
     );
+
+    // 本局与结算前最佳一局逐项对比，超过最佳的数值高亮。
+    let best = world.previous_best.as_ref();
+    let header_y = SCREEN_HEIGHT * 0.3;
+    let header = fade(160, 170, 190);
     draw_text_centered(
-        &format!("Currency gained: {}", world.currency),
-        SCREEN_WIDTH * 0.5,
-        SCREEN_HEIGHT * 0.56,
-        28.0,
-        Color::from_rgba(200, 220, 255, alpha),
+        Text::ThisRun.get(language),
+        STAT_RUN_X,
+        header_y,
+        22.0,
+        header,
     );
+    if best.is_some() {
+        draw_text_centered(
+            Text::Best.get(language),
+            STAT_BEST_X,
+            header_y,
+            22.0,
+            header,
+        );
+    }
+    for (idx, row) in STAT_ROWS.iter().enumerate() {
+        let y = SCREEN_HEIGHT * 0.35 + idx as f32 * 26.0;
+        let this = (row.value)(stats);
+        let label = row.label.get(language);
+        let dims = measure_text(label, None, 24, 1.0);
+        draw_text(
+            label,
+            STAT_LABEL_X - dims.width,
+            y,
+            24.0,
+            fade(200, 200, 210),
+        );
+        let improved = best.is_some_and(|best| this > (row.value)(best));
+        let color = if improved {
+            Color {
+                a: alpha as f32 / 255.0,
+                ..palette.improved
+            }
+        } else {
+            fade(255, 255, 255)
+        };
+        draw_text_centered(&(row.format)(this), STAT_RUN_X, y, 24.0, color);
+        if let Some(best) = best {
+            draw_text_centered(
+                &(row.format)((row.value)(best)),
+                STAT_BEST_X,
+                y,
+                24.0,
+                fade(170, 180, 200),
+            );
+        }
+    }
+    if world.boost_score > 0.0 {
+        draw_text_centered(
+            &format!(
+                "{}: +{:0.0}m, +{} {}",
+                Text::ScoreBoost.get(language),
+                world.boost_score,
+                world.boost_currency,
+                Text::Currency.get(language)
+            ),
+            SCREEN_WIDTH * 0.5,
+            SCREEN_HEIGHT * 0.77,
+            22.0,
+            fade(255, 214, 90),
+        );
+    }
     draw_text_centered(
-        "Press Space to retry",
+        &Text::Retry.format(language, &[first_key(world, Action::Confirm)]),
         SCREEN_WIDTH * 0.5,
-        SCREEN_HEIGHT * 0.64,
-        32.0,
-        Color::from_rgba(255, 255, 255, alpha),
+        SCREEN_HEIGHT * 0.83,
+        30.0,
+        fade(255, 255, 255),
     );
     draw_text_centered(
-        "Press Esc for menu",
+        &Text::GameOverHint.format(
+            language,
+            &[
+                first_key(world, Action::Pause),
+                first_key(world, Action::WatchReplay),
+            ],
+        ),
         SCREEN_WIDTH * 0.5,
-        SCREEN_HEIGHT * 0.7,
-        24.0,
-        Color::from_rgba(200, 200, 200, alpha),
+        SCREEN_HEIGHT * 0.89,
+        22.0,
+        fade(200, 200, 200),
     );
 }
@@ -0,0 +1,166 @@
+//! 录像回放界面：把 `.dreplay` 重新送入模拟并绘制，支持暂停、回到开头、变速与逐步。
+
+use crate::camera::screen_camera;
+use crate::draw::draw_floating_texts;
+use crate::input::PressedKeys;
+use crate::render::{RenderContext, draw_hud, draw_scene, draw_tuning_error, first_key};
+use crate::utils::draw_text_centered;
+use dinorun_core::bindings::Action;
+use dinorun_core::constants::*;
+use dinorun_core::input::{InputSource, ScriptedInput};
+use dinorun_core::locale::Text;
+use dinorun_core::replay::Replay;
+use dinorun_core::settings::Settings;
+use dinorun_core::timestep::FixedTimestep;
+use dinorun_core::types::GameState;
+use dinorun_core::update::step_world;
+use dinorun_core::world::World;
+use macroquad::prelude::*;
+
+/// 可选的回放速度档位。
+const SPEEDS: [f32; 5] = [0.25, 0.5, 1.0, 2.0, 4.0];
+const DEFAULT_SPEED: usize = 2;
+
+/// 录像回放器，持有独立的 World，不影响玩家的最佳成绩与货币。
+pub struct ReplayViewer {
+    replay: Replay,
+    world: World,
+    input: ScriptedInput,
+    clock: FixedTimestep,
+    speed_index: usize,
+    paused: bool,
+}
+
+impl ReplayViewer {
+    /// `settings` 为玩家当前的设置，回放画面的语言与配色与游戏内一致。
+    pub fn new(replay: Replay, settings: Settings) -> Self {
+        let mut world = World::new(replay.seed);
+        world.settings = settings;
+        let mut viewer = Self {
+            input: replay.input_source(),
+            world,
+            replay,
+            clock: FixedTimestep::default(),
+            speed_index: DEFAULT_SPEED,
+            paused: false,
+        };
+        viewer.rewind();
+        viewer
+    }
+
+    /// 回到录像开头重新播放。
+    pub fn rewind(&mut self) {
+        self.replay.start(&mut self.world);
+        self.input = self.replay.input_source();
+        self.clock = FixedTimestep::default();
+    }
+
+    /// 推进一个模拟步；对局内的暂停时间会被直接跳过。
+    fn step(&mut self) {
+        loop {
+            if self.input.is_finished() {
+                return;
+            }
+            let frame = self.input.poll(&self.world);
+            step_world(&mut self.world, &frame, SIM_DT);
+            if self.world.state != GameState::Paused {
+                return;
+            }
+        }
+    }
+
+    /// 按玩家的按键绑定处理本帧按下的回放控制键 `pressed` 并推进播放；
+    /// 返回 `false` 表示玩家要求退出回放。
+    pub fn update(&mut self, frame_dt: f32, pressed: &PressedKeys) -> bool {
+        let bindings = &self.world.settings.controls;
+        let hit = |action| pressed.hit(bindings, action);
+        if hit(Action::ReplayExit) {
+            return false;
+        }
+        let toggle = hit(Action::ReplayPause);
+        let restart = hit(Action::ReplayRestart);
+        let slower = hit(Action::ReplaySlower);
+        let faster = hit(Action::ReplayFaster);
+        let step = hit(Action::ReplayStep);
+
+        if toggle {
+            self.paused = !self.paused;
+        }
+        if restart {
+            self.rewind();
+        }
+        if slower {
+            self.speed_index = self.speed_index.saturating_sub(1);
+        }
+        if faster {
+            self.speed_index = (self.speed_index + 1).min(SPEEDS.len() - 1);
+        }
+
+        if self.paused {
+            if step {
+                self.step();
+            }
+        } else {
+            for _ in 0..self.clock.advance(frame_dt * SPEEDS[self.speed_index]) {
+                self.step();
+            }
+        }
+        true
+    }
+
+    /// 绘制回放画面与控制提示。
+    pub fn draw(&self, ctx: &RenderContext) {
+        set_camera(&screen_camera(Vec2::ZERO));
+        let alpha = if self.paused { 1.0 } else { self.clock.alpha() };
+        draw_scene(&self.world, ctx, alpha);
+        draw_hud(&self.world, 0.8);
+        draw_floating_texts(&self.world.floating_texts);
+
+        let progress = if self.input.is_empty() {
+            1.0
+        } else {
+            self.input.position() as f32 / self.input.len() as f32
+        };
+        draw_rectangle(
+            0.0,
+            SCREEN_HEIGHT - 10.0,
+            SCREEN_WIDTH * progress.min(1.0),
+            10.0,
+            Color::from_rgba(255, 200, 90, 220),
+        );
+
+        let status = if self.input.is_finished() {
+            "END".to_string()
+        } else if self.paused {
+            "PAUSED".to_string()
+        } else {
+            format!("x{}", SPEEDS[self.speed_index])
+        };
+        draw_text_centered(
+            &format!("REPLAY  {status}"),
+            SCREEN_WIDTH * 0.5,
+            48.0,
+            36.0,
+            Color::from_rgba(255, 200, 90, 255),
+        );
+        let key = |action| first_key(&self.world, action);
+        draw_text_centered(
+            &Text::ReplayHint.format(
+                self.world.settings.language,
+                &[
+                    key(Action::ReplayPause),
+                    key(Action::ReplayStep),
+                    key(Action::ReplaySlower),
+                    key(Action::ReplayFaster),
+                    key(Action::ReplayRestart),
+                    key(Action::ReplayExit),
+                ],
+            ),
+            SCREEN_WIDTH * 0.5,
+            SCREEN_HEIGHT - 24.0,
+            22.0,
+            Color::from_rgba(220, 220, 230, 220),
+        );
+        draw_tuning_error(ctx);
+    }
+}
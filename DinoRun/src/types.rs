@@ -1,3 +1,4 @@
+use dinorun_core::settings::ColorblindMode;
 use macroquad::prelude::*;
 
 /// 简单的颜色插值工具，用于昼夜循环等渐变效果。
@@ -19,50 +20,6 @@ impl ColorLerp {
     }
 }
 
-/// 玩家当前执行的动作，驱动动画、碰撞盒以及体力消耗逻辑。
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum PlayerAction {
-    Running,
-    Jump,
-    DoubleJump,
-    Slide,
-    Dash,
-}
-
-/// 强化道具的种类，用于决定触发的增益效果。
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum PowerUpKind {
-    Shield,
-    ScoreBoost,
-    TimeSlow,
-}
-
-/// 场景中可生成的障碍物类型。
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum ObstacleKind {
-    Crate,
-    Saw,
-    Pit,
-    Drone,
-}
-
-/// 可收集物体的分类，与得分与货币奖励相关联。
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum CollectibleKind {
-    Coin,
-    Gem,
-}
-
-/// 游戏状态机的枚举，涵盖了所有可见流程。
-#[derive(Clone, Copy, PartialEq)]
-pub enum GameState {
-    Splash { timer: f32 },
-    Menu { fade: f32 },
-    Running,
-    Paused,
-    GameOver { cooldown: f32 },
-}
-
 /// 视差层的参数集合，用于绘制背景带状图形。
 #[derive(Clone, Copy)]
 pub struct ParallaxLayer {
@@ -72,54 +29,57 @@ pub struct ParallaxLayer {
     pub thickness: f32,
 }
 
-/// 浮动提示文字，用于表现提示与得分反馈。
-pub struct FloatingText {
-    pub text: String,
-    pub pos: Vec2,
-    pub vel: Vec2,
-    pub timer: f32,
-    pub max_timer: f32,
-    pub color: Color,
-}
-
-/// 动态难度曲线的状态结构，掌控障碍与滚屏节奏。
-pub struct DifficultyTrack {
-    pub time: f32,
-    pub base_spacing: f32,
-    pub rng_obstacle_timer: f32,
-    pub rng_collectible_timer: f32,
+/// 需要互相区分的玩法配色；色盲模式下替换为对应色觉类型易于分辨的颜色。
+#[derive(Clone, Copy)]
+pub struct Palette {
+    pub coin: Color,
+    pub gem: Color,
+    pub shield: Color,
+    pub score_boost: Color,
+    pub time_slow: Color,
+    pub stamina: Color,
+    /// 结算界面中超过个人最佳的数值。
+    pub improved: Color,
 }
 
-impl DifficultyTrack {
-    /// 使用默认值初始化曲线，适用于新开一局的初始节奏。
-    pub fn new() -> Self {
-        Self {
-            time: 0.0,
-            base_spacing: 1.4,
-            rng_obstacle_timer: 1.6,
-            rng_collectible_timer: 1.2,
-        }
-    }
-
-    /// 依据时间推移提升滚动速度，直到达到上限。
-    pub fn scroll_speed(&self) -> f32 {
-        use crate::constants::*;
-        (BASE_SCROLL_SPEED + self.time * 12.0).min(MAX_SCROLL_SPEED)
-    }
+impl Palette {
+    const DEFAULT: Palette = Palette {
+        coin: Color::from_rgba(255, 210, 64, 255),
+        gem: Color::from_rgba(90, 210, 255, 255),
+        shield: Color::from_rgba(70, 150, 230, 255),
+        score_boost: Color::from_rgba(255, 214, 90, 255),
+        time_slow: Color::from_rgba(120, 80, 200, 255),
+        stamina: Color::from_rgba(80, 200, 255, 255),
+        improved: Color::from_rgba(140, 240, 150, 255),
+    };
 
-    /// 让地面高度随时间轻微波动，营造灵动感。
-    pub fn ground_y(&self) -> f32 {
-        use crate::constants::*;
-        BASE_GROUND_Y + (self.time * 0.1).sin() * GROUND_VARIATION
-    }
+    /// 红绿色盲（红色盲与绿色盲共用）：以蓝、橙、黄区分，避免依赖绿色。
+    const RED_GREEN: Palette = Palette {
+        coin: Color::from_rgba(240, 228, 66, 255),
+        gem: Color::from_rgba(86, 180, 233, 255),
+        shield: Color::from_rgba(0, 114, 178, 255),
+        score_boost: Color::from_rgba(230, 159, 0, 255),
+        time_slow: Color::from_rgba(204, 121, 167, 255),
+        stamina: Color::from_rgba(86, 180, 233, 255),
+        improved: Color::from_rgba(100, 190, 255, 255),
+    };
 
-    /// 返回下次障碍刷新的间隔，时间越久越短。
-    pub fn obstacle_interval(&self) -> f32 {
-        (self.base_spacing - self.time * 0.012).max(0.62)
-    }
+    /// 蓝黄色盲：以红、青绿、品红区分，避免蓝与黄并列。
+    const BLUE_YELLOW: Palette = Palette {
+        coin: Color::from_rgba(235, 110, 60, 255),
+        gem: Color::from_rgba(0, 190, 160, 255),
+        shield: Color::from_rgba(0, 140, 130, 255),
+        score_boost: Color::from_rgba(255, 130, 110, 255),
+        time_slow: Color::from_rgba(200, 90, 170, 255),
+        stamina: Color::from_rgba(0, 190, 160, 255),
+        improved: Color::from_rgba(255, 130, 110, 255),
+    };
 
-    /// 返回收集物的刷新间隔，带有周期性的呼吸感。
-    pub fn collectible_interval(&self) -> f32 {
-        (1.0 + (self.time * 0.027).sin()).max(0.3)
+    pub fn for_mode(mode: ColorblindMode) -> &'static Palette {
+        match mode {
+            ColorblindMode::Off => &Palette::DEFAULT,
+            ColorblindMode::Deuteranopia | ColorblindMode::Protanopia => &Palette::RED_GREEN,
+            ColorblindMode::Tritanopia => &Palette::BLUE_YELLOW,
+        }
     }
 }
@@ -1,29 +1,5 @@
 use macroquad::prelude::*;
 
-/// 为 `Rect` 提供常用的扩展方法，方便碰撞检测调整。
-pub trait RectExt {
-    fn overlaps(&self, other: &Rect) -> bool;
-    fn inflate(&self, amount_x: f32, amount_y: f32) -> Rect;
-}
-
-impl RectExt for Rect {
-    fn overlaps(&self, other: &Rect) -> bool {
-        self.x < other.x + other.w
-            && self.x + self.w > other.x
-            && self.y < other.y + other.h
-            && self.y + self.h > other.y
-    }
-
-    fn inflate(&self, amount_x: f32, amount_y: f32) -> Rect {
-        Rect::new(
-            self.x - amount_x,
-            self.y - amount_y,
-            self.w + amount_x * 2.0,
-            self.h + amount_y * 2.0,
-        )
-    }
-}
-
 /// 平滑的二次缓出曲线，常用于淡入淡出动画。
 pub fn ease_out_quad(t: f32) -> f32 {
     1.0 - (1.0 - t) * (1.0 - t)